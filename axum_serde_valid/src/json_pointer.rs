@@ -2,7 +2,7 @@ use std::ops::Deref;
 
 use serde::Serialize;
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Clone, Serialize)]
 pub struct JsonPointer(pub String);
 
 impl Deref for JsonPointer {
@@ -5,7 +5,10 @@ mod query;
 pub mod rejection;
 mod request;
 mod validated;
+mod warnings;
 
+#[cfg(feature = "jsonschema")]
 pub use features::*;
 pub use json::Json;
 pub use query::Query;
+pub use warnings::{Warnings, WithWarnings};
@@ -49,19 +49,23 @@ pub struct JsonSchemaErrorResponse {
 }
 
 #[cfg(not(feature = "aide"))]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Error {
     pub error: String,
     pub instance_location: JsonPointer,
     pub keyword_location: Option<JsonPointer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
 }
 
 #[cfg(feature = "aide")]
-#[derive(Debug, Serialize, schemars::JsonSchema)]
+#[derive(Debug, Clone, Serialize, schemars::JsonSchema)]
 pub struct Error {
     pub error: String,
     pub instance_location: JsonPointer,
     pub keyword_location: Option<JsonPointer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
 }
 
 impl From<Rejection> for JsonErrorResponse {
@@ -70,15 +74,7 @@ impl From<Rejection> for JsonErrorResponse {
             Rejection::Json(v) => Self::FormatError(v.to_string()),
             Rejection::Serde(_) => Self::FormatError("invalid request".to_string()),
             Rejection::SerdeValid(errors) => Self::ValidationError(JsonSchemaErrorResponse {
-                errors: errors
-                    .into_flat()
-                    .into_iter()
-                    .map(|error| Error {
-                        error: error.error,
-                        instance_location: JsonPointer(error.instance_location.to_string()),
-                        keyword_location: None,
-                    })
-                    .collect::<Vec<_>>(),
+                errors: flat_errors(errors),
             }),
             #[cfg(feature = "jsonschema")]
             Rejection::Jsonschema(errors) => Self::ValidationError(JsonSchemaErrorResponse {
@@ -88,6 +84,7 @@ impl From<Rejection> for JsonErrorResponse {
                         error: error.error_description().to_string(),
                         instance_location: JsonPointer(error.instance_location().to_string()),
                         keyword_location: Some(JsonPointer(error.keyword_location().to_string())),
+                        value: None,
                     })
                     .collect::<Vec<_>>(),
             }),
@@ -95,6 +92,20 @@ impl From<Rejection> for JsonErrorResponse {
     }
 }
 
+/// Flatten the serde_valid errors into the errors with the JSON pointers.
+pub(crate) fn flat_errors(errors: serde_valid::validation::Errors) -> Vec<Error> {
+    errors
+        .into_flat()
+        .into_iter()
+        .map(|error| Error {
+            error: error.error,
+            instance_location: JsonPointer(error.instance_location.to_string()),
+            keyword_location: None,
+            value: error.value,
+        })
+        .collect::<Vec<_>>()
+}
+
 impl IntoResponse for Rejection {
     fn into_response(self) -> axum::response::Response {
         match JsonErrorResponse::from(self) {
@@ -7,6 +7,23 @@ pub async fn from_request<S, B, T>(
     req: axum::http::Request<B>,
     state: &S,
 ) -> Result<T, crate::rejection::Rejection>
+where
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+    T: crate::validated::Deserialize + 'static,
+{
+    from_request_with_warnings::<_, _, T>(req, state)
+        .await
+        .map(|(value, _)| value)
+}
+
+/// Deserialize and validate the request, keeping the warnings of the validations.
+pub async fn from_request_with_warnings<S, B, T>(
+    req: axum::http::Request<B>,
+    state: &S,
+) -> Result<(T, crate::warnings::Warnings), crate::rejection::Rejection>
 where
     B: http_body::Body + Send + 'static,
     B::Data: Send,
@@ -27,10 +44,12 @@ where
 
     match serde_json::from_value::<T>(value) {
         Ok(v) => {
-            v.validate()
-                .map_err(crate::rejection::Rejection::SerdeValid)?;
+            let report = v.validate_with_warnings();
+            if let Some(errors) = report.errors {
+                Err(crate::rejection::Rejection::SerdeValid(errors))?
+            }
 
-            Ok(v)
+            Ok((v, crate::warnings::Warnings::from(report.warnings)))
         }
         Err(error) => {
             tracing::error!(
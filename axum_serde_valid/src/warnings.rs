@@ -0,0 +1,185 @@
+//! The warnings of the validations with `severity = "warning"`,
+//! which don't reject the request but are exposed to the handlers.
+//!
+//! ```rust
+//! use axum::{http::HeaderMap, routing::post, Router};
+//! use axum_serde_valid::{Json, WithWarnings};
+//! use serde::Deserialize;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Deserialize, Validate)]
+//! #[cfg_attr(
+//!     any(feature = "jsonschema", feature = "aide"),
+//!     derive(schemars::JsonSchema)
+//! )]
+//! struct User {
+//!     #[validate(maximum = 100, severity = "warning")]
+//!     age: u32,
+//! }
+//!
+//! async fn create_user(WithWarnings(Json(user), warnings): WithWarnings<Json<User>>) -> HeaderMap {
+//!     let mut headers = HeaderMap::new();
+//!     for warning in warnings.iter() {
+//!         headers.append("x-validation-warning", warning.error.parse().unwrap());
+//!     }
+//!     headers
+//! }
+//!
+//! let app: Router = Router::new().route("/users", post(create_user));
+//! ```
+
+use std::ops::Deref;
+
+use async_trait::async_trait;
+use axum::http::Request;
+use axum::{extract::FromRequest, BoxError};
+
+use crate::{Json, Query};
+
+/// The warnings of the validations, which are flattened like the errors of the rejection.
+#[derive(Debug, Default, Clone)]
+pub struct Warnings(pub Vec<crate::rejection::Error>);
+
+impl Deref for Warnings {
+    type Target = Vec<crate::rejection::Error>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Option<serde_valid::validation::Errors>> for Warnings {
+    fn from(warnings: Option<serde_valid::validation::Errors>) -> Self {
+        match warnings {
+            Some(warnings) => Warnings(crate::rejection::flat_errors(warnings)),
+            None => Warnings::default(),
+        }
+    }
+}
+
+/// Wrapper type over [`Json`] and [`Query`],
+/// which passes the warnings of the validations to the handler.
+pub struct WithWarnings<E>(pub E, pub Warnings);
+
+#[async_trait]
+impl<T, S, B> FromRequest<S, B> for WithWarnings<Json<T>>
+where
+    T: crate::validated::Deserialize + 'static,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    type Rejection = crate::rejection::Rejection;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        crate::request::from_request_with_warnings::<_, _, T>(req, state)
+            .await
+            .map(|(value, warnings)| WithWarnings(Json(value), warnings))
+    }
+}
+
+#[async_trait]
+impl<T, S, B> FromRequest<S, B> for WithWarnings<Query<T>>
+where
+    T: crate::validated::Deserialize + 'static,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    type Rejection = crate::rejection::Rejection;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        crate::request::from_request_with_warnings::<_, _, T>(req, state)
+            .await
+            .map(|(value, warnings)| WithWarnings(Query(value), warnings))
+    }
+}
+
+#[cfg(feature = "aide")]
+mod impl_aide {
+    use super::*;
+
+    impl<E> aide::OperationInput for WithWarnings<E>
+    where
+        E: aide::OperationInput,
+    {
+        fn operation_input(
+            ctx: &mut aide::gen::GenContext,
+            operation: &mut aide::openapi::Operation,
+        ) {
+            E::operation_input(ctx, operation);
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "jsonschema"), not(feature = "aide")))]
+mod test {
+    use crate::{Json, WithWarnings};
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::{
+        body::Body,
+        http::{self, Request},
+    };
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::Validate;
+    use tower::ServiceExt;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_json_with_warnings() -> TestResult {
+        use axum::{routing::post, Router};
+
+        #[derive(Deserialize, Validate)]
+        struct User {
+            #[validate(max_length = 8)]
+            #[validate(max_length = 3, severity = "warning")]
+            name: String,
+        }
+
+        let app = Router::new().route(
+            "/json",
+            post(
+                |WithWarnings(Json(_user), warnings): WithWarnings<Json<User>>| async move {
+                    let mut headers = HeaderMap::new();
+                    for warning in warnings.iter() {
+                        headers.append(
+                            "x-validation-warning",
+                            format!("{} {}", *warning.instance_location, warning.error)
+                                .parse()
+                                .unwrap(),
+                        );
+                    }
+                    headers
+                },
+            ),
+        );
+
+        let request = |name: &str| -> Result<Request<Body>, Box<dyn std::error::Error>> {
+            Ok(Request::builder()
+                .method(http::Method::POST)
+                .uri("/json")
+                .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                .body(Body::from(serde_json::to_vec(&json!({ "name": name }))?))?)
+        };
+
+        let response = app.clone().oneshot(request("taro")?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["x-validation-warning"],
+            "/name The length of the value must be `<= 3`."
+        );
+
+        let response = app.clone().oneshot(request("tom")?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get("x-validation-warning").is_none());
+
+        let response = app.oneshot(request("bartholomew")?).await?;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        Ok(())
+    }
+}
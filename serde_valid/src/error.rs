@@ -1,8 +1,8 @@
 use itertools::Itertools;
 use serde_valid_literal::Literal;
 
-use crate::validation::ToDefaultMessage;
-use crate::validation::{Number, Pattern};
+use crate::validation::{ErrorParams, RejectedValue, ToDefaultMessage};
+use crate::validation::{Number, Pattern, PatternMode};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error<E>
@@ -60,19 +60,120 @@ macro_rules! struct_error_params {
         #[derive(Debug, Clone)]
         pub struct $Error {
             pub $limit: Vec<$type>,
+            pub value: Option<Box<RejectedValue>>,
         }
 
+        impl $Error {
+            pub fn new($limit: Vec<$type>) -> Self {
+                Self {
+                    $limit,
+                    value: None,
+                }
+            }
+        }
+
+        impl_error_params!($Error);
+
         impl ToDefaultMessage for $Error {
             #[inline]
             fn to_default_message(&self) -> String {
                 format!(
                     $default_message,
-                    self.enumerate.iter().map(|v| format!("{}", v)).join(", ")
+                    self.$limit.iter().map(|v| format!("{}", v)).join(", ")
                 )
             }
         }
     };
 
+    (
+        #[derive(Debug, Clone)]
+        #[default_message=$default_message:literal]
+        pub struct $Error:ident {
+            pub $limit:ident: Pattern,
+            pub mode: PatternMode,
+        }
+    ) => {
+        #[derive(Debug, Clone)]
+        pub struct $Error {
+            /// The pattern as written, which is shown in the message.
+            pub $limit: Pattern,
+            /// The pattern which is actually matched, after the pattern options are applied.
+            pub compiled_pattern: Box<Pattern>,
+            pub mode: PatternMode,
+            pub value: Option<Box<RejectedValue>>,
+        }
+
+        impl $Error {
+            pub fn new<N: Into<Pattern>>($limit: N) -> Self {
+                let $limit = $limit.into();
+                Self {
+                    compiled_pattern: Box::new($limit.clone()),
+                    $limit,
+                    mode: PatternMode::default(),
+                    value: None,
+                }
+            }
+
+            pub fn with_mode(self, mode: PatternMode) -> Self {
+                Self { mode, ..self }
+            }
+
+            /// Show the pattern as written instead of the compiled one,
+            /// like `^[a-z]+$` for the compiled `(?i)^[a-z]+$` of `pattern(r"^[a-z]+$", case_insensitive)`.
+            pub fn written_as(self, source: &str) -> Self {
+                Self {
+                    $limit: Pattern::from_source(source),
+                    ..self
+                }
+            }
+        }
+
+        impl_error_params!($Error);
+
+        impl ToDefaultMessage for $Error {
+            #[inline]
+            fn to_default_message(&self) -> String {
+                format!($default_message, self.$limit)
+            }
+        }
+    };
+
+    (
+        #[derive(Debug, Clone)]
+        #[default_message=$default_message:literal]
+        pub struct $Error:ident {
+            pub $limit:ident: usize,
+            pub $measured:ident: usize,
+        }
+    ) => {
+        #[derive(Debug, Clone)]
+        pub struct $Error {
+            pub $limit: usize,
+            /// The measured value, which is compared with the limit.
+            pub $measured: usize,
+            pub value: Option<Box<RejectedValue>>,
+        }
+
+        impl $Error {
+            pub fn new($limit: usize, $measured: usize) -> Self {
+                Self {
+                    $limit,
+                    $measured,
+                    value: None,
+                }
+            }
+        }
+
+        impl_error_params!($Error);
+
+        impl ToDefaultMessage for $Error {
+            #[inline]
+            fn to_default_message(&self) -> String {
+                format!($default_message, self.$limit)
+            }
+        }
+    };
+
     (
         #[derive(Debug, Clone)]
         #[default_message=$default_message:literal]
@@ -83,16 +184,20 @@ macro_rules! struct_error_params {
         #[derive(Debug, Clone)]
         pub struct $Error {
             pub $limit: $type,
+            pub value: Option<Box<RejectedValue>>,
         }
 
         impl $Error {
             pub fn new<N: Into<$type>>($limit: N) -> Self {
                 Self {
                     $limit: $limit.into(),
+                    value: None,
                 }
             }
         }
 
+        impl_error_params!($Error);
+
         impl ToDefaultMessage for $Error {
             #[inline]
             fn to_default_message(&self) -> String {
@@ -107,8 +212,18 @@ macro_rules! struct_error_params {
         pub struct $Error:ident {
         }
     ) => {
-        #[derive(Debug, Clone)]
-        pub struct $Error {}
+        #[derive(Debug, Clone, Default)]
+        pub struct $Error {
+            pub value: Option<Box<RejectedValue>>,
+        }
+
+        impl $Error {
+            pub fn new() -> Self {
+                Self::default()
+            }
+        }
+
+        impl_error_params!($Error);
 
         impl ToDefaultMessage for $Error {
             #[inline]
@@ -119,6 +234,40 @@ macro_rules! struct_error_params {
     };
 }
 
+/// Keep the rejected value in the `value` field of the error params.
+///
+/// The value is boxed, because it makes every variant of [`Error`](crate::validation::Error) larger.
+macro_rules! impl_error_params {
+    ($Error:ident) => {
+        impl $Error {
+            pub fn with_value(mut self, value: impl Into<RejectedValue>) -> Self {
+                self.value = Some(Box::new(value.into()));
+                self
+            }
+        }
+
+        impl ErrorParams for $Error {
+            fn value(&self) -> Option<&RejectedValue> {
+                self.value.as_deref()
+            }
+
+            fn redact(&mut self) {
+                if self.value.is_some() {
+                    self.value = Some(Box::new(RejectedValue::Redacted));
+                }
+            }
+
+            fn set_value(&mut self, value: RejectedValue) {
+                self.value = Some(Box::new(value));
+            }
+
+            fn clear_value(&mut self) {
+                self.value = None;
+            }
+        }
+    };
+}
+
 // Number
 struct_error_params!(
     #[derive(Debug, Clone)]
@@ -160,12 +309,25 @@ struct_error_params!(
     }
 );
 
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The number must be finite."]
+    pub struct FiniteError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The number must not be NaN."]
+    pub struct NotNanError {}
+);
+
 // String
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The length of the value must be `>= {}`."]
     pub struct MinLengthError {
         pub min_length: usize,
+        pub length: usize,
     }
 );
 
@@ -174,6 +336,7 @@ struct_error_params!(
     #[default_message = "The length of the value must be `<= {}`."]
     pub struct MaxLengthError {
         pub max_length: usize,
+        pub length: usize,
     }
 );
 
@@ -182,15 +345,120 @@ struct_error_params!(
     #[default_message = "The value must match the pattern of \"{}\"."]
     pub struct PatternError {
         pub pattern: Pattern,
+        pub mode: PatternMode,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must not match the pattern of \"{}\"."]
+    pub struct NotPatternError {
+        pub not_pattern: Pattern,
+        pub mode: PatternMode,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must contain \"{}\"."]
+    pub struct ContainsError {
+        pub contains: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must not contain \"{}\"."]
+    pub struct NotContainsError {
+        pub not_contains: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must start with \"{}\"."]
+    pub struct StartsWithError {
+        pub starts_with: String,
     }
 );
 
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must end with \"{}\"."]
+    pub struct EndsWithError {
+        pub ends_with: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must consist of the characters in \"{}\"."]
+    pub struct CharsetError {
+        pub charset: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must not be blank."]
+    pub struct NotBlankError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must contain only ASCII characters."]
+    pub struct AsciiError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must contain only alphanumeric characters."]
+    pub struct AlphanumericError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be a valid Luhn number."]
+    pub struct LuhnError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be a valid IBAN."]
+    pub struct IbanError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be a valid ISBN."]
+    pub struct IsbnError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be a valid EAN."]
+    pub struct EanError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be a valid email address."]
+    pub struct EmailError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be a valid URL."]
+    pub struct UrlError {}
+);
+
 // Array
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The length of the items must be `<= {}`."]
     pub struct MaxItemsError {
         pub max_items: usize,
+        pub items_count: usize,
     }
 );
 
@@ -199,13 +467,161 @@ struct_error_params!(
     #[default_message = "The length of the items must be `>= {}`."]
     pub struct MinItemsError {
         pub min_items: usize,
+        pub items_count: usize,
+    }
+);
+
+/// The error of the uniqueness of the array items.
+///
+/// `duplicates` holds the indices of the items which duplicate an earlier item.
+#[derive(Debug, Clone, Default)]
+pub struct UniqueItemsError {
+    pub duplicates: Vec<usize>,
+    pub value: Option<Box<RejectedValue>>,
+}
+
+impl UniqueItemsError {
+    pub fn new(duplicates: Vec<usize>) -> Self {
+        Self {
+            duplicates,
+            value: None,
+        }
+    }
+}
+
+impl_error_params!(UniqueItemsError);
+
+impl ToDefaultMessage for UniqueItemsError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        "The items must be unique.".to_string()
+    }
+}
+
+/// The error of the order of the array items.
+///
+/// `index` is the index of the first item which is out of order.
+#[derive(Debug, Clone)]
+pub struct SortedError {
+    pub index: usize,
+    pub value: Option<Box<RejectedValue>>,
+}
+
+impl SortedError {
+    pub fn new(index: usize) -> Self {
+        Self { index, value: None }
+    }
+}
+
+impl_error_params!(SortedError);
+
+impl ToDefaultMessage for SortedError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        "The items must be sorted.".to_string()
+    }
+}
+
+/// The error of the strict order of the array items.
+///
+/// `index` is the index of the first item which is not greater than the previous item.
+#[derive(Debug, Clone)]
+pub struct StrictlyIncreasingError {
+    pub index: usize,
+    pub value: Option<Box<RejectedValue>>,
+}
+
+impl StrictlyIncreasingError {
+    pub fn new(index: usize) -> Self {
+        Self { index, value: None }
+    }
+}
+
+impl_error_params!(StrictlyIncreasingError);
+
+impl ToDefaultMessage for StrictlyIncreasingError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        "The items must be strictly increasing.".to_string()
+    }
+}
+
+/// The error of the maximum sum of the array items.
+///
+/// The sum of all the items is checked, and when it exceeds `max_sum`,
+/// `index` is the index of the first item where the running sum exceeds `max_sum`.
+#[derive(Debug, Clone)]
+pub struct MaxSumError {
+    pub max_sum: Number,
+    pub index: usize,
+    pub value: Option<Box<RejectedValue>>,
+}
+
+impl MaxSumError {
+    pub fn new<N: Into<Number>>(max_sum: N, index: usize) -> Self {
+        Self {
+            max_sum: max_sum.into(),
+            index,
+            value: None,
+        }
+    }
+}
+
+impl_error_params!(MaxSumError);
+
+impl ToDefaultMessage for MaxSumError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        format!("The sum of the items must be `<= {}`.", self.max_sum)
+    }
+}
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The sum of the items must be `>= {}`."]
+    pub struct MinSumError {
+        pub min_sum: Number,
     }
 );
 
+/// The error of the maximum count of the array items which match the predicate.
+///
+/// `index` is the index of the first matching item over `max_count`.
+#[derive(Debug, Clone)]
+pub struct MaxCountError {
+    pub max_count: usize,
+    pub index: usize,
+    pub value: Option<Box<RejectedValue>>,
+}
+
+impl MaxCountError {
+    pub fn new(max_count: usize, index: usize) -> Self {
+        Self {
+            max_count,
+            index,
+            value: None,
+        }
+    }
+}
+
+impl_error_params!(MaxCountError);
+
+impl ToDefaultMessage for MaxCountError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        format!(
+            "The count of the matching items must be `<= {}`.",
+            self.max_count
+        )
+    }
+}
+
 struct_error_params!(
     #[derive(Debug, Clone)]
-    #[default_message = "The items must be unique."]
-    pub struct UniqueItemsError {}
+    #[default_message = "The count of the matching items must be `>= {}`."]
+    pub struct MinCountError {
+        pub min_count: usize,
+    }
 );
 
 // Object
@@ -214,6 +630,7 @@ struct_error_params!(
     #[default_message = "The size of the properties must be `<= {}`."]
     pub struct MaxPropertiesError {
         pub max_properties: usize,
+        pub size: usize,
     }
 );
 
@@ -222,17 +639,20 @@ struct_error_params!(
     #[default_message = "The size of the properties must be `>= {}`."]
     pub struct MinPropertiesError {
         pub min_properties: usize,
+        pub size: usize,
     }
 );
 
 // Generic
-struct_error_params!(
-    #[derive(Debug, Clone)]
-    #[default_message = "The value must be in [{:}]."]
-    pub struct EnumerateError {
-        pub enumerate: Vec<Literal>,
-    }
-);
+/// The error of the enumerated values.
+///
+/// The allowed values are hidden with the rejected value by `#[validate(sensitive)]`,
+/// because they may reveal the value.
+#[derive(Debug, Clone)]
+pub struct EnumerateError {
+    pub enumerate: Vec<Literal>,
+    pub value: Option<Box<RejectedValue>>,
+}
 
 impl EnumerateError {
     pub fn new<T>(enumerate: &[T]) -> Self
@@ -241,6 +661,86 @@ impl EnumerateError {
     {
         Self {
             enumerate: (*enumerate).iter().map(|x| x.clone().into()).collect(),
+            value: None,
+        }
+    }
+
+    pub fn with_value(mut self, value: impl Into<RejectedValue>) -> Self {
+        self.value = Some(Box::new(value.into()));
+        self
+    }
+}
+
+impl ErrorParams for EnumerateError {
+    fn value(&self) -> Option<&RejectedValue> {
+        self.value.as_deref()
+    }
+
+    fn redact(&mut self) {
+        self.enumerate.clear();
+        if self.value.is_some() {
+            self.value = Some(Box::new(RejectedValue::Redacted));
+        }
+    }
+
+    fn set_value(&mut self, value: RejectedValue) {
+        self.value = Some(Box::new(value));
+    }
+
+    fn clear_value(&mut self) {
+        self.value = None;
+    }
+}
+
+impl ToDefaultMessage for EnumerateError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        if self.enumerate.is_empty() {
+            "The value is not allowed.".to_string()
+        } else {
+            format!(
+                "The value must be in [{}].",
+                self.enumerate.iter().map(|v| format!("{}", v)).join(", ")
+            )
         }
     }
 }
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value is required."]
+    pub struct RequiredError {}
+);
+
+// Rule
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "At least one of [{}] must be present."]
+    pub struct AtLeastOneOfError {
+        pub fields: Vec<&'static str>,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "Exactly one of [{}] must be present."]
+    pub struct ExactlyOneOfError {
+        pub fields: Vec<&'static str>,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "At most one of [{}] can be present."]
+    pub struct MutuallyExclusiveError {
+        pub fields: Vec<&'static str>,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "All or none of [{}] must be present."]
+    pub struct AllOrNoneError {
+        pub fields: Vec<&'static str>,
+    }
+);
@@ -5,6 +5,8 @@ use serde::Serialize;
 pub struct FlatError {
     pub error: String,
     pub instance_location: JSONPointer,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
 }
 
 impl FlatError {
@@ -12,19 +14,25 @@ impl FlatError {
         Self {
             error,
             instance_location: instance_location.into(),
+            value: None,
         }
     }
 
+    pub fn with_value(self, value: Option<serde_json::Value>) -> Self {
+        Self { value, ..self }
+    }
+
     pub fn merge_childs(self, instance_location: impl IntoIterator<Item = PathChunk>) -> Self {
         Self::new(
             JSONPointer::from(
                 instance_location
                     .into_iter()
-                    .chain(self.instance_location.into_iter())
+                    .chain(self.instance_location)
                     .collect::<Vec<_>>()
                     .as_slice(),
             ),
             self.error,
         )
+        .with_value(self.value)
     }
 }
@@ -61,10 +61,11 @@ mod tests {
             serde_json::to_value(
                 Errors::Array(ArrayErrors {
                     errors: vec![Error::MinItems(Message::new(
-                        MinItemsError { min_items: 1 },
+                        MinItemsError::new(1, 0),
                         MinItemsError::to_default_message
                     ))],
                     items: indexmap! {},
+                    keyed_items: Box::default(),
                 })
                 .into_flat()
             )
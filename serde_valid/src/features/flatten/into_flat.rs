@@ -1,7 +1,8 @@
 use jsonschema::paths::{JSONPointer, PathChunk};
 
 use crate::validation::{
-    ArrayErrors, ItemErrorsMap, Message, ObjectErrors, PropertyErrorsMap, ToDefaultMessage,
+    ArrayErrors, ErrorParams, ItemErrorsMap, KeyedItemErrorsMap, Message, ObjectErrors,
+    PropertyErrorsMap, ToDefaultMessage,
 };
 
 use super::{FlatError, FlatErrors};
@@ -38,15 +39,62 @@ impl IntoFlat for crate::validation::Error {
             crate::validation::Error::ExclusiveMinimum(inner) => inner.into_flat_at(path),
             crate::validation::Error::ExclusiveMaximum(inner) => inner.into_flat_at(path),
             crate::validation::Error::MultipleOf(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Finite(inner) => inner.into_flat_at(path),
+            crate::validation::Error::NotNan(inner) => inner.into_flat_at(path),
             crate::validation::Error::MinLength(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxLength(inner) => inner.into_flat_at(path),
             crate::validation::Error::Pattern(inner) => inner.into_flat_at(path),
+            crate::validation::Error::NotPattern(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Contains(inner) => inner.into_flat_at(path),
+            crate::validation::Error::NotContains(inner) => inner.into_flat_at(path),
+            crate::validation::Error::StartsWith(inner) => inner.into_flat_at(path),
+            crate::validation::Error::EndsWith(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Charset(inner) => inner.into_flat_at(path),
+            crate::validation::Error::NotBlank(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Ascii(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Alphanumeric(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Luhn(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Iban(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Isbn(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Ean(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Email(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Url(inner) => inner.into_flat_at(path),
             crate::validation::Error::MinItems(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxItems(inner) => inner.into_flat_at(path),
-            crate::validation::Error::UniqueItems(inner) => inner.into_flat_at(path),
+            // The error at the array is kept, followed by the errors at the duplicated items.
+            crate::validation::Error::UniqueItems(inner) => {
+                let item_errors =
+                    item_flat_errors_at(path, inner.params().duplicates.clone(), inner.error());
+                FlatErrors::new(
+                    inner
+                        .into_flat_at(path)
+                        .into_iter()
+                        .chain(item_errors)
+                        .collect::<Vec<_>>(),
+                )
+            }
+            crate::validation::Error::Sorted(inner) => {
+                item_flat_errors_at(path, [inner.params().index], inner.error())
+            }
+            crate::validation::Error::StrictlyIncreasing(inner) => {
+                item_flat_errors_at(path, [inner.params().index], inner.error())
+            }
+            crate::validation::Error::MaxSum(inner) => {
+                item_flat_errors_at(path, [inner.params().index], inner.error())
+            }
+            crate::validation::Error::MinSum(inner) => inner.into_flat_at(path),
+            crate::validation::Error::MaxCount(inner) => {
+                item_flat_errors_at(path, [inner.params().index], inner.error())
+            }
+            crate::validation::Error::MinCount(inner) => inner.into_flat_at(path),
             crate::validation::Error::MinProperties(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxProperties(inner) => inner.into_flat_at(path),
             crate::validation::Error::Enumerate(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Required(inner) => inner.into_flat_at(path),
+            crate::validation::Error::AtLeastOneOf(inner) => inner.into_flat_at(path),
+            crate::validation::Error::ExactlyOneOf(inner) => inner.into_flat_at(path),
+            crate::validation::Error::MutuallyExclusive(inner) => inner.into_flat_at(path),
+            crate::validation::Error::AllOrNone(inner) => inner.into_flat_at(path),
             crate::validation::Error::Items(inner) => inner.into_flat_at(path),
             crate::validation::Error::Properties(inner) => inner.into_flat_at(path),
             crate::validation::Error::Custom(inner) => {
@@ -60,6 +108,29 @@ impl IntoFlat for crate::validation::Error {
     }
 }
 
+/// Point the error at the offending items, like `/items/3`.
+fn item_flat_errors_at(
+    path: &JSONPointer,
+    indices: impl IntoIterator<Item = usize>,
+    error: String,
+) -> FlatErrors {
+    FlatErrors::new(
+        indices
+            .into_iter()
+            .map(|index| {
+                FlatError::new(
+                    path.clone()
+                        .into_iter()
+                        .chain([PathChunk::Index(index)])
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    error.clone(),
+                )
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
 impl<E> IntoFlat for Vec<E>
 where
     E: IntoFlat,
@@ -88,6 +159,26 @@ where
     }
 }
 
+/// The items are located by the key instead of the index, like `/users/user-42`.
+impl<E> IntoFlat for KeyedItemErrorsMap<E>
+where
+    E: IntoFlat,
+{
+    fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
+        FlatErrors::new(self.into_iter().fold(vec![], |pre, (key, errors)| {
+            pre.into_iter()
+                .chain(errors.into_flat().into_iter().map(|error| {
+                    error.merge_childs(
+                        path.clone()
+                            .into_iter()
+                            .chain([PathChunk::Property(key.clone().into_boxed_str())]),
+                    )
+                }))
+                .collect::<Vec<_>>()
+        }))
+    }
+}
+
 impl<E> IntoFlat for PropertyErrorsMap<E>
 where
     E: IntoFlat,
@@ -109,10 +200,16 @@ where
 
 impl<T> IntoFlat for Message<T>
 where
-    T: ToDefaultMessage,
+    T: ToDefaultMessage + ErrorParams,
 {
     fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
-        FlatErrors::new(vec![FlatError::new(path.to_owned(), self.error())])
+        let value = self
+            .params()
+            .value()
+            .and_then(|value| serde_json::to_value(value).ok());
+        FlatErrors::new(vec![
+            FlatError::new(path.to_owned(), self.error()).with_value(value)
+        ])
     }
 }
 
@@ -120,12 +217,17 @@ impl<E> IntoFlat for ArrayErrors<E>
 where
     E: IntoFlat,
 {
+    /// The keyed items are located by the key, like `/users/user-42`.
+    ///
+    /// The key takes the place of the index, so the location of a keyed item
+    /// is not a JSON Pointer into the payload.
     fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
         FlatErrors::new(
             self.errors
                 .into_flat_at(path)
                 .into_iter()
                 .chain(self.items.into_flat_at(path))
+                .chain((*self.keyed_items).into_flat_at(path))
                 .collect::<Vec<_>>(),
         )
     }
@@ -135,6 +237,7 @@ impl<E> IntoFlat for ObjectErrors<E>
 where
     E: IntoFlat,
 {
+    /// The `internal` errors are not included, because they have no instance location.
     fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
         FlatErrors::new(
             self.errors
@@ -161,19 +264,14 @@ mod tests {
 
     use crate::{
         validation::{Error, Errors},
-        MaximumError, MinItemsError,
+        MaximumError, MinItemsError, SortedError, UniqueItemsError,
     };
 
     #[test]
     fn array_errors_flatten() {
-        let min_items = Message::new(
-            MinItemsError { min_items: 1 },
-            MinItemsError::to_default_message,
-        );
+        let min_items = Message::new(MinItemsError::new(1, 0), MinItemsError::to_default_message);
         let maximum = Message::new(
-            MaximumError {
-                maximum: Number::I32(1),
-            },
+            MaximumError::new(Number::I32(1)),
             MaximumError::to_default_message,
         );
         assert_eq!(
@@ -185,20 +283,22 @@ mod tests {
                             errors: vec![Error::Maximum(maximum.clone())],
                             items: indexmap! {
                                 2 => Errors::NewType(vec![Error::Maximum(maximum.clone())]),
-                            }
+                            },
+                            keyed_items: Box::default(),
                         }
                     ),
                     3 => Errors::NewType(vec![Error::Maximum(maximum.clone())]),
                     5 => Errors::Object(
-                        ObjectErrors {
-                            errors: vec![Error::Maximum(maximum.clone())],
-                            properties: indexmap! {
+                        ObjectErrors::new(
+                            vec![Error::Maximum(maximum.clone())],
+                            indexmap! {
                                 "name" => Errors::NewType(vec![Error::Maximum(maximum.clone())]),
                             }
-                        }
+                        )
                     ),
 
                 },
+                keyed_items: Box::default(),
             })
             .into_flat(),
             FlatErrors::new(vec![
@@ -231,4 +331,91 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn unique_items_errors_flatten() {
+        let unique_items = Message::new(
+            UniqueItemsError::new(vec![2, 4]),
+            UniqueItemsError::to_default_message,
+        );
+        assert_eq!(
+            Errors::Object(ObjectErrors::new(
+                vec![],
+                indexmap! {
+                    "items" => Errors::NewType(vec![Error::UniqueItems(unique_items.clone())]),
+                }
+            ))
+            .into_flat(),
+            FlatErrors::new(vec![
+                FlatError::new(
+                    JSONPointer::from([PathChunk::from("items".to_owned())].as_ref()),
+                    unique_items.error(),
+                ),
+                FlatError::new(
+                    JSONPointer::from(
+                        [PathChunk::from("items".to_owned()), PathChunk::from(2)].as_ref()
+                    ),
+                    unique_items.error(),
+                ),
+                FlatError::new(
+                    JSONPointer::from(
+                        [PathChunk::from("items".to_owned()), PathChunk::from(4)].as_ref()
+                    ),
+                    unique_items.error(),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn sorted_errors_flatten() {
+        let sorted = Message::new(SortedError::new(3), SortedError::to_default_message);
+        assert_eq!(
+            Errors::Object(ObjectErrors::new(
+                vec![],
+                indexmap! {
+                    "items" => Errors::NewType(vec![Error::Sorted(sorted.clone())]),
+                }
+            ))
+            .into_flat(),
+            FlatErrors::new(vec![FlatError::new(
+                JSONPointer::from(
+                    [PathChunk::from("items".to_owned()), PathChunk::from(3)].as_ref()
+                ),
+                sorted.error(),
+            )])
+        );
+    }
+
+    #[test]
+    fn keyed_items_errors_flatten() {
+        let maximum = Message::new(
+            MaximumError::new(Number::I32(1)),
+            MaximumError::to_default_message,
+        );
+        assert_eq!(
+            Errors::Array(
+                ArrayErrors::new(
+                    vec![],
+                    indexmap! {
+                        3 => Errors::NewType(vec![Error::Maximum(maximum.clone())]),
+                    }
+                )
+                .with_keyed_items(indexmap! {
+                    "user-42".to_owned() => Errors::NewType(vec![Error::Maximum(maximum.clone())]),
+                })
+            )
+            .into_flat(),
+            FlatErrors::new(vec![
+                FlatError::new(
+                    JSONPointer::from([PathChunk::from(3)].as_ref()),
+                    maximum.error(),
+                ),
+                FlatError::new(
+                    JSONPointer::from([PathChunk::from("user-42".to_owned())].as_ref()),
+                    maximum.error(),
+                ),
+            ])
+        );
+    }
 }
@@ -1,7 +1,8 @@
 use fluent_0::{FluentArgs, FluentBundle, FluentResource};
 
 use crate::validation::{
-    ArrayErrors, Errors, ItemErrorsMap, ObjectErrors, PropertyErrorsMap, VecErrors,
+    ArrayErrors, Errors, ItemErrorsMap, KeyedItemErrorsMap, ObjectErrors, PropertyErrorsMap,
+    VecErrors,
 };
 
 pub trait IntoLocalization {
@@ -10,7 +11,10 @@ pub trait IntoLocalization {
     fn into_localization(self, bundle: &FluentBundle<FluentResource>) -> Self::Target;
 }
 
-impl IntoLocalization for Errors<crate::validation::Error> {
+impl<E> IntoLocalization for Errors<E>
+where
+    E: IntoLocalization<Target = String>,
+{
     type Target = Errors<String>;
 
     fn into_localization(self, bundle: &FluentBundle<FluentResource>) -> Self::Target {
@@ -22,29 +26,40 @@ impl IntoLocalization for Errors<crate::validation::Error> {
     }
 }
 
-impl IntoLocalization for ArrayErrors<crate::validation::Error> {
+impl<E> IntoLocalization for ArrayErrors<E>
+where
+    E: IntoLocalization<Target = String>,
+{
     type Target = ArrayErrors<String>;
 
     fn into_localization(self, bundle: &FluentBundle<FluentResource>) -> Self::Target {
         ArrayErrors {
             errors: self.errors.into_localization(bundle),
             items: self.items.into_localization(bundle),
+            keyed_items: Box::new((*self.keyed_items).into_localization(bundle)),
         }
     }
 }
 
-impl IntoLocalization for ObjectErrors<crate::validation::Error> {
+impl<E> IntoLocalization for ObjectErrors<E>
+where
+    E: IntoLocalization<Target = String>,
+{
     type Target = ObjectErrors<String>;
 
     fn into_localization(self, bundle: &FluentBundle<FluentResource>) -> Self::Target {
         ObjectErrors {
             errors: self.errors.into_localization(bundle),
             properties: self.properties.into_localization(bundle),
+            internal: Box::new((*self.internal).into_localization(bundle)),
         }
     }
 }
 
-impl IntoLocalization for VecErrors<crate::validation::Error> {
+impl<E> IntoLocalization for VecErrors<E>
+where
+    E: IntoLocalization<Target = String>,
+{
     type Target = VecErrors<String>;
 
     fn into_localization(self, bundle: &FluentBundle<FluentResource>) -> Self::Target {
@@ -54,7 +69,10 @@ impl IntoLocalization for VecErrors<crate::validation::Error> {
     }
 }
 
-impl IntoLocalization for ItemErrorsMap<crate::validation::Error> {
+impl<E> IntoLocalization for ItemErrorsMap<E>
+where
+    E: IntoLocalization<Target = String>,
+{
     type Target = ItemErrorsMap<String>;
 
     fn into_localization(self, bundle: &FluentBundle<FluentResource>) -> Self::Target {
@@ -64,7 +82,23 @@ impl IntoLocalization for ItemErrorsMap<crate::validation::Error> {
     }
 }
 
-impl IntoLocalization for PropertyErrorsMap<crate::validation::Error> {
+impl<E> IntoLocalization for KeyedItemErrorsMap<E>
+where
+    E: IntoLocalization<Target = String>,
+{
+    type Target = KeyedItemErrorsMap<String>;
+
+    fn into_localization(self, bundle: &FluentBundle<FluentResource>) -> Self::Target {
+        self.into_iter()
+            .map(|(key, error)| (key, error.into_localization(bundle)))
+            .collect()
+    }
+}
+
+impl<E> IntoLocalization for PropertyErrorsMap<E>
+where
+    E: IntoLocalization<Target = String>,
+{
     type Target = PropertyErrorsMap<String>;
 
     fn into_localization(self, bundle: &FluentBundle<FluentResource>) -> Self::Target {
@@ -84,15 +118,43 @@ impl IntoLocalization for crate::validation::Error {
             Self::ExclusiveMinimum(message) => localize_or_default(&message, bundle),
             Self::ExclusiveMaximum(message) => localize_or_default(&message, bundle),
             Self::MultipleOf(message) => localize_or_default(&message, bundle),
+            Self::Finite(message) => localize_or_default(&message, bundle),
+            Self::NotNan(message) => localize_or_default(&message, bundle),
             Self::MinLength(message) => localize_or_default(&message, bundle),
             Self::MaxLength(message) => localize_or_default(&message, bundle),
             Self::Pattern(message) => localize_or_default(&message, bundle),
+            Self::NotPattern(message) => localize_or_default(&message, bundle),
+            Self::Contains(message) => localize_or_default(&message, bundle),
+            Self::NotContains(message) => localize_or_default(&message, bundle),
+            Self::StartsWith(message) => localize_or_default(&message, bundle),
+            Self::EndsWith(message) => localize_or_default(&message, bundle),
+            Self::Charset(message) => localize_or_default(&message, bundle),
+            Self::NotBlank(message) => localize_or_default(&message, bundle),
+            Self::Ascii(message) => localize_or_default(&message, bundle),
+            Self::Alphanumeric(message) => localize_or_default(&message, bundle),
+            Self::Luhn(message) => localize_or_default(&message, bundle),
+            Self::Iban(message) => localize_or_default(&message, bundle),
+            Self::Isbn(message) => localize_or_default(&message, bundle),
+            Self::Ean(message) => localize_or_default(&message, bundle),
+            Self::Email(message) => localize_or_default(&message, bundle),
+            Self::Url(message) => localize_or_default(&message, bundle),
             Self::MinItems(message) => localize_or_default(&message, bundle),
             Self::MaxItems(message) => localize_or_default(&message, bundle),
             Self::UniqueItems(message) => localize_or_default(&message, bundle),
+            Self::Sorted(message) => localize_or_default(&message, bundle),
+            Self::StrictlyIncreasing(message) => localize_or_default(&message, bundle),
+            Self::MaxSum(message) => localize_or_default(&message, bundle),
+            Self::MinSum(message) => localize_or_default(&message, bundle),
+            Self::MaxCount(message) => localize_or_default(&message, bundle),
+            Self::MinCount(message) => localize_or_default(&message, bundle),
             Self::MinProperties(message) => localize_or_default(&message, bundle),
             Self::MaxProperties(message) => localize_or_default(&message, bundle),
             Self::Enumerate(message) => localize_or_default(&message, bundle),
+            Self::Required(message) => localize_or_default(&message, bundle),
+            Self::AtLeastOneOf(message) => localize_or_default(&message, bundle),
+            Self::ExactlyOneOf(message) => localize_or_default(&message, bundle),
+            Self::MutuallyExclusive(message) => localize_or_default(&message, bundle),
+            Self::AllOrNone(message) => localize_or_default(&message, bundle),
             Self::Custom(message) => message,
             Self::Items(message) => format!("{message}"),
             Self::Properties(message) => format!("{message}"),
@@ -199,9 +261,7 @@ mod test {
                     args: vec![("name", FluentValue::from("John"))],
                 }),
             }
-            .into_message(crate::MaximumError {
-                maximum: Number::I32(10),
-            }),
+            .into_message(crate::MaximumError::new(Number::I32(10))),
         );
 
         assert_eq!(
@@ -43,17 +43,331 @@
 //! | String  | `#[validate(max_length = 5)]`        | [`ValidateMaxLength`](ValidateMaxLength)               | [maxLength](https://json-schema.org/understanding-json-schema/reference/string.html#length)            |
 //! | String  | `#[validate(min_length = 5)]`        | [`ValidateMinLength`](ValidateMinLength)               | [minLength](https://json-schema.org/understanding-json-schema/reference/string.html#length)            |
 //! | String  | `#[validate(pattern = r"^\d{5}$")]`  | [`ValidatePattern`](ValidatePattern)                   | [pattern](https://json-schema.org/understanding-json-schema/reference/string.html#regular-expressions) |
+//! | String  | `#[validate(pattern = MY_REGEX)]`    | [`ValidatePattern`](ValidatePattern)                   |                                                                                                        |
+//! | String  | `#[validate(not_pattern = r"\s")]`   | [`ValidateNotPattern`](ValidateNotPattern)             |                                                                                                        |
+//! | String  | `#[validate(contains = "@")]`        | [`ValidateContains`](ValidateContains)                 |                                                                                                        |
+//! | String  | `#[validate(not_contains = "..")]`   | [`ValidateNotContains`](ValidateNotContains)           |                                                                                                        |
+//! | String  | `#[validate(starts_with = "a")]`     | [`ValidateStartsWith`](ValidateStartsWith)             |                                                                                                        |
+//! | String  | `#[validate(ends_with = "z")]`       | [`ValidateEndsWith`](ValidateEndsWith)                 |                                                                                                        |
+//! | String  | `#[validate(not_blank)]`             | [`ValidateNotBlank`](ValidateNotBlank)                 |                                                                                                        |
+//! | String  | `#[validate(ascii)]`                 | [`ValidateAscii`](ValidateAscii)                       |                                                                                                        |
+//! | String  | `#[validate(alphanumeric)]`          | [`ValidateAlphanumeric`](ValidateAlphanumeric)         |                                                                                                        |
+//! | String  | `#[validate(charset = "a-z_")]`      | [`ValidateCharset`](ValidateCharset)                   |                                                                                                        |
+//! | String  | `#[validate(luhn)]`                  | [`ValidateLuhn`](ValidateLuhn)                         |                                                                                                        |
+//! | String  | `#[validate(iban)]`                  | [`ValidateIban`](ValidateIban)                         |                                                                                                        |
+//! | String  | `#[validate(isbn)]`                  | [`ValidateIsbn`](ValidateIsbn)                         |                                                                                                        |
+//! | String  | `#[validate(ean)]`                   | [`ValidateEan`](ValidateEan)                           |                                                                                                        |
+//! | String  | `#[validate(email)]`                 | [`ValidateEmail`](ValidateEmail)                       | [email](https://json-schema.org/understanding-json-schema/reference/string.html#email-addresses)       |
+//! | String  | `#[validate(url)]`                   | [`ValidateUrl`](ValidateUrl)                           | [uri](https://json-schema.org/understanding-json-schema/reference/string.html#resource-identifiers)    |
 //! | Numeric | `#[validate(maximum = 5)]`           | [`ValidateMaximum`](ValidateMaximum)                   | [maximum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)              |
 //! | Numeric | `#[validate(minimum = 5)]`           | [`ValidateMinimum`](ValidateMinimum)                   | [minimum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)              |
 //! | Numeric | `#[validate(exclusive_maximum = 5)]` | [`ValidateExclusiveMaximum`](ValidateExclusiveMaximum) | [exclusiveMaximum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)     |
 //! | Numeric | `#[validate(exclusive_minimum = 5)]` | [`ValidateExclusiveMinimum`](ValidateExclusiveMinimum) | [exclusiveMinimum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)     |
 //! | Numeric | `#[validate(multiple_of = 5)]`       | [`ValidateMultipleOf`](ValidateMultipleOf)             | [multipleOf](https://json-schema.org/understanding-json-schema/reference/numeric.html#multiples)       |
+//! | Numeric | `#[validate(finite)]`                | [`ValidateFinite`](ValidateFinite)                     |                                                                                                        |
+//! | Numeric | `#[validate(not_nan)]`               | [`ValidateNotNan`](ValidateNotNan)                     |                                                                                                        |
 //! | Object  | `#[validate(max_properties = 5)]`    | [`ValidateMaxProperties`](ValidateMaxProperties)       | [maxProperties](https://json-schema.org/understanding-json-schema/reference/object.html#size)          |
 //! | Object  | `#[validate(min_properties = 5)]`    | [`ValidateMinProperties`](ValidateMinProperties)       | [minProperties](https://json-schema.org/understanding-json-schema/reference/object.html#size)          |
 //! | Array   | `#[validate(max_items = 5)]`         | [`ValidateMaxItems`](ValidateMaxItems)                 | [maxItems](https://json-schema.org/understanding-json-schema/reference/array.html#length)              |
 //! | Array   | `#[validate(min_items = 5)]`         | [`ValidateMinItems`](ValidateMinItems)                 | [minItems](https://json-schema.org/understanding-json-schema/reference/array.html#length)              |
 //! | Array   | `#[validate(unique_items)]`          | [`ValidateUniqueItems`](ValidateUniqueItems)           | [uniqueItems](https://json-schema.org/understanding-json-schema/reference/array.html#unique_items)     |
+//! | Array   | `#[validate(unique_by = "id")]`      | [`ValidateUniqueItemsByKey`](ValidateUniqueItemsByKey) | -                                                                                                      |
+//! | Array   | `#[validate(sorted)]`                | [`ValidateSorted`](ValidateSorted)                     | -                                                                                                      |
+//! | Array   | `#[validate(sorted_by = "at")]`      | [`ValidateSortedBy`](ValidateSortedBy)                 | -                                                                                                      |
+//! | Array   | `#[validate(strictly_increasing)]`   | [`ValidateStrictlyIncreasing`](ValidateStrictlyIncreasing) | -                                                                                                      |
+//! | Array   | `#[validate(sum(maximum = 100))]`    | [`ValidateMaxSum`](ValidateMaxSum)                     | -                                                                                                      |
+//! | Array   | `#[validate(sum(minimum = 1))]`      | [`ValidateMinSum`](ValidateMinSum)                     | -                                                                                                      |
+//! | Array   | `#[validate(count_where(f, max = 1))]` | [`ValidateMaxCount`](ValidateMaxCount)                 | -                                                                                                      |
+//! | Array   | `#[validate(count_where(f, min = 1))]` | [`ValidateMinCount`](ValidateMinCount)                 | -                                                                                                      |
 //! | Generic | `#[validate(enumerate(5, 10, 15))]`  | [`ValidateEnumerate`](ValidateEnumerate)               | [enum](https://json-schema.org/understanding-json-schema/reference/generic.html#enumerated-values)     |
+//! | Generic | `#[validate(required)]`              | [`ValidateRequired`](ValidateRequired)                 | [required](https://json-schema.org/understanding-json-schema/reference/object.html#required-properties) |
+//!
+//! Array validations accept `Vec`, arrays, slices (`&[T]`, `Box<[T]>`), `VecDeque`, `LinkedList`, `HashSet`, `BTreeSet` and `IndexSet`.
+//! Object validations accept `HashMap`, `BTreeMap`, `IndexMap` and `serde_json::Map`,
+//! as well as `toml::Table` and `serde_yaml::Mapping` with the `toml` and `yaml` features.
+//! Other validations on a collection are applied to each of its items.
+//! The item errors of a `HashSet` have no index, because its iteration order is not deterministic,
+//! so they are reported in the `errors` of the set, sorted by the message and without the duplicates.
+//!
+//! `unique_by = "id"` compares the items by a field (`"a.b"` for a nested field), and `unique_by(key_fn)` by the key which `key_fn` returns.
+//! The key needs `Eq` and `Hash`.
+//! `unique_items(partial_eq)` accepts the items which are only `PartialEq`, such as floats,
+//! by comparing every pair of the items with [`ValidateUniqueItemsBy`](ValidateUniqueItemsBy).
+//! `UniqueItemsError::duplicates` holds the indices of the duplicated items.
+//! The flattened errors keep the error at the array, like `/items`,
+//! followed by the errors at the duplicated items, like `/items/3`.
+//!
+//! `sorted`, `sorted_by` and `strictly_increasing` report the `index` of the first item out of order.
+//! When the total goes over, `sum(maximum = ...)` reports the first item where the running sum goes over,
+//! and `count_where(f, max = ...)` reports the first matching item over the count.
+//! The float sums with `NaN` fail both `sum(maximum = ...)` and `sum(minimum = ...)`.
+//! Integer sums are added up in `i128` or `u128`, so the mixed signs don't overflow,
+//! and the sums of the `i128` or `u128` items which overflow fail.
+//!
+//! Multiple validations can be written in one attribute.
+//! The message of each validation is given in the form like `maximum(10, message = "...")`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(minimum = 0, maximum(10, message = "too large."), multiple_of = 2)]
+//!     val: i32,
+//! }
+//!
+//! assert_eq!(
+//!     SampleStruct { val: 13 }.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": ["too large.", "The value must be multiple of `2`."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Compile-Time Checks
+//!
+//! The arguments of the validations are checked when the derive expands.
+//!
+//! - The `pattern` regex is compiled, and an invalid regex is a compile error.
+//! - The numeric limit is checked against the primitive field type, like `maximum = 10` for a `f64` field (use `10.0`) or `maximum = 300` for a `u8` field.
+//! - `multiple_of = 0` and a negative `max_length` are rejected.
+//! - The bounds on the same field which no value can satisfy, like `minimum = 10` with `maximum = 5`
+//!   or `min_items = 4` with `max_items = 2`, are a compile error, even when they are written in separate attributes.
+//!   Such fields used to compile and fail on every value.
+//!   The bounds under the different `when` or `skip_if` conditions, or of the different `severity`, are not compared.
+//!
+//! ### `validator` Crate Compatible Syntax
+//!
+//! The attributes of the [`validator`](https://docs.rs/validator) crate are also accepted,
+//! so that one set of attributes drives both serde_valid and [`schemars`](https://docs.rs/schemars).
+//!
+//! | `validator` syntax                           | Serde Valid syntax                                            |
+//! | :------------------------------------------- | :------------------------------------------------------------ |
+//! | `length(min = 1, max = 5)`                   | `min_length`/`max_length`, `min_items`/`max_items` or `min_properties`/`max_properties` by the field type |
+//! | `length(equal = 5)`                          | The min and the max of the above                              |
+//! | `range(min = 1, max = 5)`                    | `minimum = 1, maximum = 5`                                    |
+//! | `range(exclusive_min = 1, exclusive_max = 5)` | `exclusive_minimum = 1, exclusive_maximum = 5`               |
+//! | `contains(pattern = "@")`                    | `contains = "@"`                                              |
+//! | `does_not_contain(pattern = "..")`           | `not_contains = ".."`                                         |
+//! | `regex(path = "MY_REGEX")`                   | `pattern = MY_REGEX`                                          |
+//! | `credit_card`                                | `luhn`                                                        |
+//! | `custom(function = "my_fn")`                 | `custom(my_fn)`                                               |
+//! | `nested`                                     | `#[validate]`                                                 |
+//!
+//! The integer bounds of `range` become the float bounds for the `f32` or `f64` field, like `minimum = 1.0`.
+//!
+//! The function of `custom(function = "my_fn")` returns `validator::ValidationError` as in the `validator` crate,
+//! and the error is shown by its `Display`, unless the message is given.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(length(min = 1, max = 5, message = "name is too long."))]
+//!     name: String,
+//!     #[validate(range(min = 0, max = 150))]
+//!     age: i32,
+//!     #[validate(email)]
+//!     email: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     name: String::from("serde_valid"),
+//!     age: 20,
+//!     email: String::from("user@example.com"),
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "name": {
+//!                 "errors": ["name is too long."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Conditional Validation
+//! The validations of the field can be conditional with the field options.
+//!
+//! - `when = "predicate"` runs the validations only when `predicate(&self)` is true.
+//! - `skip_if = "predicate"` skips the validations when `predicate(&field)` is true.
+//! - `required` needs the `Option` field to be `Some`, like `dependentRequired` of JSON Schema.
+//!
+//! `when` and `skip_if` apply only to the validations of their own `#[validate(...)]` attribute,
+//! so the other attributes of the field are always validated.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(PartialEq)]
+//! enum Kind {
+//!     Personal,
+//!     Business,
+//! }
+//!
+//! fn is_business(s: &SampleStruct) -> bool {
+//!     s.kind == Kind::Business
+//! }
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     kind: Kind,
+//!     #[validate(when = "is_business", required)]
+//!     company: Option<String>,
+//!     #[validate(skip_if = "str::is_empty", min_length = 8)]
+//!     nickname: &'static str,
+//! }
+//!
+//! let s = SampleStruct {
+//!     kind: Kind::Business,
+//!     company: None,
+//!     nickname: "",
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "company": {
+//!                 "errors": ["The value is required."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Skipped and Default Fields
+//! The fields with `#[serde(skip)]` or `#[serde(skip_deserializing)]` are not validated,
+//! because they are not part of the input.
+//! With `#[validate(internal)]`, they are validated and the errors are output to `internal`.
+//!
+//! The fields with `#[serde(default)]` are validated like others.
+//! With `#[validate(skip_default)]`, the validations are skipped while the field is the default value.
+//! It needs `#[serde(default)]` or `#[serde(default = "path")]` on the field, and the field type needs `PartialEq`.
+//! The value is compared with the default, so the default value sent explicitly is skipped as well.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(serde::Deserialize, Validate)]
+//! struct SampleStruct {
+//!     #[serde(skip)]
+//!     #[validate(internal, maximum = 5)]
+//!     cache_size: usize,
+//!     #[serde(default)]
+//!     #[validate(skip_default, minimum = 1)]
+//!     retry: i32,
+//! }
+//!
+//! let s = SampleStruct { cache_size: 10, retry: 0 };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {},
+//!         "internal": {
+//!             "cache_size": {
+//!                 "errors": ["The number must be `<= 5`."]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+//!
+//! ### Severity
+//! With `severity = "warning"`, the validations of the attribute report warnings instead of errors.
+//! The warnings don't fail `validate`, and `validate_with_warnings` reports them alongside the errors.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 1000)]
+//!     #[validate(maximum = 100, severity = "warning")]
+//!     val: i32,
+//! }
+//!
+//! let s = SampleStruct { val: 150 };
+//!
+//! assert!(s.validate().is_ok());
+//!
+//! let report = s.validate_with_warnings();
+//! assert!(report.errors.is_none());
+//! assert_eq!(
+//!     serde_json::to_value(report.warnings.unwrap()).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": ["The number must be `<= 100`."]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+//!
+//! ### Sensitive Fields
+//! The errors don't keep the rejected value by default.
+//! With `capture_value`, the value is available by `Error::value` and in the flattened errors.
+//! It works for the strings, the numbers and their collections, which implement `CaptureValue`.
+//! With `sensitive`, the captured value is replaced with `[REDACTED]`, and `enumerate` doesn't echo the allowed values.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(enumerate("alpha", "bravo"), sensitive)]
+//!     code: String,
+//!     #[validate(max_length = 3, capture_value)]
+//!     name: String,
+//! }
+//!
+//! let errors = SampleStruct {
+//!     code: "charlie".to_string(),
+//!     name: "taro".to_string(),
+//! }
+//! .validate()
+//! .unwrap_err();
+//!
+//! assert_eq!(
+//!     errors.to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "code": {
+//!                 "errors": ["The value is not allowed."]
+//!             },
+//!             "name": {
+//!                 "errors": ["The length of the value must be `<= 3`."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
 //!
 //! ## Complete Constructor (Deserialization)
 //!
@@ -126,22 +440,106 @@
 //! #[derive(Validate)]
 //! struct SampleStruct {
 //!     #[validate(min_items = 4, message_fn(min_error_message))]
+//!     val1: Vec<i32>,
 //!     #[validate(max_items = 2, message = "this is max custom message.")]
-//!     val: Vec<i32>,
+//!     val2: Vec<i32>,
 //! }
 //!
-//! let s = SampleStruct { val: vec![1, 2, 3] };
+//! let s = SampleStruct {
+//!     val1: vec![1, 2, 3],
+//!     val2: vec![1, 2, 3],
+//! };
 //!
 //! assert_eq!(
 //!     s.validate().unwrap_err().to_string(),
 //!     json!({
 //!         "errors": [],
 //!         "properties": {
-//!             "val": {
-//!                 "errors": [
-//!                     "this is min custom message_fn.",
-//!                     "this is max custom message."
-//!                 ]
+//!             "val1": {
+//!                 "errors": ["this is min custom message_fn."]
+//!             },
+//!             "val2": {
+//!                 "errors": ["this is max custom message."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! The `message` is a template, whose placeholders like `{max_length}` are the fields of the error params,
+//! and `{value}` is the rejected value, which needs `#[validate(capture_value)]` on the field.
+//! The unknown placeholder is a compile error, and `{{` or `}}` escapes the braces.
+//! The length, items and properties errors also have the measured `{length}`, `{items_count}` or `{size}`,
+//! like `message = "must be at most {max_length} characters, got {length}"`.
+//!
+//! **Breaking change**: the message which has a literal `{` or `}`, like `message = "one of {a, b}"`,
+//! is now a compile error. Escape the braces as `{{` and `}}`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(capture_value)]
+//!     #[validate(max_length = 3, message = "{value} must be at most {max_length} characters.")]
+//!     name: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     name: "taro".to_string(),
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "name": {
+//!                 "errors": ["taro must be at most 3 characters."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! The message of `nested`, `custom(...)` and `#[rule(...)]` replaces their errors with the single error,
+//! and the `message_fn` takes the replaced error.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Address {
+//!     #[validate(min_length = 1)]
+//!     city: String,
+//! }
+//!
+//! #[derive(Validate)]
+//! #[rule(builtin::at_least_one_of(email, phone), message = "Either of [{fields}] is needed.")]
+//! struct SampleStruct {
+//!     #[validate(nested, message = "The address is invalid.")]
+//!     address: Address,
+//!     email: Option<String>,
+//!     phone: Option<String>,
+//! }
+//!
+//! let s = SampleStruct {
+//!     address: Address { city: "".to_string() },
+//!     email: None,
+//!     phone: None,
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": ["Either of [email, phone] is needed."],
+//!         "properties": {
+//!             "address": {
+//!                 "errors": ["The address is invalid."]
 //!             }
 //!         }
 //!     })
@@ -209,6 +607,44 @@
 //! );
 //! ```
 //!
+//! The rules for the group of `Option` fields are built in, under the `builtin::` prefix
+//! so that they don't shadow the rule functions of the same names.
+//!
+//! | Rule                                         | Error                                              |
+//! | :------------------------------------------: | :------------------------------------------------: |
+//! | `#[rule(builtin::at_least_one_of(a, b))]`    | [`AtLeastOneOfError`](AtLeastOneOfError)           |
+//! | `#[rule(builtin::exactly_one_of(a, b))]`     | [`ExactlyOneOfError`](ExactlyOneOfError)           |
+//! | `#[rule(builtin::mutually_exclusive(a, b))]` | [`MutuallyExclusiveError`](MutuallyExclusiveError) |
+//! | `#[rule(builtin::all_or_none(a, b))]`        | [`AllOrNoneError`](AllOrNoneError)                 |
+//!
+//! The field names in the messages follow `#[serde(rename)]`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! #[rule(builtin::at_least_one_of(email, phone))]
+//! struct SampleStruct {
+//!     email: Option<String>,
+//!     phone: Option<String>,
+//! }
+//!
+//! let s = SampleStruct {
+//!     email: None,
+//!     phone: None,
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": ["At least one of [email, phone] must be present."],
+//!         "properties": {}
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
 //! If you want to use rule to unnamed fields struct, just like this,
 //!
 //! ```rust
@@ -256,10 +692,19 @@
 //! assert!(s.validate().is_ok());
 //! ```
 //!
+//! The nested `#[validate]` works with the smart pointers (`Box`, `Rc`, `Arc` and `Cow`),
+//! the tuples, the slices and the std collections (`Vec`, `VecDeque`, `LinkedList`, `BTreeSet` and `HashSet`).
+//! The item errors of them are keyed by the index of the iteration order.
+//! The items of `HashSet` need `Ord`, because they are keyed by the sorted order as `BTreeSet`
+//! instead of the iteration order, which is not deterministic.
+//!
 //! ## Validation Errors Format
 //! ### Named Struct
 //! Field errors are output to `properties`.
 //!
+//! The errors of a nested field with `#[serde(flatten)]` are merged into the parent `properties`,
+//! in the same way as serde flattens the fields.
+//!
 //! ```rust
 //! use serde_json::json;
 //! use serde_valid::Validate;
@@ -342,7 +787,7 @@
 //! ```
 //!
 //! ### Named Enum
-//! Variant errors are output to `properties`.
+//! Variant errors are output to `properties`, under the variant name.
 //!
 //! ```rust
 //! use serde_json::json;
@@ -365,11 +810,16 @@
 //!     json!({
 //!         "errors": [],
 //!         "properties": {
-//!             "a": {
-//!                 "errors": ["The number must be `<= 5`."]
-//!             },
-//!             "b": {
-//!                 "errors": ["The number must be `<= 5`."]
+//!             "Named": {
+//!                 "errors": [],
+//!                 "properties": {
+//!                     "a": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     },
+//!                     "b": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     }
+//!                 }
 //!             }
 //!         }
 //!     })
@@ -378,7 +828,7 @@
 //! ```
 //!
 //! ### Unnamed Enum
-//! Variant errors are output to `items`. The key for `items` is guaranteed to be a string of positive numbers.
+//! Variant errors are output to `items`, under the variant name. The key for `items` is guaranteed to be a string of positive numbers.
 //!
 //! ```rust
 //! use serde_json::json;
@@ -398,12 +848,17 @@
 //!     s.validate().unwrap_err().to_string(),
 //!     json!({
 //!         "errors": [],
-//!         "items": {
-//!             "0": {
-//!                 "errors": ["The number must be `<= 5`."]
-//!             },
-//!             "1": {
-//!                 "errors": ["The number must be `<= 5`."]
+//!         "properties": {
+//!             "Unnamed": {
+//!                 "errors": [],
+//!                 "items": {
+//!                     "0": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     },
+//!                     "1": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     }
+//!                 }
 //!             }
 //!         }
 //!     })
@@ -412,7 +867,7 @@
 //! ```
 //!
 //! ### Newtype Enum
-//! Variant errors are output to `errors`.
+//! Variant errors are output to `errors`, under the variant name.
 //!
 //! ```rust
 //! use serde_json::json;
@@ -430,11 +885,168 @@
 //! assert_eq!(
 //!     s.validate().unwrap_err().to_string(),
 //!     json!({
-//!         "errors": ["The number must be `<= 5`."]
+//!         "errors": [],
+//!         "properties": {
+//!             "NewType": {
+//!                 "errors": ["The number must be `<= 5`."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Enum Representation
+//! The variant errors follow [the serde enum representation](https://serde.rs/enum-representations.html).
+//! The default externally tagged enum outputs them under the variant name,
+//! and `#[serde(rename)]` and `#[serde(rename_all)]` are applied to it.
+//! The adjacently tagged enum `#[serde(tag = "...", content = "...")]` outputs them under the content key,
+//! while the internally tagged enum `#[serde(tag = "...")]` and the untagged enum `#[serde(untagged)]` output them as is.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(serde::Deserialize, Validate)]
+//! #[serde(tag = "type", content = "value")]
+//! enum SampleEnum {
+//!     Named {
+//!         #[validate(maximum = 5)]
+//!         a: i32,
+//!     },
+//! }
+//!
+//! let s = SampleEnum::Named { a: 6 };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "value": {
+//!                 "errors": [],
+//!                 "properties": {
+//!                     "a": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     }
+//!                 }
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Keyed Items
+//! The item errors of a collection are output to `items` keyed by the index.
+//! With `#[validate(key = "id")]` instead of `#[validate]`, they are output to `keyed_items` keyed by the field of the item,
+//! so the errors still point at the right item after the items are sorted or filtered.
+//! The keys must be unique, and the duplicated keys are reported as [`UniqueItemsError`](UniqueItemsError) of the collection.
+//!
+//! The flattened errors locate the keyed items by the key, like `/users/user-42`.
+//! It is not a JSON Pointer into the payload, because the key takes the place of the index.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct User {
+//!     id: String,
+//!     #[validate(max_length = 5)]
+//!     name: String,
+//! }
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(key = "id")]
+//!     users: Vec<User>,
+//! }
+//!
+//! let s = SampleStruct {
+//!     users: vec![User { id: "user-42".to_string(), name: "Alexander".to_string() }],
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "users": {
+//!                 "errors": [],
+//!                 "items": {},
+//!                 "keyed_items": {
+//!                     "user-42": {
+//!                         "errors": [],
+//!                         "properties": {
+//!                             "name": {
+//!                                 "errors": ["The length of the value must be `<= 5`."]
+//!                             }
+//!                         }
+//!                     }
+//!                 }
+//!             }
+//!         }
 //!     })
 //!     .to_string()
 //! );
 //! ```
+//!
+//! ### Custom Error Type
+//! The errors are `serde_valid::validation::Error` by default.
+//! With `#[serde_valid(error = MyError)]`, where `MyError: From<serde_valid::validation::Error>`,
+//! the errors tree holds `MyError` instead, so the custom functions and the rules can return `MyError`.
+//! The nested `#[validate]` types can use either error type.
+//! `IntoFlat` and `IntoLocalization` work with the errors tree when `MyError` implements them.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Debug, serde::Serialize)]
+//! struct MyError {
+//!     code: &'static str,
+//!     message: String,
+//! }
+//!
+//! impl From<serde_valid::validation::Error> for MyError {
+//!     fn from(error: serde_valid::validation::Error) -> Self {
+//!         Self { code: "invalid", message: error.to_string() }
+//!     }
+//! }
+//!
+//! fn is_not_reserved(name: &str) -> Result<(), MyError> {
+//!     match name {
+//!         "admin" => Err(MyError { code: "reserved", message: "The name is reserved.".to_string() }),
+//!         _ => Ok(()),
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[serde_valid(error = MyError)]
+//! struct SampleStruct {
+//!     #[validate(custom(is_not_reserved))]
+//!     #[validate(max_length = 3)]
+//!     name: String,
+//! }
+//!
+//! let s = SampleStruct { name: "admin".to_string() };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "name": {
+//!                 "errors": [
+//!                     {"code": "reserved", "message": "The name is reserved."},
+//!                     {"code": "invalid", "message": "The length of the value must be `<= 3`."}
+//!                 ]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
 
 pub mod error;
 mod features;
@@ -445,17 +1057,31 @@ pub mod validation;
 use indexmap::IndexMap;
 
 pub use error::{
-    EnumerateError, Error, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError,
-    MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
-    MinPropertiesError, MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    AllOrNoneError, AlphanumericError, AsciiError, AtLeastOneOfError, CharsetError, ContainsError,
+    EanError, EmailError, EndsWithError, EnumerateError, Error, ExactlyOneOfError,
+    ExclusiveMaximumError, ExclusiveMinimumError, FiniteError, IbanError, IsbnError, LuhnError,
+    MaxCountError, MaxItemsError, MaxLengthError, MaxPropertiesError, MaxSumError, MaximumError,
+    MinCountError, MinItemsError, MinLengthError, MinPropertiesError, MinSumError, MinimumError,
+    MultipleOfError, MutuallyExclusiveError, NotBlankError, NotContainsError, NotNanError,
+    NotPatternError, PatternError, RequiredError, SortedError, StartsWithError,
+    StrictlyIncreasingError, UniqueItemsError, UrlError,
 };
 pub use validation::{
-    ValidateEnumerate, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaxItems,
-    ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
-    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
-    ValidateUniqueItems,
+    ValidateAlphanumeric, ValidateAscii, ValidateCharset, ValidateContains, ValidateEan,
+    ValidateEmail, ValidateEndsWith, ValidateEnumerate, ValidateExclusiveMaximum,
+    ValidateExclusiveMinimum, ValidateFinite, ValidateIban, ValidateIsbn, ValidateLuhn,
+    ValidateMaxCount, ValidateMaxItems, ValidateMaxLength, ValidateMaxProperties, ValidateMaxSum,
+    ValidateMaximum, ValidateMinCount, ValidateMinItems, ValidateMinLength, ValidateMinProperties,
+    ValidateMinSum, ValidateMinimum, ValidateMultipleOf, ValidateNotBlank, ValidateNotContains,
+    ValidateNotNan, ValidateNotPattern, ValidatePattern, ValidateRequired, ValidateSorted,
+    ValidateSortedBy, ValidateStartsWith, ValidateStrictlyIncreasing, ValidateUniqueItems,
+    ValidateUniqueItemsBy, ValidateUniqueItemsByKey, ValidateUrl,
 };
 
+pub use validation::PatternMode;
+#[cfg(feature = "fancy_regex")]
+pub use validation::{ValidateEcmaNotPattern, ValidateEcmaPattern};
+
 #[cfg(feature = "flatten")]
 pub use features::flatten;
 #[cfg(feature = "fluent")]
@@ -466,69 +1092,412 @@ pub use features::toml;
 pub use features::yaml;
 
 pub mod export {
-    pub use once_cell::sync::OnceCell;
+    #[cfg(feature = "fancy_regex")]
+    pub use fancy_regex::Regex as FancyRegex;
+    pub use once_cell::sync::{Lazy, OnceCell};
+    pub use regex::Regex;
 }
 
-pub trait Validate {
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors>;
+/// Validation of the value, which is implemented by `#[derive(Validate)]`.
+///
+/// The error type of the tree is [`validation::Error`] by default,
+/// and can be replaced with `#[serde_valid(error = MyError)]`.
+pub trait Validate<E = validation::Error> {
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>>;
+
+    /// Validate the value, reporting the warnings of `severity = "warning"` alongside the errors.
+    ///
+    /// Only the errors fail [`Validate::validate`].
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        self.validate().into()
+    }
+}
+
+/// The errors of the items, or `None` when no item has errors.
+fn items_errors<E>(
+    item_errors: self::validation::ItemErrorsMap<E>,
+) -> Option<self::validation::Errors<E>> {
+    if item_errors.is_empty() {
+        None
+    } else {
+        Some(self::validation::Errors::Array(
+            validation::ArrayErrors::new(vec![], item_errors),
+        ))
+    }
 }
 
-impl<T> Validate for Vec<T>
+/// Validate the items, which are keyed by the index of the iteration order.
+fn validate_items<'a, E, T, I>(items: I) -> self::validation::Report<E>
 where
-    T: Validate,
+    T: Validate<E> + 'a + ?Sized,
+    I: IntoIterator<Item = &'a T>,
 {
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
+    let mut item_errors = IndexMap::new();
+    let mut item_warnings = IndexMap::new();
 
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
-            }
+    for (index, item) in items.into_iter().enumerate() {
+        let report = item.validate_with_warnings();
+        if let Some(errors) = report.errors {
+            item_errors.insert(index, errors);
+        }
+        if let Some(warnings) = report.warnings {
+            item_warnings.insert(index, warnings);
         }
+    }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::ArrayErrors::new(vec![], items),
-            ))
+    self::validation::Report::new(items_errors(item_errors), items_errors(item_warnings))
+}
+
+impl<E, T> Validate<E> for [T]
+where
+    T: Validate<E>,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        validate_items(self).into_result()
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        validate_items(self)
+    }
+}
+
+impl<E, T> Validate<E> for Vec<T>
+where
+    T: Validate<E>,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        validate_items(self).into_result()
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        validate_items(self)
+    }
+}
+
+impl<E, T, const N: usize> Validate<E> for [T; N]
+where
+    T: Validate<E>,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        validate_items(self).into_result()
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        validate_items(self)
+    }
+}
+
+impl<E, T> Validate<E> for std::collections::VecDeque<T>
+where
+    T: Validate<E>,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        validate_items(self).into_result()
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        validate_items(self)
+    }
+}
+
+impl<E, T> Validate<E> for std::collections::LinkedList<T>
+where
+    T: Validate<E>,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        validate_items(self).into_result()
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        validate_items(self)
+    }
+}
+
+/// The items are keyed by the sorted order.
+impl<E, T> Validate<E> for std::collections::BTreeSet<T>
+where
+    T: Validate<E>,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        validate_items(self).into_result()
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        validate_items(self)
+    }
+}
+
+/// The items are keyed by the sorted order as `BTreeSet`, because the iteration order is not deterministic.
+impl<E, T, S> Validate<E> for std::collections::HashSet<T, S>
+where
+    T: Validate<E> + Ord,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        self.validate_with_warnings().into_result()
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        let mut items = self.iter().collect::<Vec<_>>();
+        items.sort();
+        validate_items(items)
+    }
+}
+
+impl<E, T> Validate<E> for Option<T>
+where
+    T: Validate<E>,
+{
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        match self {
+            Some(value) => value.validate_with_warnings(),
+            None => self::validation::Report::default(),
         }
     }
 }
 
-impl<T, const N: usize> Validate for [T; N]
+macro_rules! impl_validate_for_pointer {
+    ($($pointer:ty),*) => {
+        $(
+            impl<E, T> Validate<E> for $pointer
+            where
+                T: Validate<E> + ?Sized,
+            {
+                fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+                    (**self).validate()
+                }
+
+                fn validate_with_warnings(&self) -> self::validation::Report<E> {
+                    (**self).validate_with_warnings()
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_for_pointer!(&T, &mut T, Box<T>, std::rc::Rc<T>, std::sync::Arc<T>);
+
+impl<E, T> Validate<E> for std::borrow::Cow<'_, T>
 where
-    T: Validate,
+    T: Validate<E> + ToOwned + ?Sized,
 {
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
+    fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+        (**self).validate()
+    }
+
+    fn validate_with_warnings(&self) -> self::validation::Report<E> {
+        (**self).validate_with_warnings()
+    }
+}
+
+/// Validate the items, which are keyed by the key of each item instead of the index.
+///
+/// This is used by `#[validate(key = "id")]`.
+/// The errors are output to `keyed_items` instead of `items`.
+/// The keys must be unique, and the duplicated keys are reported as [`UniqueItemsError`],
+/// whose `duplicates` are the indices of the later items with the same key.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateKeyedItems};
+///
+/// #[derive(Validate)]
+/// struct User {
+///     id: String,
+///     #[validate(maximum = 150)]
+///     age: u32,
+/// }
+///
+/// let users = vec![
+///     User { id: "alice".to_string(), age: 20 },
+///     User { id: "bob".to_string(), age: 200 },
+/// ];
+///
+/// assert_eq!(
+///     serde_json::to_value(users.validate_keyed_items(|user| user.id.clone()).unwrap_err())
+///         .unwrap(),
+///     json!({
+///         "errors": [],
+///         "items": {},
+///         "keyed_items": {
+///             "bob": {
+///                 "errors": [],
+///                 "properties": {
+///                     "age": {"errors": ["The number must be `<= 150`."]}
+///                 }
+///             }
+///         }
+///     })
+/// );
+/// ```
+pub trait ValidateKeyedItems<E = validation::Error> {
+    type Item;
+
+    fn validate_keyed_items<F>(
+        &self,
+        key: F,
+    ) -> std::result::Result<(), self::validation::Errors<E>>
+    where
+        F: Fn(&Self::Item) -> String,
+    {
+        self.validate_keyed_items_with_warnings(key).into_result()
+    }
 
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
+    /// Validate the keyed items, reporting the warnings alongside the errors.
+    fn validate_keyed_items_with_warnings<F>(&self, key: F) -> self::validation::Report<E>
+    where
+        F: Fn(&Self::Item) -> String;
+}
+
+/// The errors of the keyed items, or `None` when there are no errors.
+fn keyed_items_errors<E>(
+    errors: self::validation::VecErrors<E>,
+    keyed_item_errors: self::validation::KeyedItemErrorsMap<E>,
+) -> Option<self::validation::Errors<E>> {
+    if errors.is_empty() && keyed_item_errors.is_empty() {
+        None
+    } else {
+        Some(self::validation::Errors::Array(
+            validation::ArrayErrors::new(errors, IndexMap::new())
+                .with_keyed_items(keyed_item_errors),
+        ))
+    }
+}
+
+/// Insert the errors of the item, merging them into the errors of the same key.
+fn insert_keyed_item_errors<E>(
+    keyed_item_errors: &mut self::validation::KeyedItemErrorsMap<E>,
+    key: String,
+    errors: self::validation::Errors<E>,
+) {
+    match keyed_item_errors.get_mut(&key) {
+        Some(key_errors) => key_errors.merge(errors),
+        None => {
+            keyed_item_errors.insert(key, errors);
+        }
+    }
+}
+
+impl<E, T> ValidateKeyedItems<E> for T
+where
+    T: traits::Items + ?Sized,
+    T::Item: Validate<E>,
+    E: From<validation::Error>,
+{
+    type Item = T::Item;
+
+    fn validate_keyed_items_with_warnings<F>(&self, key: F) -> self::validation::Report<E>
+    where
+        F: Fn(&Self::Item) -> String,
+    {
+        let mut keys = std::collections::HashSet::new();
+        let mut duplicates = vec![];
+        let mut keyed_item_errors = IndexMap::new();
+        let mut keyed_item_warnings = IndexMap::new();
+
+        for (index, item) in self.items().enumerate() {
+            let item_key = key(item);
+            if !keys.insert(item_key.clone()) {
+                duplicates.push(index);
+            }
+            let report = item.validate_with_warnings();
+            if let Some(errors) = report.errors {
+                insert_keyed_item_errors(&mut keyed_item_errors, item_key.clone(), errors);
+            }
+            if let Some(warnings) = report.warnings {
+                insert_keyed_item_errors(&mut keyed_item_warnings, item_key, warnings);
             }
         }
 
-        if items.is_empty() {
-            Ok(())
+        let errors = if duplicates.is_empty() {
+            vec![]
         } else {
-            Err(self::validation::Errors::Array(
-                validation::ArrayErrors::new(vec![], items),
-            ))
-        }
+            vec![E::from(validation::Error::UniqueItems(
+                validation::Message::new(
+                    UniqueItemsError::new(duplicates),
+                    <UniqueItemsError as validation::ToDefaultMessage>::to_default_message,
+                ),
+            ))]
+        };
+
+        self::validation::Report::new(
+            keyed_items_errors(errors, keyed_item_errors),
+            keyed_items_errors(vec![], keyed_item_warnings),
+        )
     }
 }
 
-impl<T> Validate for Option<T>
+impl<E, T> ValidateKeyedItems<E> for Option<T>
 where
-    T: Validate,
+    T: ValidateKeyedItems<E>,
 {
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+    type Item = T::Item;
+
+    fn validate_keyed_items_with_warnings<F>(&self, key: F) -> self::validation::Report<E>
+    where
+        F: Fn(&Self::Item) -> String,
+    {
         match self {
-            Some(value) => value.validate(),
-            None => Ok(()),
+            Some(value) => value.validate_keyed_items_with_warnings(key),
+            None => self::validation::Report::default(),
         }
     }
 }
 
+macro_rules! impl_validate_for_tuple {
+    ($(($($index:tt $T:ident),+)),*) => {
+        $(
+            impl<E, $($T),+> Validate<E> for ($($T,)+)
+            where
+                $($T: Validate<E>),+
+            {
+                fn validate(&self) -> std::result::Result<(), self::validation::Errors<E>> {
+                    Validate::<E>::validate_with_warnings(self).into_result()
+                }
+
+                fn validate_with_warnings(&self) -> self::validation::Report<E> {
+                    let mut item_errors = IndexMap::new();
+                    let mut item_warnings = IndexMap::new();
+
+                    $(
+                        let report = self.$index.validate_with_warnings();
+                        if let Some(errors) = report.errors {
+                            item_errors.insert($index, errors);
+                        }
+                        if let Some(warnings) = report.warnings {
+                            item_warnings.insert($index, warnings);
+                        }
+                    )+
+
+                    self::validation::Report::new(
+                        items_errors(item_errors),
+                        items_errors(item_warnings),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_for_tuple!(
+    (0 T0),
+    (0 T0, 1 T1),
+    (0 T0, 1 T1, 2 T2),
+    (0 T0, 1 T1, 2 T2, 3 T3),
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4),
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5),
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6),
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7),
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8),
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9),
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10),
+    (0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11)
+);
+
 pub use serde_valid_derive::Validate;
@@ -1,9 +1,15 @@
 mod is_match;
-mod is_unique;
+mod items;
+mod items_count;
 mod length;
+mod lossy_str;
 mod size;
+mod summable;
 
 pub use is_match::IsMatch;
-pub use is_unique::IsUnique;
+pub use items::Items;
+pub use items_count::ItemsCount;
 pub use length::Length;
+pub use lossy_str::LossyStr;
 pub use size::Size;
+pub use summable::Summable;
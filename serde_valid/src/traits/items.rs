@@ -0,0 +1,49 @@
+use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+
+use indexmap::IndexSet;
+
+pub trait Items {
+    type Item;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_>;
+}
+
+macro_rules! impl_items {
+    (impl<$($generic:ident),*> Items for $ty:ty) => {
+        impl<$($generic),*> Items for $ty {
+            type Item = T;
+
+            fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+                Box::new(self.iter())
+            }
+        }
+    };
+}
+
+impl_items!(impl<T> Items for [T]);
+impl_items!(impl<T> Items for Vec<T>);
+impl_items!(impl<T> Items for Box<[T]>);
+impl_items!(impl<T> Items for VecDeque<T>);
+impl_items!(impl<T> Items for LinkedList<T>);
+impl_items!(impl<T, S> Items for HashSet<T, S>);
+impl_items!(impl<T> Items for BTreeSet<T>);
+impl_items!(impl<T, S> Items for IndexSet<T, S>);
+
+impl<T, const N: usize> Items for [T; N] {
+    type Item = T;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<T> Items for &T
+where
+    T: Items + ?Sized,
+{
+    type Item = T::Item;
+
+    fn items(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        (**self).items()
+    }
+}
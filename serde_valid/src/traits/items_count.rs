@@ -0,0 +1,70 @@
+use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+
+use indexmap::IndexSet;
+
+pub trait ItemsCount {
+    fn items_count(&self) -> usize;
+}
+
+impl<T> ItemsCount for [T] {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> ItemsCount for [T; N] {
+    fn items_count(&self) -> usize {
+        N
+    }
+}
+
+impl<T> ItemsCount for Vec<T> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ItemsCount for VecDeque<T> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ItemsCount for LinkedList<T> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> ItemsCount for HashSet<T, S> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ItemsCount for BTreeSet<T> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> ItemsCount for IndexSet<T, S> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ItemsCount for &T
+where
+    T: ItemsCount + ?Sized,
+{
+    fn items_count(&self) -> usize {
+        (**self).items_count()
+    }
+}
+
+impl<T> ItemsCount for Box<[T]> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+}
@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+pub trait LossyStr {
+    fn lossy_str(&self) -> Cow<'_, str>;
+}
+
+macro_rules! impl_for_str {
+    ($ty:ty) => {
+        impl LossyStr for $ty {
+            fn lossy_str(&self) -> Cow<'_, str> {
+                Cow::Borrowed(self)
+            }
+        }
+    };
+}
+
+impl_for_str!(str);
+impl_for_str!(&str);
+impl_for_str!(String);
+impl_for_str!(std::borrow::Cow<'_, str>);
+
+macro_rules! impl_for_os_str {
+    ($ty:ty) => {
+        impl LossyStr for $ty {
+            fn lossy_str(&self) -> Cow<'_, str> {
+                self.to_string_lossy()
+            }
+        }
+    };
+}
+
+impl_for_os_str!(std::ffi::OsStr);
+impl_for_os_str!(&std::ffi::OsStr);
+impl_for_os_str!(std::ffi::OsString);
+impl_for_os_str!(std::borrow::Cow<'_, std::ffi::OsStr>);
+
+macro_rules! impl_for_path {
+    ($ty:ty) => {
+        impl LossyStr for $ty {
+            fn lossy_str(&self) -> Cow<'_, str> {
+                self.as_os_str().to_string_lossy()
+            }
+        }
+    };
+}
+
+impl_for_path!(std::path::Path);
+impl_for_path!(&std::path::Path);
+impl_for_path!(std::path::PathBuf);
+impl_for_path!(std::borrow::Cow<'_, std::path::Path>);
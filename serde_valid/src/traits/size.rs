@@ -1,11 +1,13 @@
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 
+use indexmap::IndexMap;
+
 pub trait Size {
     fn size(&self) -> usize;
 }
 
-impl<K, V> Size for HashMap<K, V> {
+impl<K, V, S> Size for HashMap<K, V, S> {
     fn size(&self) -> usize {
         self.len()
     }
@@ -17,8 +19,28 @@ impl<K, V> Size for BTreeMap<K, V> {
     }
 }
 
+impl<K, V, S> Size for IndexMap<K, V, S> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
 impl Size for serde_json::Map<String, serde_json::Value> {
     fn size(&self) -> usize {
         self.len()
     }
 }
+
+#[cfg(feature = "toml")]
+impl Size for serde_toml::Table {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl Size for serde_yaml::Mapping {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
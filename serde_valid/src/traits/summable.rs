@@ -0,0 +1,65 @@
+pub trait Summable: Copy + PartialOrd {
+    /// The type of the sum, which is wider than the integer items,
+    /// so that the sum of the mixed signs neither overflows nor saturates.
+    type Sum: Copy + PartialOrd;
+
+    fn zero() -> Self::Sum;
+
+    fn to_sum(self) -> Self::Sum;
+
+    /// The sum of the `i128` or `u128` items can still overflow, which is `None`.
+    fn checked_sum(sum: Self::Sum, item: Self) -> Option<Self::Sum>;
+}
+
+macro_rules! impl_for_integer {
+    ($sum:ty; $($ty:ty),*) => {
+        $(
+            impl Summable for $ty {
+                type Sum = $sum;
+
+                fn zero() -> Self::Sum {
+                    0
+                }
+
+                fn to_sum(self) -> Self::Sum {
+                    self as $sum
+                }
+
+                fn checked_sum(sum: Self::Sum, item: Self) -> Option<Self::Sum> {
+                    sum.checked_add(item as $sum)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_integer!(i128; i8, i16, i32, i64, isize);
+impl_for_integer!(u128; u8, u16, u32, u64, usize);
+#[cfg(feature = "i128")]
+impl_for_integer!(i128; i128);
+#[cfg(feature = "i128")]
+impl_for_integer!(u128; u128);
+
+macro_rules! impl_for_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Summable for $ty {
+                type Sum = $ty;
+
+                fn zero() -> Self::Sum {
+                    0.0
+                }
+
+                fn to_sum(self) -> Self::Sum {
+                    self
+                }
+
+                fn checked_sum(sum: Self::Sum, item: Self) -> Option<Self::Sum> {
+                    Some(sum + item)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
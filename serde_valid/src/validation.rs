@@ -6,25 +6,77 @@ mod object;
 mod string;
 
 use crate::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinLengthError, MinPropertiesError, MinimumError,
-    MultipleOfError, PatternError,
+    AlphanumericError, AsciiError, CharsetError, ContainsError, EanError, EmailError,
+    EndsWithError, EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, FiniteError,
+    IbanError, IsbnError, LuhnError, MaxLengthError, MaxPropertiesError, MaximumError,
+    MinLengthError, MinPropertiesError, MinimumError, MultipleOfError, NotBlankError,
+    NotContainsError, NotNanError, NotPatternError, PatternError, StartsWithError, UrlError,
+};
+pub use array::{
+    ValidateMaxCount, ValidateMaxItems, ValidateMaxSum, ValidateMinCount, ValidateMinItems,
+    ValidateMinSum, ValidateSorted, ValidateSortedBy, ValidateStrictlyIncreasing,
+    ValidateUniqueItems, ValidateUniqueItemsBy, ValidateUniqueItemsByKey,
 };
-pub use array::{ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
 pub use error::{
-    ArrayErrors, Composited, CustomMessage, Error, Errors, IntoError, ItemErrorsMap,
-    ItemVecErrorsMap, Message, ObjectErrors, PropertyErrorsMap, PropertyVecErrorsMap,
-    ToDefaultMessage, VecErrors,
+    ArrayErrors, CaptureValue, Composited, CustomMessage, Error, ErrorParams, Errors, FieldError,
+    IntoError, ItemErrorsMap, ItemVecErrorsMap, KeyedItemErrorsMap, Message, ObjectErrors,
+    PropertyErrorsMap, PropertyVecErrorsMap, RejectedValue, Report, ToDefaultMessage,
+    ToMessageParam, ToRejectedValue, VecErrors,
 };
-pub use generic::ValidateEnumerate;
-use indexmap::IndexMap;
+pub use generic::{ValidateEnumerate, ValidateRequired};
+use indexmap::{IndexMap, IndexSet};
 pub use numeric::{
-    ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaximum, ValidateMinimum,
-    ValidateMultipleOf,
+    ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateFinite, ValidateMaximum,
+    ValidateMinimum, ValidateMultipleOf, ValidateNotNan,
 };
 pub use object::{ValidateMaxProperties, ValidateMinProperties};
-pub use serde_valid_literal::{Literal, Number, Pattern};
-pub use string::{ValidateMaxLength, ValidateMinLength, ValidatePattern};
+pub use serde_valid_literal::{ByteSize, Literal, Number, Pattern};
+use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+pub use string::{
+    PatternMode, ValidateAlphanumeric, ValidateAscii, ValidateCharset, ValidateContains,
+    ValidateEan, ValidateEmail, ValidateEndsWith, ValidateIban, ValidateIsbn, ValidateLuhn,
+    ValidateMaxLength, ValidateMinLength, ValidateNotBlank, ValidateNotContains,
+    ValidateNotPattern, ValidatePattern, ValidateStartsWith, ValidateUrl,
+};
+#[cfg(feature = "fancy_regex")]
+pub use string::{ValidateEcmaNotPattern, ValidateEcmaPattern};
+
+/// Validates each item of a collection, keying the errors by the item index.
+fn validate_composited_items<'a, T, E>(
+    items: impl Iterator<Item = &'a T>,
+    validate: impl Fn(&'a T) -> Result<(), Composited<E>>,
+) -> Result<(), Composited<E>>
+where
+    T: 'a + ?Sized,
+{
+    let errors: IndexMap<usize, Composited<E>> = items
+        .enumerate()
+        .filter_map(|(index, item)| match validate(item) {
+            Ok(_) => None,
+            Err(error) => Some((index, error)),
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Composited::Array(errors))
+    }
+}
+
+/// Validates each item of an unordered collection like `HashSet`, whose errors are reported without the index.
+fn validate_composited_unordered_items<'a, T, E>(
+    items: impl Iterator<Item = &'a T>,
+    validate: impl Fn(&'a T) -> Result<(), Composited<E>>,
+) -> Result<(), Composited<E>>
+where
+    T: 'a + ?Sized,
+{
+    validate_composited_items(items, validate).map_err(|error| match error {
+        Composited::Array(items) => Composited::Unordered(items),
+        error => error,
+    })
+}
 
 macro_rules! impl_composited_validation_1args {
     (
@@ -64,22 +116,9 @@ macro_rules! impl_composited_validation_1args {
                     &self,
                     $limit: $limit_type,
                 ) -> Result<(), Composited<$Error>> {
-                    let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                        .iter()
-                        .enumerate()
-                        .filter_map(
-                            |(index, item)| match item.$validate_composited_method($limit) {
-                                Ok(_) => None,
-                                Err(error) => Some((index, error)),
-                            },
-                        )
-                        .collect();
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
                 }
             }
 
@@ -91,22 +130,121 @@ macro_rules! impl_composited_validation_1args {
                     &self,
                     $limit: $limit_type,
                 ) -> Result<(), Composited<$Error>> {
-                    let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                        .iter()
-                        .enumerate()
-                        .filter_map(
-                            |(index, item)| match item.$validate_composited_method($limit) {
-                                Ok(_) => None,
-                                Err(error) => Some((index, error)),
-                            },
-                        )
-                        .collect();
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
+                }
+            }
+
+            impl<T> $ValidateCompositedTrait for [T]
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: $limit_type,
+                ) -> Result<(), Composited<$Error>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
+                }
+            }
+
+            impl<T> $ValidateCompositedTrait for &[T]
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: $limit_type,
+                ) -> Result<(), Composited<$Error>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
+                }
+            }
+
+            impl<T> $ValidateCompositedTrait for Box<[T]>
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: $limit_type,
+                ) -> Result<(), Composited<$Error>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
+                }
+            }
+
+            impl<T> $ValidateCompositedTrait for VecDeque<T>
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: $limit_type,
+                ) -> Result<(), Composited<$Error>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
+                }
+            }
+
+            impl<T> $ValidateCompositedTrait for LinkedList<T>
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: $limit_type,
+                ) -> Result<(), Composited<$Error>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
+                }
+            }
+
+            impl<T, S> $ValidateCompositedTrait for HashSet<T, S>
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: $limit_type,
+                ) -> Result<(), Composited<$Error>> {
+                    validate_composited_unordered_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
+                }
+            }
+
+            impl<T> $ValidateCompositedTrait for BTreeSet<T>
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: $limit_type,
+                ) -> Result<(), Composited<$Error>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
+                }
+            }
+
+            impl<T, S> $ValidateCompositedTrait for IndexSet<T, S>
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: $limit_type,
+                ) -> Result<(), Composited<$Error>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.$validate_composited_method($limit)
+                    })
                 }
             }
 
@@ -150,22 +288,9 @@ macro_rules! impl_composited_validation_1args {
                 &self,
                 $limit: T,
             ) -> Result<(), crate::validation::Composited<$Error>> {
-                let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                    .iter()
-                    .enumerate()
-                    .filter_map(
-                        |(index, item)| match item.$validate_composited_method($limit) {
-                            Ok(_) => None,
-                            Err(error) => Some((index, error)),
-                        },
-                    )
-                    .collect();
-
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(Composited::Array(errors))
-                }
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
             }
         }
 
@@ -178,22 +303,129 @@ macro_rules! impl_composited_validation_1args {
                 &self,
                 $limit: T,
             ) -> Result<(), crate::validation::Composited<$Error>> {
-                let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                    .iter()
-                    .enumerate()
-                    .filter_map(
-                        |(index, item)| match item.$validate_composited_method($limit) {
-                            Ok(_) => None,
-                            Err(error) => Some((index, error)),
-                        },
-                    )
-                    .collect();
-
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(Composited::Array(errors))
-                }
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
+            }
+        }
+
+        impl<T, U> $ValidateCompositedTrait<T> for [U]
+        where
+            T: Copy,
+            U: $ValidateCompositedTrait<T>,
+        {
+            fn $validate_composited_method(
+                &self,
+                $limit: T,
+            ) -> Result<(), crate::validation::Composited<$Error>> {
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
+            }
+        }
+
+        impl<T, U> $ValidateCompositedTrait<T> for &[U]
+        where
+            T: Copy,
+            U: $ValidateCompositedTrait<T>,
+        {
+            fn $validate_composited_method(
+                &self,
+                $limit: T,
+            ) -> Result<(), crate::validation::Composited<$Error>> {
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
+            }
+        }
+
+        impl<T, U> $ValidateCompositedTrait<T> for Box<[U]>
+        where
+            T: Copy,
+            U: $ValidateCompositedTrait<T>,
+        {
+            fn $validate_composited_method(
+                &self,
+                $limit: T,
+            ) -> Result<(), crate::validation::Composited<$Error>> {
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
+            }
+        }
+
+        impl<T, U> $ValidateCompositedTrait<T> for VecDeque<U>
+        where
+            T: Copy,
+            U: $ValidateCompositedTrait<T>,
+        {
+            fn $validate_composited_method(
+                &self,
+                $limit: T,
+            ) -> Result<(), crate::validation::Composited<$Error>> {
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
+            }
+        }
+
+        impl<T, U> $ValidateCompositedTrait<T> for LinkedList<U>
+        where
+            T: Copy,
+            U: $ValidateCompositedTrait<T>,
+        {
+            fn $validate_composited_method(
+                &self,
+                $limit: T,
+            ) -> Result<(), crate::validation::Composited<$Error>> {
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
+            }
+        }
+
+        impl<T, U, S> $ValidateCompositedTrait<T> for HashSet<U, S>
+        where
+            T: Copy,
+            U: $ValidateCompositedTrait<T>,
+        {
+            fn $validate_composited_method(
+                &self,
+                $limit: T,
+            ) -> Result<(), crate::validation::Composited<$Error>> {
+                validate_composited_unordered_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
+            }
+        }
+
+        impl<T, U> $ValidateCompositedTrait<T> for BTreeSet<U>
+        where
+            T: Copy,
+            U: $ValidateCompositedTrait<T>,
+        {
+            fn $validate_composited_method(
+                &self,
+                $limit: T,
+            ) -> Result<(), crate::validation::Composited<$Error>> {
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
+            }
+        }
+
+        impl<T, U, S> $ValidateCompositedTrait<T> for IndexSet<U, S>
+        where
+            T: Copy,
+            U: $ValidateCompositedTrait<T>,
+        {
+            fn $validate_composited_method(
+                &self,
+                $limit: T,
+            ) -> Result<(), crate::validation::Composited<$Error>> {
+                validate_composited_items(self.iter(), |item| {
+                    item.$validate_composited_method($limit)
+                })
             }
         }
 
@@ -215,6 +447,174 @@ macro_rules! impl_composited_validation_1args {
     };
 }
 
+macro_rules! impl_composited_validation_0args {
+    ($ErrorType:ident) => {
+        paste::paste! {
+            pub trait [<ValidateComposited $ErrorType>] {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>>;
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for T
+            where
+                T: [<Validate $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    self.[<validate_ $ErrorType:snake>]()
+                        .map_err(|error| Composited::Single(error))
+                }
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for Vec<T>
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T, const N: usize> [<ValidateComposited $ErrorType>] for [T; N]
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for [T]
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for &[T]
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for Box<[T]>
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for VecDeque<T>
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for LinkedList<T>
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T, S> [<ValidateComposited $ErrorType>] for HashSet<T, S>
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_unordered_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for BTreeSet<T>
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T, S> [<ValidateComposited $ErrorType>] for IndexSet<T, S>
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    validate_composited_items(self.iter(), |item| {
+                        item.[<validate_composited_ $ErrorType:snake>]()
+                    })
+                }
+            }
+
+            impl<T> [<ValidateComposited $ErrorType>] for Option<T>
+            where
+                T: [<ValidateComposited $ErrorType>],
+            {
+                fn [<validate_composited_ $ErrorType:snake>](
+                    &self,
+                ) -> Result<(), Composited<[<$ErrorType Error>]>> {
+                    match self {
+                        Some(value) => value.[<validate_composited_ $ErrorType:snake>](),
+                        None => Ok(()),
+                    }
+                }
+            }
+        }
+    };
+}
+
 macro_rules! impl_generic_composited_validation_1args {
     (
         $ErrorType:ident,
@@ -279,6 +679,9 @@ impl_composited_validation_1args!(
     }
 );
 
+impl_composited_validation_0args!(Finite);
+impl_composited_validation_0args!(NotNan);
+
 // String
 impl_composited_validation_1args!(
     pub trait ValidateCompositedMaxLength {
@@ -307,6 +710,92 @@ impl_composited_validation_1args!(
     }
 );
 
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedNotPattern {
+        fn validate_composited_not_pattern(
+            &self,
+            not_pattern: &regex::Regex,
+        ) -> Result<(), Composited<NotPatternError>>;
+    }
+);
+
+#[cfg(feature = "fancy_regex")]
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedEcmaPattern {
+        fn validate_composited_ecma_pattern(
+            &self,
+            ecma_pattern: &fancy_regex::Regex,
+        ) -> Result<(), Composited<PatternError>>;
+    }
+);
+
+#[cfg(feature = "fancy_regex")]
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedEcmaNotPattern {
+        fn validate_composited_ecma_not_pattern(
+            &self,
+            ecma_not_pattern: &fancy_regex::Regex,
+        ) -> Result<(), Composited<NotPatternError>>;
+    }
+);
+
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedContains {
+        fn validate_composited_contains(
+            &self,
+            contains: &str,
+        ) -> Result<(), Composited<ContainsError>>;
+    }
+);
+
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedNotContains {
+        fn validate_composited_not_contains(
+            &self,
+            not_contains: &str,
+        ) -> Result<(), Composited<NotContainsError>>;
+    }
+);
+
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedStartsWith {
+        fn validate_composited_starts_with(
+            &self,
+            starts_with: &str,
+        ) -> Result<(), Composited<StartsWithError>>;
+    }
+);
+
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedEndsWith {
+        fn validate_composited_ends_with(
+            &self,
+            ends_with: &str,
+        ) -> Result<(), Composited<EndsWithError>>;
+    }
+);
+
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedCharset {
+        fn validate_composited_charset(
+            &self,
+            charset: &str,
+        ) -> Result<(), Composited<CharsetError>>;
+    }
+);
+
+impl_composited_validation_0args!(NotBlank);
+impl_composited_validation_0args!(Ascii);
+impl_composited_validation_0args!(Alphanumeric);
+
+impl_composited_validation_0args!(Luhn);
+impl_composited_validation_0args!(Iban);
+impl_composited_validation_0args!(Isbn);
+impl_composited_validation_0args!(Ean);
+
+impl_composited_validation_0args!(Email);
+impl_composited_validation_0args!(Url);
+
 // Object
 impl_composited_validation_1args!(
     pub trait ValidateCompositedMaxProperties {
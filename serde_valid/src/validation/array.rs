@@ -1,10 +1,22 @@
+mod max_count;
 mod max_items;
+mod max_sum;
+mod min_count;
 mod min_items;
+mod min_sum;
+mod sorted;
+mod strictly_increasing;
 mod unique_items;
 
+pub use max_count::ValidateMaxCount;
 pub use max_items::ValidateMaxItems;
+pub use max_sum::ValidateMaxSum;
+pub use min_count::ValidateMinCount;
 pub use min_items::ValidateMinItems;
-pub use unique_items::ValidateUniqueItems;
+pub use min_sum::ValidateMinSum;
+pub use sorted::{ValidateSorted, ValidateSortedBy};
+pub use strictly_increasing::ValidateStrictlyIncreasing;
+pub use unique_items::{ValidateUniqueItems, ValidateUniqueItemsBy, ValidateUniqueItemsByKey};
 
 use crate::{MaxItemsError, MinItemsError};
 
@@ -0,0 +1,105 @@
+use crate::traits::Items;
+use crate::MaxCountError;
+
+/// Maximum count validation of the array items which match the predicate.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+///
+/// fn is_admin(role: &&str) -> bool {
+///     *role == "admin"
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct<'a> {
+///     #[validate(count_where(is_admin, max = 1))]
+///     roles: Vec<&'a str>,
+/// }
+///
+/// let s = TestStruct {
+///     roles: vec!["admin", "user", "admin"],
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "roles": {
+///                 "errors": ["The count of the matching items must be `<= 1`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateMaxCount {
+    type Item;
+
+    fn validate_max_count<F>(&self, predicate: F, max_count: usize) -> Result<(), MaxCountError>
+    where
+        F: Fn(&Self::Item) -> bool;
+}
+
+impl<T> ValidateMaxCount for T
+where
+    T: Items + ?Sized,
+{
+    type Item = T::Item;
+
+    fn validate_max_count<F>(&self, predicate: F, max_count: usize) -> Result<(), MaxCountError>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        match self
+            .items()
+            .enumerate()
+            .filter(|(_, item)| predicate(item))
+            .nth(max_count)
+        {
+            Some((index, _)) => Err(MaxCountError::new(max_count, index)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> ValidateMaxCount for Option<T>
+where
+    T: ValidateMaxCount,
+{
+    type Item = T::Item;
+
+    fn validate_max_count<F>(&self, predicate: F, max_count: usize) -> Result<(), MaxCountError>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        match self {
+            Some(value) => value.validate_max_count(predicate, max_count),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_max_count_is_true() {
+        assert!(ValidateMaxCount::validate_max_count(&[1, 2, 3, 4], |x| x % 2 == 0, 2).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_max_count_is_false() {
+        let error =
+            ValidateMaxCount::validate_max_count(&vec![2, 1, 4, 6], |x| x % 2 == 0, 2).unwrap_err();
+        assert_eq!(error.index, 3);
+    }
+
+    #[test]
+    fn test_validate_array_max_count_zero() {
+        let error = ValidateMaxCount::validate_max_count(&[1, 2], |x| *x > 1, 0).unwrap_err();
+        assert_eq!(error.index, 1);
+    }
+}
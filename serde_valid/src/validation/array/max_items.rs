@@ -1,3 +1,5 @@
+use crate::traits::ItemsCount;
+
 /// Max length validation of the array items.
 ///
 /// See <https://json-schema.org/understanding-json-schema/reference/array.html#length>
@@ -44,22 +46,15 @@ pub trait ValidateMaxItems {
     fn validate_max_items(&self, max_items: usize) -> Result<(), crate::MaxItemsError>;
 }
 
-impl<T> ValidateMaxItems for Vec<T> {
+impl<T> ValidateMaxItems for T
+where
+    T: ItemsCount + ?Sized,
+{
     fn validate_max_items(&self, max_items: usize) -> Result<(), crate::MaxItemsError> {
-        if max_items >= self.len() {
+        if max_items >= self.items_count() {
             Ok(())
         } else {
-            Err(crate::MaxItemsError::new(max_items))
-        }
-    }
-}
-
-impl<T, const N: usize> ValidateMaxItems for [T; N] {
-    fn validate_max_items(&self, max_items: usize) -> Result<(), crate::MaxItemsError> {
-        if max_items >= self.len() {
-            Ok(())
-        } else {
-            Err(crate::MaxItemsError::new(max_items))
+            Err(crate::MaxItemsError::new(max_items, self.items_count()))
         }
     }
 }
@@ -87,4 +82,29 @@ mod tests {
     fn test_validate_array_max_items_is_false() {
         assert!(ValidateMaxItems::validate_max_items(&[1, 2, 3], 2).is_err());
     }
+
+    #[test]
+    fn test_validate_array_max_items_collection_types() {
+        let deque = std::collections::VecDeque::from([1, 2, 3]);
+        assert!(ValidateMaxItems::validate_max_items(&deque, 3).is_ok());
+        assert!(ValidateMaxItems::validate_max_items(&deque, 2).is_err());
+
+        let hash_set = std::collections::HashSet::from([1, 2, 3]);
+        assert!(ValidateMaxItems::validate_max_items(&hash_set, 3).is_ok());
+
+        let btree_set = std::collections::BTreeSet::from([1, 2, 3]);
+        assert!(ValidateMaxItems::validate_max_items(&btree_set, 2).is_err());
+
+        let index_set = indexmap::IndexSet::from([1, 2, 3]);
+        assert!(ValidateMaxItems::validate_max_items(&index_set, 3).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_max_items_slice_types() {
+        let slice: &[i32] = &[1, 2, 3];
+        assert!(ValidateMaxItems::validate_max_items(&slice, 2).is_err());
+
+        let boxed: Box<[i32]> = vec![1, 2, 3].into_boxed_slice();
+        assert!(ValidateMaxItems::validate_max_items(&boxed, 3).is_ok());
+    }
 }
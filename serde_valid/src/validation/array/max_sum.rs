@@ -0,0 +1,150 @@
+use crate::traits::{Items, Summable};
+use crate::MaxSumError;
+use serde_valid_literal::Number;
+
+/// Maximum sum validation of the array items.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateMaxSum};
+///
+/// struct MyType(Vec<i32>);
+///
+/// impl ValidateMaxSum<i32> for MyType {
+///     fn validate_max_sum(&self, max_sum: i32) -> Result<(), serde_valid::MaxSumError> {
+///         self.0.validate_max_sum(max_sum)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(sum(maximum = 10))]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(vec![4, 5, 6]),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The sum of the items must be `<= 10`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateMaxSum<T> {
+    fn validate_max_sum(&self, max_sum: T) -> Result<(), MaxSumError>;
+}
+
+impl<C, T> ValidateMaxSum<T> for C
+where
+    C: Items<Item = T> + ?Sized,
+    T: Summable + Into<Number>,
+{
+    fn validate_max_sum(&self, max_sum: T) -> Result<(), MaxSumError> {
+        // NaN is not `<=` any limit, so the sum with NaN fails, and so does the overflowing sum.
+        let limit = max_sum.to_sum();
+        let is_within = |sum: Option<T::Sum>| sum.is_some_and(|sum| sum <= limit);
+        let total = self
+            .items()
+            .try_fold(T::zero(), |sum, item| T::checked_sum(sum, *item));
+        if is_within(total) {
+            return Ok(());
+        }
+
+        // The items can be negative, so the index is looked for only when the total fails.
+        let mut sum = Some(T::zero());
+        let index = self
+            .items()
+            .position(|item| {
+                sum = sum.and_then(|sum| T::checked_sum(sum, *item));
+                !is_within(sum)
+            })
+            .unwrap_or_default();
+        Err(MaxSumError::new(max_sum, index))
+    }
+}
+
+impl<U, T> ValidateMaxSum<T> for Option<U>
+where
+    U: ValidateMaxSum<T>,
+{
+    fn validate_max_sum(&self, max_sum: T) -> Result<(), MaxSumError> {
+        match self {
+            Some(value) => value.validate_max_sum(max_sum),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_max_sum_is_true() {
+        assert!(ValidateMaxSum::validate_max_sum(&[1, 2, 3], 6).is_ok());
+        assert!(ValidateMaxSum::validate_max_sum(&Vec::<u8>::new(), 0).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_max_sum_is_false() {
+        let error = ValidateMaxSum::validate_max_sum(&vec![1, 2, 3, 4], 5).unwrap_err();
+        assert_eq!(error.index, 2);
+    }
+
+    #[test]
+    fn test_validate_array_max_sum_does_not_overflow() {
+        let error = ValidateMaxSum::validate_max_sum(&[200u8, 100], u8::MAX - 1).unwrap_err();
+        assert_eq!(error.index, 1);
+
+        let error =
+            ValidateMaxSum::validate_max_sum(&[i32::MAX, i32::MAX, -i32::MAX], 0).unwrap_err();
+        assert_eq!(error.index, 0);
+        assert!(
+            ValidateMaxSum::validate_max_sum(&[i32::MAX, i32::MAX, -i32::MAX], i32::MAX).is_ok()
+        );
+    }
+
+    #[cfg(feature = "i128")]
+    #[test]
+    fn test_validate_array_max_sum_overflow_fails() {
+        let error = ValidateMaxSum::validate_max_sum(&[i128::MAX, 1, -1], i128::MAX).unwrap_err();
+        assert_eq!(error.index, 1);
+    }
+
+    #[test]
+    fn test_validate_array_max_sum_float_type() {
+        assert!(ValidateMaxSum::validate_max_sum(&[0.5, 0.25], 0.75).is_ok());
+        assert!(ValidateMaxSum::validate_max_sum(&[0.5, 0.5], 0.75).is_err());
+    }
+
+    #[test]
+    fn test_validate_array_max_sum_negative_items() {
+        assert!(ValidateMaxSum::validate_max_sum(&[10, -5], 6).is_ok());
+        assert!(ValidateMaxSum::validate_max_sum(&[-5, 10, -3], 2).is_ok());
+
+        let error = ValidateMaxSum::validate_max_sum(&[1, 10, -5, 2], 6).unwrap_err();
+        assert_eq!(error.index, 1);
+    }
+
+    #[test]
+    fn test_validate_array_max_sum_nan() {
+        let error = ValidateMaxSum::validate_max_sum(&[0.5, f64::NAN, 0.25], 10.0).unwrap_err();
+        assert_eq!(error.index, 1);
+        assert!(ValidateMaxSum::validate_max_sum(&[0.5], f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_validate_array_max_sum_option_type() {
+        assert!(ValidateMaxSum::validate_max_sum(&None::<Vec<i32>>, 0).is_ok());
+        assert!(ValidateMaxSum::validate_max_sum(&Some(vec![1, 2]), 2).is_err());
+    }
+}
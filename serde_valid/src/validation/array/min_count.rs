@@ -0,0 +1,69 @@
+use crate::traits::Items;
+use crate::MinCountError;
+
+/// Minimum count validation of the array items which match the predicate.
+///
+/// ```rust
+/// use serde_valid::ValidateMinCount;
+///
+/// let roles = vec!["user", "user"];
+///
+/// assert!(roles.validate_min_count(|role| *role == "admin", 1).is_err());
+/// ```
+pub trait ValidateMinCount {
+    type Item;
+
+    fn validate_min_count<F>(&self, predicate: F, min_count: usize) -> Result<(), MinCountError>
+    where
+        F: Fn(&Self::Item) -> bool;
+}
+
+impl<T> ValidateMinCount for T
+where
+    T: Items + ?Sized,
+{
+    type Item = T::Item;
+
+    fn validate_min_count<F>(&self, predicate: F, min_count: usize) -> Result<(), MinCountError>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        if self.items().filter(|item| predicate(item)).count() >= min_count {
+            Ok(())
+        } else {
+            Err(MinCountError::new(min_count))
+        }
+    }
+}
+
+impl<T> ValidateMinCount for Option<T>
+where
+    T: ValidateMinCount,
+{
+    type Item = T::Item;
+
+    fn validate_min_count<F>(&self, predicate: F, min_count: usize) -> Result<(), MinCountError>
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        match self {
+            Some(value) => value.validate_min_count(predicate, min_count),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_min_count_is_true() {
+        assert!(ValidateMinCount::validate_min_count(&[1, 2, 3, 4], |x| x % 2 == 0, 2).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_min_count_is_false() {
+        assert!(ValidateMinCount::validate_min_count(&vec![1, 2, 3], |x| x % 2 == 0, 2).is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::traits::ItemsCount;
+
 /// Min length validation of the array items.
 ///
 /// See <https://json-schema.org/understanding-json-schema/reference/array.html#length>
@@ -44,22 +46,15 @@ pub trait ValidateMinItems {
     fn validate_min_items(&self, min_items: usize) -> Result<(), crate::MinItemsError>;
 }
 
-impl<T> ValidateMinItems for Vec<T> {
+impl<T> ValidateMinItems for T
+where
+    T: ItemsCount + ?Sized,
+{
     fn validate_min_items(&self, min_items: usize) -> Result<(), crate::MinItemsError> {
-        if min_items <= self.len() {
+        if min_items <= self.items_count() {
             Ok(())
         } else {
-            Err(crate::MinItemsError::new(min_items))
-        }
-    }
-}
-
-impl<T, const N: usize> ValidateMinItems for [T; N] {
-    fn validate_min_items(&self, min_items: usize) -> Result<(), crate::MinItemsError> {
-        if min_items <= self.len() {
-            Ok(())
-        } else {
-            Err(crate::MinItemsError::new(min_items))
+            Err(crate::MinItemsError::new(min_items, self.items_count()))
         }
     }
 }
@@ -87,4 +82,29 @@ mod tests {
     fn test_validate_array_min_items_array_is_true() {
         assert!(ValidateMinItems::validate_min_items(&['a', 'b', 'c'], 3).is_ok());
     }
+
+    #[test]
+    fn test_validate_array_min_items_collection_types() {
+        let deque = std::collections::VecDeque::from([1, 2, 3]);
+        assert!(ValidateMinItems::validate_min_items(&deque, 3).is_ok());
+        assert!(ValidateMinItems::validate_min_items(&deque, 4).is_err());
+
+        let hash_set = std::collections::HashSet::from([1, 2, 3]);
+        assert!(ValidateMinItems::validate_min_items(&hash_set, 3).is_ok());
+
+        let btree_set = std::collections::BTreeSet::from([1, 2, 3]);
+        assert!(ValidateMinItems::validate_min_items(&btree_set, 3).is_ok());
+
+        let index_set = indexmap::IndexSet::from([1, 2, 3]);
+        assert!(ValidateMinItems::validate_min_items(&index_set, 4).is_err());
+    }
+
+    #[test]
+    fn test_validate_array_min_items_slice_types() {
+        let slice: &[i32] = &[1, 2, 3];
+        assert!(ValidateMinItems::validate_min_items(&slice, 3).is_ok());
+
+        let boxed: Box<[i32]> = vec![1, 2, 3].into_boxed_slice();
+        assert!(ValidateMinItems::validate_min_items(&boxed, 4).is_err());
+    }
 }
@@ -0,0 +1,123 @@
+use crate::traits::{Items, Summable};
+use crate::MinSumError;
+use serde_valid_literal::Number;
+
+/// Minimum sum validation of the array items.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateMinSum};
+///
+/// struct MyType(Vec<i32>);
+///
+/// impl ValidateMinSum<i32> for MyType {
+///     fn validate_min_sum(&self, min_sum: i32) -> Result<(), serde_valid::MinSumError> {
+///         self.0.validate_min_sum(min_sum)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(sum(minimum = 10))]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(vec![1, 2, 3]),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The sum of the items must be `>= 10`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateMinSum<T> {
+    fn validate_min_sum(&self, min_sum: T) -> Result<(), MinSumError>;
+}
+
+impl<C, T> ValidateMinSum<T> for C
+where
+    C: Items<Item = T> + ?Sized,
+    T: Summable + Into<Number>,
+{
+    fn validate_min_sum(&self, min_sum: T) -> Result<(), MinSumError> {
+        // NaN is not `>=` any limit, so the sum with NaN fails, and so does the overflowing sum.
+        let sum = self
+            .items()
+            .try_fold(T::zero(), |sum, item| T::checked_sum(sum, *item));
+        if sum.is_some_and(|sum| sum >= min_sum.to_sum()) {
+            Ok(())
+        } else {
+            Err(MinSumError::new(min_sum))
+        }
+    }
+}
+
+impl<U, T> ValidateMinSum<T> for Option<U>
+where
+    U: ValidateMinSum<T>,
+{
+    fn validate_min_sum(&self, min_sum: T) -> Result<(), MinSumError> {
+        match self {
+            Some(value) => value.validate_min_sum(min_sum),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_min_sum_is_true() {
+        assert!(ValidateMinSum::validate_min_sum(&[1, 2, 3], 6).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_min_sum_is_false() {
+        assert!(ValidateMinSum::validate_min_sum(&vec![1, 2, 3], 7).is_err());
+        assert!(ValidateMinSum::validate_min_sum(&Vec::<f64>::new(), 0.5).is_err());
+    }
+
+    #[test]
+    fn test_validate_array_min_sum_negative_items() {
+        assert!(ValidateMinSum::validate_min_sum(&[-5, 10], 5).is_ok());
+        assert!(ValidateMinSum::validate_min_sum(&[10, -5], 6).is_err());
+    }
+
+    #[test]
+    fn test_validate_array_min_sum_does_not_overflow() {
+        assert!(ValidateMinSum::validate_min_sum(&[i32::MIN, i32::MIN, i32::MAX], 0).is_err());
+        assert!(
+            ValidateMinSum::validate_min_sum(&[i32::MIN, i32::MIN, i32::MAX, i32::MAX, 2], 0)
+                .is_ok()
+        );
+        assert!(ValidateMinSum::validate_min_sum(&[0u8, 0], 1).is_err());
+    }
+
+    #[cfg(feature = "i128")]
+    #[test]
+    fn test_validate_array_min_sum_overflow_fails() {
+        assert!(ValidateMinSum::validate_min_sum(&[i128::MIN, -1, 1], i128::MIN).is_err());
+    }
+
+    #[test]
+    fn test_validate_array_min_sum_nan() {
+        assert!(ValidateMinSum::validate_min_sum(&[0.5, f64::NAN], 0.0).is_err());
+        assert!(ValidateMinSum::validate_min_sum(&[0.5], f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_validate_array_min_sum_option_type() {
+        assert!(ValidateMinSum::validate_min_sum(&None::<Vec<i32>>, 1).is_ok());
+    }
+}
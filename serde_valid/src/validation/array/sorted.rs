@@ -0,0 +1,155 @@
+use crate::traits::Items;
+
+/// Order validation of the array items.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateSorted};
+///
+/// struct MyType(Vec<i32>);
+///
+/// impl ValidateSorted for MyType {
+///     fn validate_sorted(&self) -> Result<(), serde_valid::SortedError> {
+///         self.0.validate_sorted()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(sorted)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(vec![1, 3, 2]),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The items must be sorted."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateSorted {
+    fn validate_sorted(&self) -> Result<(), crate::SortedError>;
+}
+
+impl<T> ValidateSorted for T
+where
+    T: ValidateSortedBy + ?Sized,
+    T::Item: PartialOrd,
+{
+    fn validate_sorted(&self) -> Result<(), crate::SortedError> {
+        self.validate_sorted_by(|a, b| a <= b)
+    }
+}
+
+/// Order validation of the array items, compared by the given function.
+///
+/// The function returns whether the pair of the adjacent items is in order.
+///
+/// ```rust
+/// use serde_valid::ValidateSortedBy;
+///
+/// let events = vec![("start", 10), ("stop", 20), ("restart", 15)];
+///
+/// let error = events.validate_sorted_by(|a, b| a.1 <= b.1).unwrap_err();
+/// assert_eq!(error.index, 2);
+/// ```
+pub trait ValidateSortedBy {
+    type Item;
+
+    fn validate_sorted_by<F>(&self, is_ordered: F) -> Result<(), crate::SortedError>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool;
+}
+
+impl<T> ValidateSortedBy for T
+where
+    T: Items + ?Sized,
+{
+    type Item = T::Item;
+
+    fn validate_sorted_by<F>(&self, is_ordered: F) -> Result<(), crate::SortedError>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool,
+    {
+        match first_unordered_index(self.items(), is_ordered) {
+            Some(index) => Err(crate::SortedError::new(index)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> ValidateSortedBy for Option<T>
+where
+    T: ValidateSortedBy,
+{
+    type Item = T::Item;
+
+    fn validate_sorted_by<F>(&self, is_ordered: F) -> Result<(), crate::SortedError>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool,
+    {
+        match self {
+            Some(value) => value.validate_sorted_by(is_ordered),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The index of the first item which is out of order with the previous item.
+pub(crate) fn first_unordered_index<'a, T, F>(
+    mut items: impl Iterator<Item = &'a T>,
+    is_ordered: F,
+) -> Option<usize>
+where
+    T: 'a,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut previous = items.next()?;
+    for (index, item) in items.enumerate() {
+        if !is_ordered(previous, item) {
+            return Some(index + 1);
+        }
+        previous = item;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_sorted_is_true() {
+        assert!(ValidateSorted::validate_sorted(&[1, 2, 2, 3]).is_ok());
+        assert!(ValidateSorted::validate_sorted(&Vec::<i32>::new()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_sorted_is_false() {
+        let error = ValidateSorted::validate_sorted(&vec![1.0, 3.0, 2.0, 1.0]).unwrap_err();
+        assert_eq!(error.index, 2);
+    }
+
+    #[test]
+    fn test_validate_array_sorted_by_is_false() {
+        let error = ValidateSortedBy::validate_sorted_by(&[(1, 'c'), (2, 'b')], |a, b| a.1 <= b.1)
+            .unwrap_err();
+        assert_eq!(error.index, 1);
+    }
+
+    #[test]
+    fn test_validate_array_sorted_option_type() {
+        assert!(ValidateSorted::validate_sorted(&None::<Vec<i32>>).is_ok());
+        assert!(ValidateSorted::validate_sorted(&Some(vec![2, 1])).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use super::sorted::first_unordered_index;
+use crate::traits::Items;
+
+/// Strict order validation of the array items.
+///
+/// Unlike `sorted`, the equal adjacent items are not allowed.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateStrictlyIncreasing};
+///
+/// struct MyType(Vec<i32>);
+///
+/// impl ValidateStrictlyIncreasing for MyType {
+///     fn validate_strictly_increasing(
+///         &self,
+///     ) -> Result<(), serde_valid::StrictlyIncreasingError> {
+///         self.0.validate_strictly_increasing()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(strictly_increasing)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(vec![1, 2, 2]),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The items must be strictly increasing."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateStrictlyIncreasing {
+    fn validate_strictly_increasing(&self) -> Result<(), crate::StrictlyIncreasingError>;
+}
+
+impl<T> ValidateStrictlyIncreasing for T
+where
+    T: Items + ?Sized,
+    T::Item: PartialOrd,
+{
+    fn validate_strictly_increasing(&self) -> Result<(), crate::StrictlyIncreasingError> {
+        match first_unordered_index(self.items(), |a, b| a < b) {
+            Some(index) => Err(crate::StrictlyIncreasingError::new(index)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> ValidateStrictlyIncreasing for Option<T>
+where
+    T: ValidateStrictlyIncreasing,
+{
+    fn validate_strictly_increasing(&self) -> Result<(), crate::StrictlyIncreasingError> {
+        match self {
+            Some(value) => value.validate_strictly_increasing(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_strictly_increasing_is_true() {
+        assert!(ValidateStrictlyIncreasing::validate_strictly_increasing(&[1, 2, 3]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_strictly_increasing_is_false() {
+        let error = ValidateStrictlyIncreasing::validate_strictly_increasing(&vec![1, 2, 2, 3])
+            .unwrap_err();
+        assert_eq!(error.index, 2);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::traits::IsUnique;
+use std::collections::HashSet;
+
+use crate::traits::Items;
 
 /// Uniqueness validation of the array items.
 ///
@@ -43,39 +45,200 @@ pub trait ValidateUniqueItems {
     fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsError>;
 }
 
-impl<T> ValidateUniqueItems for Vec<T>
+impl<T> ValidateUniqueItems for T
 where
-    T: std::cmp::Eq + std::hash::Hash + std::fmt::Debug,
+    T: Items + ?Sized,
+    T::Item: std::cmp::Eq + std::hash::Hash,
 {
     fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
-        if self.is_unique() {
+        let mut seen = HashSet::new();
+        let duplicates: Vec<usize> = self
+            .items()
+            .enumerate()
+            .filter_map(|(index, item)| if seen.insert(item) { None } else { Some(index) })
+            .collect();
+
+        if duplicates.is_empty() {
             Ok(())
         } else {
-            Err(crate::UniqueItemsError {})
+            Err(crate::UniqueItemsError::new(duplicates))
         }
     }
 }
 
-impl<T, const N: usize> ValidateUniqueItems for [T; N]
+impl<T> ValidateUniqueItems for Option<T>
 where
-    T: std::cmp::Eq + std::hash::Hash + std::fmt::Debug,
+    T: ValidateUniqueItems,
 {
     fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
-        if self.is_unique() {
+        match self {
+            Some(value) => value.validate_unique_items(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Uniqueness validation of the array items, compared by the given function.
+///
+/// Unlike [`ValidateUniqueItems`], the items need neither [`Eq`] nor [`Hash`],
+/// so it works for the items which are only [`PartialEq`] such as floats,
+/// at the cost of comparing every pair of the items.
+/// `#[validate(unique_items(partial_eq))]` uses it.
+///
+/// ```rust
+/// use serde_valid::ValidateUniqueItemsBy;
+///
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let users = vec![
+///     User { id: 1, name: "alice" },
+///     User { id: 2, name: "bob" },
+///     User { id: 1, name: "carol" },
+/// ];
+/// # assert_eq!(users[2].name, "carol");
+///
+/// let error = users.validate_unique_items_by(|a, b| a.id == b.id).unwrap_err();
+/// assert_eq!(error.duplicates, vec![2]);
+///
+/// assert!(vec![0.5, 1.5].validate_unique_items_by(|a, b| a == b).is_ok());
+/// ```
+pub trait ValidateUniqueItemsBy {
+    type Item;
+
+    fn validate_unique_items_by<F>(&self, eq: F) -> Result<(), crate::UniqueItemsError>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool;
+}
+
+impl<T> ValidateUniqueItemsBy for T
+where
+    T: Items + ?Sized,
+{
+    type Item = T::Item;
+
+    fn validate_unique_items_by<F>(&self, eq: F) -> Result<(), crate::UniqueItemsError>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool,
+    {
+        let items: Vec<&Self::Item> = self.items().collect();
+        let duplicates: Vec<usize> = (0..items.len())
+            .filter(|&index| items[..index].iter().any(|item| eq(item, items[index])))
+            .collect();
+
+        if duplicates.is_empty() {
             Ok(())
         } else {
-            Err(crate::UniqueItemsError {})
+            Err(crate::UniqueItemsError::new(duplicates))
         }
     }
 }
 
-impl<T> ValidateUniqueItems for Option<T>
+impl<T> ValidateUniqueItemsBy for Option<T>
 where
-    T: ValidateUniqueItems,
+    T: ValidateUniqueItemsBy,
 {
-    fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
+    type Item = T::Item;
+
+    fn validate_unique_items_by<F>(&self, eq: F) -> Result<(), crate::UniqueItemsError>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> bool,
+    {
         match self {
-            Some(value) => value.validate_unique_items(),
+            Some(value) => value.validate_unique_items_by(eq),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Uniqueness validation of the array items, compared by the key of each item.
+///
+/// The keys are collected into a [`HashSet`], so the key needs [`Eq`] and [`Hash`].
+/// `#[validate(unique_by = "id")]` and `#[validate(unique_by(key_fn))]` use it.
+///
+/// ```rust
+/// use serde_valid::ValidateUniqueItemsByKey;
+///
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let users = vec![
+///     User { id: 1, name: "alice" },
+///     User { id: 2, name: "bob" },
+///     User { id: 1, name: "carol" },
+/// ];
+/// # assert_eq!(users[2].name, "carol");
+///
+/// let error = users.validate_unique_items_by_key(|user| user.id).unwrap_err();
+/// assert_eq!(error.duplicates, vec![2]);
+/// ```
+pub trait ValidateUniqueItemsByKey {
+    type Item;
+
+    fn validate_unique_items_by_key<'a, K, F>(
+        &'a self,
+        key: F,
+    ) -> Result<(), crate::UniqueItemsError>
+    where
+        K: std::cmp::Eq + std::hash::Hash,
+        F: Fn(&'a Self::Item) -> K;
+}
+
+impl<T> ValidateUniqueItemsByKey for T
+where
+    T: Items + ?Sized,
+{
+    type Item = T::Item;
+
+    fn validate_unique_items_by_key<'a, K, F>(
+        &'a self,
+        key: F,
+    ) -> Result<(), crate::UniqueItemsError>
+    where
+        K: std::cmp::Eq + std::hash::Hash,
+        F: Fn(&'a Self::Item) -> K,
+    {
+        let mut seen = HashSet::new();
+        let duplicates: Vec<usize> = self
+            .items()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                if seen.insert(key(item)) {
+                    None
+                } else {
+                    Some(index)
+                }
+            })
+            .collect();
+
+        if duplicates.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::UniqueItemsError::new(duplicates))
+        }
+    }
+}
+
+impl<T> ValidateUniqueItemsByKey for Option<T>
+where
+    T: ValidateUniqueItemsByKey,
+{
+    type Item = T::Item;
+
+    fn validate_unique_items_by_key<'a, K, F>(
+        &'a self,
+        key: F,
+    ) -> Result<(), crate::UniqueItemsError>
+    where
+        K: std::cmp::Eq + std::hash::Hash,
+        F: Fn(&'a Self::Item) -> K,
+    {
+        match self {
+            Some(value) => value.validate_unique_items_by_key(key),
             None => Ok(()),
         }
     }
@@ -99,4 +262,87 @@ mod tests {
     fn test_validate_array_unique_items_is_false() {
         assert!(ValidateUniqueItems::validate_unique_items(&[1, 2, 3, 3]).is_err());
     }
+
+    #[test]
+    fn test_validate_array_unique_items_collection_types() {
+        let deque = std::collections::VecDeque::from([1, 2, 3]);
+        assert!(ValidateUniqueItems::validate_unique_items(&deque).is_ok());
+
+        let deque = std::collections::VecDeque::from([1, 2, 2]);
+        assert!(ValidateUniqueItems::validate_unique_items(&deque).is_err());
+
+        let hash_set = std::collections::HashSet::from([1, 2, 3]);
+        assert!(ValidateUniqueItems::validate_unique_items(&hash_set).is_ok());
+
+        let btree_set = std::collections::BTreeSet::from([1, 2, 3]);
+        assert!(ValidateUniqueItems::validate_unique_items(&btree_set).is_ok());
+
+        let index_set = indexmap::IndexSet::from([1, 2, 3]);
+        assert!(ValidateUniqueItems::validate_unique_items(&index_set).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_slice_types() {
+        let slice: &[i32] = &[1, 2, 1];
+        assert!(ValidateUniqueItems::validate_unique_items(&slice).is_err());
+
+        let boxed: Box<[i32]> = vec![1, 2, 3].into_boxed_slice();
+        assert!(ValidateUniqueItems::validate_unique_items(&boxed).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_duplicates() {
+        let error = ValidateUniqueItems::validate_unique_items(&[1, 2, 1, 3, 2]).unwrap_err();
+        assert_eq!(error.duplicates, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_by_is_true() {
+        assert!(
+            ValidateUniqueItemsBy::validate_unique_items_by(&vec![0.1, 0.2], |a, b| a == b).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_by_is_false() {
+        let error = ValidateUniqueItemsBy::validate_unique_items_by(
+            &vec![(1, 'a'), (2, 'b'), (1, 'c'), (1, 'd')],
+            |a, b| a.0 == b.0,
+        )
+        .unwrap_err();
+        assert_eq!(error.duplicates, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_by_option_type() {
+        let none: Option<Vec<f64>> = None;
+        assert!(ValidateUniqueItemsBy::validate_unique_items_by(&none, |a, b| a == b).is_ok());
+        assert!(
+            ValidateUniqueItemsBy::validate_unique_items_by(&Some(vec![0.1, 0.1]), |a, b| a == b)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_by_key_is_false() {
+        let error = ValidateUniqueItemsByKey::validate_unique_items_by_key(
+            &vec![(1, 'a'), (2, 'b'), (1, 'c'), (1, 'd')],
+            |item| item.0,
+        )
+        .unwrap_err();
+        assert_eq!(error.duplicates, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_by_key_borrowed_key() {
+        let items = vec!["a".to_string(), "b".to_string()];
+        assert!(
+            ValidateUniqueItemsByKey::validate_unique_items_by_key(&items, String::as_str).is_ok()
+        );
+        assert!(ValidateUniqueItemsByKey::validate_unique_items_by_key(
+            &Some(vec!["a".to_string(), "a".to_string()]),
+            |item| item.as_str()
+        )
+        .is_err());
+    }
 }
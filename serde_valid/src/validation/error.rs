@@ -1,25 +1,40 @@
 mod array_erros;
+mod capture_value;
 mod composited;
 mod custom_message;
 mod errors;
+mod field_error;
 mod into_error;
 mod message;
+mod message_param;
 mod object_errors;
+mod rejected_value;
+mod report;
 mod to_default_message;
 
 pub use crate::error::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    AllOrNoneError, AlphanumericError, AsciiError, AtLeastOneOfError, CharsetError, ContainsError,
+    EanError, EmailError, EndsWithError, EnumerateError, ExactlyOneOfError, ExclusiveMaximumError,
+    ExclusiveMinimumError, FiniteError, IbanError, IsbnError, LuhnError, MaxCountError,
+    MaxItemsError, MaxLengthError, MaxPropertiesError, MaxSumError, MaximumError, MinCountError,
+    MinItemsError, MinLengthError, MinPropertiesError, MinSumError, MinimumError, MultipleOfError,
+    MutuallyExclusiveError, NotBlankError, NotContainsError, NotNanError, NotPatternError,
+    PatternError, RequiredError, SortedError, StartsWithError, StrictlyIncreasingError,
+    UniqueItemsError, UrlError,
 };
 pub use array_erros::ArrayErrors;
+pub use capture_value::{CaptureValue, ToRejectedValue};
 pub use composited::Composited;
 pub use custom_message::CustomMessage;
 pub use errors::Errors;
+pub use field_error::FieldError;
 use indexmap::IndexMap;
 pub use into_error::IntoError;
 pub use message::Message;
+pub use message_param::ToMessageParam;
 pub use object_errors::ObjectErrors;
+pub use rejected_value::{ErrorParams, RejectedValue};
+pub use report::Report;
 pub use to_default_message::ToDefaultMessage;
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
@@ -45,6 +60,14 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     MultipleOf(Message<MultipleOfError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Finite(Message<FiniteError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    NotNan(Message<NotNanError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinLength(Message<MinLengthError>),
@@ -57,6 +80,66 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Pattern(Message<PatternError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    NotPattern(Message<NotPatternError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Contains(Message<ContainsError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    NotContains(Message<NotContainsError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    StartsWith(Message<StartsWithError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    EndsWith(Message<EndsWithError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Charset(Message<CharsetError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    NotBlank(Message<NotBlankError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Ascii(Message<AsciiError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Alphanumeric(Message<AlphanumericError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Luhn(Message<LuhnError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Iban(Message<IbanError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Isbn(Message<IsbnError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Ean(Message<EanError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Email(Message<EmailError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Url(Message<UrlError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinItems(Message<MinItemsError>),
@@ -69,6 +152,30 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     UniqueItems(Message<UniqueItemsError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Sorted(Message<SortedError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    StrictlyIncreasing(Message<StrictlyIncreasingError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MaxSum(Message<MaxSumError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MinSum(Message<MinSumError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MaxCount(Message<MaxCountError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MinCount(Message<MinCountError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinProperties(Message<MinPropertiesError>),
@@ -81,6 +188,26 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Enumerate(Message<EnumerateError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Required(Message<RequiredError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    AtLeastOneOf(Message<AtLeastOneOfError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    ExactlyOneOf(Message<ExactlyOneOfError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MutuallyExclusive(Message<MutuallyExclusiveError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    AllOrNone(Message<AllOrNoneError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     Custom(String),
@@ -97,6 +224,127 @@ pub enum Error {
     Fluent(crate::fluent::Message),
 }
 
+impl Error {
+    /// The error with the custom message, which replaces the error without the params,
+    /// like the errors of `#[validate(nested)]`, `custom(...)` and `#[rule(...)]`.
+    pub fn custom_message<T>(error: &T, message_fn: fn(&T) -> String) -> Self {
+        Self::Custom(message_fn(error))
+    }
+
+    /// The rejected value, which is `None` when the validation doesn't capture it.
+    pub fn value(&self) -> Option<&RejectedValue> {
+        match self {
+            Self::Minimum(message) => message.params().value(),
+            Self::Maximum(message) => message.params().value(),
+            Self::ExclusiveMinimum(message) => message.params().value(),
+            Self::ExclusiveMaximum(message) => message.params().value(),
+            Self::MultipleOf(message) => message.params().value(),
+            Self::Finite(message) => message.params().value(),
+            Self::NotNan(message) => message.params().value(),
+            Self::MinLength(message) => message.params().value(),
+            Self::MaxLength(message) => message.params().value(),
+            Self::Pattern(message) => message.params().value(),
+            Self::NotPattern(message) => message.params().value(),
+            Self::Contains(message) => message.params().value(),
+            Self::NotContains(message) => message.params().value(),
+            Self::StartsWith(message) => message.params().value(),
+            Self::EndsWith(message) => message.params().value(),
+            Self::Charset(message) => message.params().value(),
+            Self::NotBlank(message) => message.params().value(),
+            Self::Ascii(message) => message.params().value(),
+            Self::Alphanumeric(message) => message.params().value(),
+            Self::Luhn(message) => message.params().value(),
+            Self::Iban(message) => message.params().value(),
+            Self::Isbn(message) => message.params().value(),
+            Self::Ean(message) => message.params().value(),
+            Self::Email(message) => message.params().value(),
+            Self::Url(message) => message.params().value(),
+            Self::MinItems(message) => message.params().value(),
+            Self::MaxItems(message) => message.params().value(),
+            Self::UniqueItems(message) => message.params().value(),
+            Self::Sorted(message) => message.params().value(),
+            Self::StrictlyIncreasing(message) => message.params().value(),
+            Self::MaxSum(message) => message.params().value(),
+            Self::MinSum(message) => message.params().value(),
+            Self::MaxCount(message) => message.params().value(),
+            Self::MinCount(message) => message.params().value(),
+            Self::MinProperties(message) => message.params().value(),
+            Self::MaxProperties(message) => message.params().value(),
+            Self::Enumerate(message) => message.params().value(),
+            Self::Required(message) => message.params().value(),
+            Self::AtLeastOneOf(message) => message.params().value(),
+            Self::ExactlyOneOf(message) => message.params().value(),
+            Self::MutuallyExclusive(message) => message.params().value(),
+            Self::AllOrNone(message) => message.params().value(),
+            Self::Custom(_) | Self::Items(_) | Self::Properties(_) => None,
+            #[cfg(feature = "fluent")]
+            Self::Fluent(_) => None,
+        }
+    }
+
+    /// Hides the rejected values of the error, like `#[validate(sensitive)]`.
+    pub fn redacted(self) -> Self {
+        self.map_params(&|params| params.redact())
+    }
+
+    /// Drops the rejected values of the error, which are kept only with `#[validate(capture_value)]`.
+    pub fn without_value(self) -> Self {
+        self.map_params(&|params| params.clear_value())
+    }
+
+    fn map_params(self, f: &dyn Fn(&mut dyn ErrorParams)) -> Self {
+        match self {
+            Self::Minimum(message) => Self::Minimum(message.map_params(f)),
+            Self::Maximum(message) => Self::Maximum(message.map_params(f)),
+            Self::ExclusiveMinimum(message) => Self::ExclusiveMinimum(message.map_params(f)),
+            Self::ExclusiveMaximum(message) => Self::ExclusiveMaximum(message.map_params(f)),
+            Self::MultipleOf(message) => Self::MultipleOf(message.map_params(f)),
+            Self::Finite(message) => Self::Finite(message.map_params(f)),
+            Self::NotNan(message) => Self::NotNan(message.map_params(f)),
+            Self::MinLength(message) => Self::MinLength(message.map_params(f)),
+            Self::MaxLength(message) => Self::MaxLength(message.map_params(f)),
+            Self::Pattern(message) => Self::Pattern(message.map_params(f)),
+            Self::NotPattern(message) => Self::NotPattern(message.map_params(f)),
+            Self::Contains(message) => Self::Contains(message.map_params(f)),
+            Self::NotContains(message) => Self::NotContains(message.map_params(f)),
+            Self::StartsWith(message) => Self::StartsWith(message.map_params(f)),
+            Self::EndsWith(message) => Self::EndsWith(message.map_params(f)),
+            Self::Charset(message) => Self::Charset(message.map_params(f)),
+            Self::NotBlank(message) => Self::NotBlank(message.map_params(f)),
+            Self::Ascii(message) => Self::Ascii(message.map_params(f)),
+            Self::Alphanumeric(message) => Self::Alphanumeric(message.map_params(f)),
+            Self::Luhn(message) => Self::Luhn(message.map_params(f)),
+            Self::Iban(message) => Self::Iban(message.map_params(f)),
+            Self::Isbn(message) => Self::Isbn(message.map_params(f)),
+            Self::Ean(message) => Self::Ean(message.map_params(f)),
+            Self::Email(message) => Self::Email(message.map_params(f)),
+            Self::Url(message) => Self::Url(message.map_params(f)),
+            Self::MinItems(message) => Self::MinItems(message.map_params(f)),
+            Self::MaxItems(message) => Self::MaxItems(message.map_params(f)),
+            Self::UniqueItems(message) => Self::UniqueItems(message.map_params(f)),
+            Self::Sorted(message) => Self::Sorted(message.map_params(f)),
+            Self::StrictlyIncreasing(message) => Self::StrictlyIncreasing(message.map_params(f)),
+            Self::MaxSum(message) => Self::MaxSum(message.map_params(f)),
+            Self::MinSum(message) => Self::MinSum(message.map_params(f)),
+            Self::MaxCount(message) => Self::MaxCount(message.map_params(f)),
+            Self::MinCount(message) => Self::MinCount(message.map_params(f)),
+            Self::MinProperties(message) => Self::MinProperties(message.map_params(f)),
+            Self::MaxProperties(message) => Self::MaxProperties(message.map_params(f)),
+            Self::Enumerate(message) => Self::Enumerate(message.map_params(f)),
+            Self::Required(message) => Self::Required(message.map_params(f)),
+            Self::AtLeastOneOf(message) => Self::AtLeastOneOf(message.map_params(f)),
+            Self::ExactlyOneOf(message) => Self::ExactlyOneOf(message.map_params(f)),
+            Self::MutuallyExclusive(message) => Self::MutuallyExclusive(message.map_params(f)),
+            Self::AllOrNone(message) => Self::AllOrNone(message.map_params(f)),
+            Self::Items(items) => Self::Items(items.map_errors(|error| error.map_params(f))),
+            Self::Properties(properties) => {
+                Self::Properties(properties.map_errors(|error| error.map_params(f)))
+            }
+            error => error,
+        }
+    }
+}
+
 fn serialize_error_message<T, S>(message: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     T: std::fmt::Display,
@@ -108,5 +356,6 @@ where
 pub type VecErrors<E = crate::validation::Error> = Vec<E>;
 pub type ItemErrorsMap<E> = IndexMap<usize, Errors<E>>;
 pub type ItemVecErrorsMap<E> = IndexMap<usize, VecErrors<E>>;
+pub type KeyedItemErrorsMap<E> = IndexMap<String, Errors<E>>;
 pub type PropertyErrorsMap<E> = IndexMap<&'static str, Errors<E>>;
 pub type PropertyVecErrorsMap<E> = IndexMap<&'static str, VecErrors<E>>;
@@ -1,11 +1,17 @@
 use serde::ser::SerializeStruct;
 
-use super::{ItemErrorsMap, VecErrors};
+use super::{ItemErrorsMap, KeyedItemErrorsMap, VecErrors};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub struct ArrayErrors<E = crate::validation::Error> {
     pub errors: VecErrors<E>,
     pub items: ItemErrorsMap<E>,
+    /// Errors of the items, which are keyed by the key of the item like `#[validate(key = "id")]`.
+    ///
+    /// They are serialized into `keyed_items` apart from `items`, which is keyed by the index,
+    /// and `keyed_items` is omitted when it is empty.
+    /// This is boxed to keep the size of the errors small.
+    pub keyed_items: Box<KeyedItemErrorsMap<E>>,
 }
 
 impl<E> serde::Serialize for ArrayErrors<E>
@@ -16,23 +22,57 @@ where
     where
         S: serde::Serializer,
     {
-        let mut array_errors = serializer.serialize_struct("ArrayErrors", 2)?;
+        let mut array_errors = serializer.serialize_struct("ArrayErrors", 3)?;
         array_errors.serialize_field("errors", &self.errors)?;
         array_errors.serialize_field("items", &self.items)?;
+        if self.keyed_items.is_empty() {
+            array_errors.skip_field("keyed_items")?;
+        } else {
+            array_errors.serialize_field("keyed_items", &self.keyed_items)?;
+        }
         array_errors.end()
     }
 }
 
 impl<E> ArrayErrors<E> {
     pub fn new(errors: VecErrors<E>, items: ItemErrorsMap<E>) -> Self {
-        Self { errors, items }
+        Self {
+            errors,
+            items,
+            keyed_items: Box::default(),
+        }
+    }
+
+    pub fn with_keyed_items(mut self, keyed_items: KeyedItemErrorsMap<E>) -> Self {
+        self.keyed_items = Box::new(keyed_items);
+        self
+    }
+
+    /// Converts the errors in the tree into another error type.
+    pub fn map_errors<T, F>(self, f: F) -> ArrayErrors<T>
+    where
+        F: Fn(E) -> T,
+    {
+        self.map_errors_by(&f)
+    }
+
+    pub(super) fn map_errors_by<T>(self, f: &dyn Fn(E) -> T) -> ArrayErrors<T> {
+        ArrayErrors {
+            errors: self.errors.into_iter().map(f).collect(),
+            items: self
+                .items
+                .into_iter()
+                .map(|(index, errors)| (index, errors.map_errors_by(f)))
+                .collect(),
+            keyed_items: Box::new(
+                self.keyed_items
+                    .into_iter()
+                    .map(|(key, errors)| (key, errors.map_errors_by(f)))
+                    .collect(),
+            ),
+        }
     }
-}
 
-impl<E> ArrayErrors<E>
-where
-    E: Clone,
-{
     pub fn merge(mut self, other: ArrayErrors<E>) -> Self {
         self.errors.extend(other.errors);
 
@@ -44,11 +84,22 @@ where
                 }
             };
         }
+        for (key, item) in *other.keyed_items {
+            match self.keyed_items.get_mut(&key) {
+                Some(errors) => errors.merge(item),
+                None => {
+                    self.keyed_items.insert(key, item);
+                }
+            };
+        }
         self
     }
 }
 
-impl std::fmt::Display for ArrayErrors {
+impl<E> std::fmt::Display for ArrayErrors<E>
+where
+    E: std::fmt::Display + serde::Serialize,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match serde_json::to_string(&self) {
             Ok(json_string) => {
@@ -0,0 +1,216 @@
+use super::{Composited, ErrorParams, RejectedValue};
+use crate::traits::LossyStr;
+use indexmap::{IndexMap, IndexSet};
+use serde_valid_literal::Number;
+use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+
+/// The value which is kept in the errors as the rejected value.
+pub trait ToRejectedValue {
+    fn to_rejected_value(&self) -> RejectedValue;
+}
+
+impl<T> ToRejectedValue for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn to_rejected_value(&self) -> RejectedValue {
+        RejectedValue::from(self.lossy_str().as_ref())
+    }
+}
+
+impl ToRejectedValue for char {
+    fn to_rejected_value(&self) -> RejectedValue {
+        RejectedValue::from(*self)
+    }
+}
+
+macro_rules! impl_to_rejected_value_for_number {
+    ($type:ty) => {
+        impl ToRejectedValue for $type {
+            fn to_rejected_value(&self) -> RejectedValue {
+                RejectedValue::Number(Number::from(self))
+            }
+        }
+    };
+}
+
+impl_to_rejected_value_for_number!(i8);
+impl_to_rejected_value_for_number!(i16);
+impl_to_rejected_value_for_number!(i32);
+impl_to_rejected_value_for_number!(i64);
+#[cfg(feature = "i128")]
+impl_to_rejected_value_for_number!(i128);
+impl_to_rejected_value_for_number!(isize);
+impl_to_rejected_value_for_number!(u8);
+impl_to_rejected_value_for_number!(u16);
+impl_to_rejected_value_for_number!(u32);
+impl_to_rejected_value_for_number!(u64);
+#[cfg(feature = "i128")]
+impl_to_rejected_value_for_number!(u128);
+impl_to_rejected_value_for_number!(usize);
+impl_to_rejected_value_for_number!(std::num::NonZeroI8);
+impl_to_rejected_value_for_number!(std::num::NonZeroI16);
+impl_to_rejected_value_for_number!(std::num::NonZeroI32);
+impl_to_rejected_value_for_number!(std::num::NonZeroI64);
+#[cfg(feature = "i128")]
+impl_to_rejected_value_for_number!(std::num::NonZeroI128);
+impl_to_rejected_value_for_number!(std::num::NonZeroIsize);
+impl_to_rejected_value_for_number!(std::num::NonZeroU8);
+impl_to_rejected_value_for_number!(std::num::NonZeroU16);
+impl_to_rejected_value_for_number!(std::num::NonZeroU32);
+impl_to_rejected_value_for_number!(std::num::NonZeroU64);
+#[cfg(feature = "i128")]
+impl_to_rejected_value_for_number!(std::num::NonZeroU128);
+impl_to_rejected_value_for_number!(std::num::NonZeroUsize);
+impl_to_rejected_value_for_number!(f32);
+impl_to_rejected_value_for_number!(f64);
+impl_to_rejected_value_for_number!(std::time::Duration);
+
+/// Attach the rejected values to the error params, which is done by `#[validate(capture_value)]`.
+///
+/// The validations don't keep the value by themselves,
+/// so that the value never appears in the errors unless the field opts in.
+/// The items of the collections are matched with their errors by the index of the iteration order.
+///
+/// ```rust
+/// use serde_valid::validation::{
+///     CaptureValue, Composited, ErrorParams, Number, RejectedValue, ValidateCompositedMaximum,
+/// };
+///
+/// let values = vec![5, 20];
+/// let error = values.capture_value(values.validate_composited_maximum(10).unwrap_err());
+///
+/// match error {
+///     Composited::Array(items) => match &items[&1] {
+///         Composited::Single(params) => assert_eq!(
+///             params.value(),
+///             Some(&RejectedValue::Number(Number::I32(20)))
+///         ),
+///         _ => unreachable!(),
+///     },
+///     _ => unreachable!(),
+/// }
+/// ```
+pub trait CaptureValue {
+    fn capture_value<E>(&self, error: Composited<E>) -> Composited<E>
+    where
+        E: ErrorParams;
+}
+
+impl<T> CaptureValue for T
+where
+    T: ToRejectedValue + ?Sized,
+{
+    fn capture_value<E>(&self, error: Composited<E>) -> Composited<E>
+    where
+        E: ErrorParams,
+    {
+        match error {
+            Composited::Single(mut params) => {
+                params.set_value(self.to_rejected_value());
+                Composited::Single(params)
+            }
+            error => error,
+        }
+    }
+}
+
+fn capture_items_value<'a, T, E>(
+    items: impl Iterator<Item = &'a T>,
+    error: Composited<E>,
+) -> Composited<E>
+where
+    T: CaptureValue + 'a + ?Sized,
+    E: ErrorParams,
+{
+    let capture = |errors: IndexMap<usize, Composited<E>>| {
+        let items = items.collect::<Vec<_>>();
+        errors
+            .into_iter()
+            .map(|(index, error)| match items.get(index) {
+                Some(item) => (index, item.capture_value(error)),
+                None => (index, error),
+            })
+            .collect()
+    };
+    match error {
+        Composited::Array(errors) => Composited::Array(capture(errors)),
+        Composited::Unordered(errors) => Composited::Unordered(capture(errors)),
+        error => error,
+    }
+}
+
+macro_rules! impl_capture_value_for_items {
+    ($type:ty) => {
+        impl<T> CaptureValue for $type
+        where
+            T: CaptureValue,
+        {
+            fn capture_value<E>(&self, error: Composited<E>) -> Composited<E>
+            where
+                E: ErrorParams,
+            {
+                capture_items_value(self.iter(), error)
+            }
+        }
+    };
+}
+
+impl_capture_value_for_items!(Vec<T>);
+impl_capture_value_for_items!([T]);
+impl_capture_value_for_items!(&[T]);
+impl_capture_value_for_items!(Box<[T]>);
+impl_capture_value_for_items!(VecDeque<T>);
+impl_capture_value_for_items!(LinkedList<T>);
+impl_capture_value_for_items!(BTreeSet<T>);
+
+impl<T, const N: usize> CaptureValue for [T; N]
+where
+    T: CaptureValue,
+{
+    fn capture_value<E>(&self, error: Composited<E>) -> Composited<E>
+    where
+        E: ErrorParams,
+    {
+        capture_items_value(self.iter(), error)
+    }
+}
+
+impl<T, S> CaptureValue for HashSet<T, S>
+where
+    T: CaptureValue,
+{
+    fn capture_value<E>(&self, error: Composited<E>) -> Composited<E>
+    where
+        E: ErrorParams,
+    {
+        capture_items_value(self.iter(), error)
+    }
+}
+
+impl<T, S> CaptureValue for IndexSet<T, S>
+where
+    T: CaptureValue,
+{
+    fn capture_value<E>(&self, error: Composited<E>) -> Composited<E>
+    where
+        E: ErrorParams,
+    {
+        capture_items_value(self.iter(), error)
+    }
+}
+
+impl<T> CaptureValue for Option<T>
+where
+    T: CaptureValue,
+{
+    fn capture_value<E>(&self, error: Composited<E>) -> Composited<E>
+    where
+        E: ErrorParams,
+    {
+        match self {
+            Some(value) => value.capture_value(error),
+            None => error,
+        }
+    }
+}
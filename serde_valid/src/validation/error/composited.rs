@@ -1,9 +1,14 @@
 use super::into_error::IntoError;
 use super::{custom_message::CustomMessage, Error};
 use crate::error::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    AllOrNoneError, AlphanumericError, AsciiError, AtLeastOneOfError, CharsetError, ContainsError,
+    EanError, EmailError, EndsWithError, EnumerateError, ExactlyOneOfError, ExclusiveMaximumError,
+    ExclusiveMinimumError, FiniteError, IbanError, IsbnError, LuhnError, MaxCountError,
+    MaxItemsError, MaxLengthError, MaxPropertiesError, MaxSumError, MaximumError, MinCountError,
+    MinItemsError, MinLengthError, MinPropertiesError, MinSumError, MinimumError, MultipleOfError,
+    MutuallyExclusiveError, NotBlankError, NotContainsError, NotNanError, NotPatternError,
+    PatternError, RequiredError, SortedError, StartsWithError, StrictlyIncreasingError,
+    UniqueItemsError, UrlError,
 };
 use indexmap::IndexMap;
 
@@ -11,6 +16,46 @@ use indexmap::IndexMap;
 pub enum Composited<Error> {
     Single(Error),
     Array(IndexMap<usize, Composited<Error>>),
+    /// The errors of the items of an unordered collection like `HashSet`.
+    ///
+    /// They are keyed by the iteration order only to match the items with their errors,
+    /// and are reported without the index, because the iteration order is not deterministic.
+    Unordered(IndexMap<usize, Composited<Error>>),
+}
+
+impl<Error> Composited<Error> {
+    /// Map each error params, including the ones of the items.
+    pub fn map<F>(self, f: &F) -> Self
+    where
+        F: Fn(Error) -> Error,
+    {
+        match self {
+            Composited::Single(single) => Composited::Single(f(single)),
+            Composited::Array(array) => Composited::Array(
+                array
+                    .into_iter()
+                    .map(|(index, composited)| (index, composited.map(f)))
+                    .collect(),
+            ),
+            Composited::Unordered(items) => Composited::Unordered(
+                items
+                    .into_iter()
+                    .map(|(index, composited)| (index, composited.map(f)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Sort the errors of the unordered items by the message, and drop the same messages,
+/// so that they don't depend on the iteration order.
+fn unordered_errors(items: impl Iterator<Item = Error>) -> Vec<Error> {
+    let mut errors = items
+        .map(|error| (error.to_string(), error))
+        .collect::<Vec<_>>();
+    errors.sort_by(|(a, _), (b, _)| a.cmp(b));
+    errors.dedup_by(|(a, _), (b, _)| a == b);
+    errors.into_iter().map(|(_, error)| error).collect()
 }
 
 macro_rules! impl_into_error {
@@ -32,6 +77,16 @@ macro_rules! impl_into_error {
                                 })
                                 .collect::<IndexMap<_, _>>(),
                         ))},
+                        Composited::Unordered(items) => {
+                            Error::Items(crate::validation::ArrayErrors::new(
+                                unordered_errors(
+                                    items
+                                        .into_values()
+                                        .map(|params| params.into_error_by(custom.clone())),
+                                ),
+                                IndexMap::new(),
+                            ))
+                        }
                     }
                 }
             }
@@ -41,6 +96,13 @@ macro_rules! impl_into_error {
 
 // Global
 impl_into_error!(Enumerate);
+impl_into_error!(Required);
+
+// Rule
+impl_into_error!(AtLeastOneOf);
+impl_into_error!(ExactlyOneOf);
+impl_into_error!(MutuallyExclusive);
+impl_into_error!(AllOrNone);
 
 // Numeric
 impl_into_error!(Maximum);
@@ -48,16 +110,39 @@ impl_into_error!(Minimum);
 impl_into_error!(ExclusiveMaximum);
 impl_into_error!(ExclusiveMinimum);
 impl_into_error!(MultipleOf);
+impl_into_error!(Finite);
+impl_into_error!(NotNan);
 
 // String
 impl_into_error!(MaxLength);
 impl_into_error!(MinLength);
 impl_into_error!(Pattern);
+impl_into_error!(NotPattern);
+impl_into_error!(Contains);
+impl_into_error!(NotContains);
+impl_into_error!(StartsWith);
+impl_into_error!(EndsWith);
+impl_into_error!(Charset);
+impl_into_error!(NotBlank);
+impl_into_error!(Ascii);
+impl_into_error!(Alphanumeric);
+impl_into_error!(Luhn);
+impl_into_error!(Iban);
+impl_into_error!(Isbn);
+impl_into_error!(Ean);
+impl_into_error!(Email);
+impl_into_error!(Url);
 
 // Array
 impl_into_error!(MaxItems);
 impl_into_error!(MinItems);
 impl_into_error!(UniqueItems);
+impl_into_error!(Sorted);
+impl_into_error!(StrictlyIncreasing);
+impl_into_error!(MaxSum);
+impl_into_error!(MinSum);
+impl_into_error!(MaxCount);
+impl_into_error!(MinCount);
 
 // Object
 impl_into_error!(MaxProperties);
@@ -1,4 +1,4 @@
-use super::{ArrayErrors, ObjectErrors, VecErrors};
+use super::{ArrayErrors, ObjectErrors, PropertyErrorsMap, VecErrors};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum Errors<E = crate::validation::Error> {
@@ -30,10 +30,35 @@ where
     }
 }
 
-impl<E> Errors<E>
-where
-    E: Clone,
-{
+impl<E> Errors<E> {
+    /// Converts the errors in the tree into another error type.
+    ///
+    /// ```rust
+    /// use serde_valid::validation::{Error, Errors};
+    ///
+    /// let errors: Errors = Errors::NewType(vec![Error::Custom("invalid".to_string())]);
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(errors.map_errors(|error| error.to_string().to_uppercase()))
+    ///         .unwrap(),
+    ///     serde_json::json!({ "errors": ["INVALID"] })
+    /// );
+    /// ```
+    pub fn map_errors<T, F>(self, f: F) -> Errors<T>
+    where
+        F: Fn(E) -> T,
+    {
+        self.map_errors_by(&f)
+    }
+
+    pub(super) fn map_errors_by<T>(self, f: &dyn Fn(E) -> T) -> Errors<T> {
+        match self {
+            Errors::Array(array) => Errors::Array(array.map_errors_by(f)),
+            Errors::Object(object) => Errors::Object(object.map_errors_by(f)),
+            Errors::NewType(errors) => Errors::NewType(errors.into_iter().map(f).collect()),
+        }
+    }
+
     pub fn merge(&mut self, other: Errors<E>) {
         match self {
             Errors::Array(a) => match other {
@@ -48,18 +73,29 @@ where
                             }
                         };
                     }
+                    for (key, item) in *b.keyed_items {
+                        match a.keyed_items.get_mut(&key) {
+                            Some(errors) => errors.merge(item),
+                            None => {
+                                a.keyed_items.insert(key, item);
+                            }
+                        };
+                    }
                 }
                 Errors::Object(_) => {
                     unreachable!("conflict Array and Object in serde_valid::validation::Errors")
                 }
                 Errors::NewType(errors) => {
-                    a.errors.extend(errors.into_iter());
+                    a.errors.extend(errors);
                 }
             },
             Errors::NewType(a) => match other {
                 Errors::Array(b) => {
                     a.extend(b.errors);
-                    *self = Errors::Array(ArrayErrors::new(a.to_vec(), b.items));
+                    *self = Errors::Array(
+                        ArrayErrors::new(std::mem::take(a), b.items)
+                            .with_keyed_items(*b.keyed_items),
+                    );
                 }
                 Errors::Object(_) => {
                     unreachable!("conflict Array and Object in serde_valid::validation::Errors")
@@ -68,14 +104,38 @@ where
                     a.extend(b);
                 }
             },
-            Errors::Object(_) => {
-                unimplemented!("Object does not support yet.")
-            }
+            Errors::Object(a) => match other {
+                Errors::Object(b) => {
+                    a.errors.extend(b.errors);
+                    merge_property_errors(&mut a.properties, b.properties);
+                    merge_property_errors(&mut a.internal, *b.internal);
+                }
+                Errors::Array(_) => {
+                    unreachable!("conflict Object and Array in serde_valid::validation::Errors")
+                }
+                Errors::NewType(errors) => {
+                    a.errors.extend(errors);
+                }
+            },
         }
     }
 }
 
-impl std::fmt::Display for Errors {
+fn merge_property_errors<E>(a: &mut PropertyErrorsMap<E>, b: PropertyErrorsMap<E>) {
+    for (property, errors) in b {
+        match a.get_mut(property) {
+            Some(property_errors) => property_errors.merge(errors),
+            None => {
+                a.insert(property, errors);
+            }
+        };
+    }
+}
+
+impl<E> std::fmt::Display for Errors<E>
+where
+    E: std::fmt::Display + serde::Serialize,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Array(errors) => std::fmt::Display::fmt(errors, f),
@@ -0,0 +1,62 @@
+use super::{ArrayErrors, Error, ObjectErrors};
+
+/// An error of a field, which `#[derive(Validate)]` collects before building the errors tree.
+///
+/// The errors of the items and the properties are kept apart from the errors of the field,
+/// so that they are nested in the tree whatever the error type is.
+#[derive(Debug, Clone)]
+pub enum FieldError<E = Error> {
+    Single(E),
+    Items(ArrayErrors<E>),
+    Properties(ObjectErrors<E>),
+}
+
+impl<E> FieldError<E>
+where
+    E: From<Error>,
+{
+    /// Converts the error of the built-in validations into the error type of the derive.
+    pub fn from_error(error: Error) -> Self {
+        match error {
+            Error::Items(array_errors) => Self::Items(array_errors.map_errors(E::from)),
+            Error::Properties(object_errors) => Self::Properties(object_errors.map_errors(E::from)),
+            error => Self::Single(E::from(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::Errors;
+    use indexmap::indexmap;
+
+    #[derive(Debug, PartialEq)]
+    struct CodedError(String);
+
+    impl From<Error> for CodedError {
+        fn from(error: Error) -> Self {
+            Self(error.to_string())
+        }
+    }
+
+    #[test]
+    fn field_error_from_items_error() {
+        let error = Error::Items(ArrayErrors::new(
+            vec![],
+            indexmap! {
+                1 => Errors::NewType(vec![Error::Custom("invalid".to_string())]),
+            },
+        ));
+
+        match FieldError::<CodedError>::from_error(error) {
+            FieldError::Items(array_errors) => match &array_errors.items[&1] {
+                Errors::NewType(errors) => {
+                    assert_eq!(errors, &vec![CodedError("invalid".to_string())])
+                }
+                errors => panic!("unexpected errors: {errors:?}"),
+            },
+            error => panic!("unexpected error: {error:?}"),
+        }
+    }
+}
@@ -19,6 +19,33 @@ impl<E> Message<E> {
     pub fn error(&self) -> String {
         (self.format_fn)(&self.error)
     }
+
+    /// The parameters of the error, which the message is formatted from.
+    pub fn params(&self) -> &E {
+        &self.error
+    }
+}
+
+impl<E> Message<E>
+where
+    E: super::ErrorParams,
+{
+    /// Hides the rejected value of the error.
+    pub fn redacted(mut self) -> Self {
+        self.error.redact();
+        self
+    }
+
+    /// Drops the rejected value of the error.
+    pub fn without_value(mut self) -> Self {
+        self.error.clear_value();
+        self
+    }
+
+    pub(crate) fn map_params(mut self, f: &dyn Fn(&mut dyn super::ErrorParams)) -> Self {
+        f(&mut self.error);
+        self
+    }
 }
 
 impl<E> std::fmt::Debug for Message<E>
@@ -0,0 +1,82 @@
+use serde_valid_literal::{Literal, Number, Pattern};
+
+use crate::validation::PatternMode;
+
+use super::RejectedValue;
+
+/// The param of the error, which fills the placeholder of the message template like `{maximum}`.
+pub trait ToMessageParam {
+    fn to_message_param(&self) -> String;
+}
+
+macro_rules! impl_to_message_param_by_display {
+    ($($ty:ty),*) => {
+        $(
+            impl ToMessageParam for $ty {
+                fn to_message_param(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_message_param_by_display!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    f32,
+    f64,
+    bool,
+    char,
+    &'static str,
+    String,
+    Number,
+    Literal,
+    Pattern,
+    PatternMode,
+    RejectedValue
+);
+
+/// The items are joined like the default messages, like `1, 2, 3`.
+impl<T> ToMessageParam for Vec<T>
+where
+    T: ToMessageParam,
+{
+    fn to_message_param(&self) -> String {
+        self.iter()
+            .map(ToMessageParam::to_message_param)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// The value which is not captured is empty.
+impl<T> ToMessageParam for Option<T>
+where
+    T: ToMessageParam,
+{
+    fn to_message_param(&self) -> String {
+        self.as_ref()
+            .map(ToMessageParam::to_message_param)
+            .unwrap_or_default()
+    }
+}
+
+impl<T> ToMessageParam for Box<T>
+where
+    T: ToMessageParam,
+{
+    fn to_message_param(&self) -> String {
+        (**self).to_message_param()
+    }
+}
@@ -2,10 +2,19 @@ use serde::ser::SerializeStruct;
 
 use super::{PropertyErrorsMap, VecErrors};
 
+/// The errors of the object.
+///
+/// New fields may be added, so build it with [`ObjectErrors::new`] instead of the struct literal.
 #[derive(Debug, Clone, thiserror::Error)]
+#[non_exhaustive]
 pub struct ObjectErrors<E = crate::validation::Error> {
     pub errors: VecErrors<E>,
     pub properties: PropertyErrorsMap<E>,
+    /// Errors of the fields which are not deserialized, like `#[serde(skip)]`.
+    ///
+    /// They are collected only with `#[validate(internal)]`.
+    /// This is boxed to keep the size of the errors small.
+    pub internal: Box<PropertyErrorsMap<E>>,
 }
 
 impl<E> serde::Serialize for ObjectErrors<E>
@@ -16,16 +25,52 @@ where
     where
         S: serde::Serializer,
     {
-        let mut object_errors = serializer.serialize_struct("ObjectErrors", 2)?;
+        let len = if self.internal.is_empty() { 2 } else { 3 };
+        let mut object_errors = serializer.serialize_struct("ObjectErrors", len)?;
         object_errors.serialize_field("errors", &self.errors)?;
         object_errors.serialize_field("properties", &self.properties)?;
+        if !self.internal.is_empty() {
+            object_errors.serialize_field("internal", &self.internal)?;
+        }
         object_errors.end()
     }
 }
 
 impl<E> ObjectErrors<E> {
     pub fn new(errors: VecErrors<E>, properties: PropertyErrorsMap<E>) -> Self {
-        Self { errors, properties }
+        Self {
+            errors,
+            properties,
+            internal: Box::default(),
+        }
+    }
+
+    pub fn with_internal(mut self, internal: PropertyErrorsMap<E>) -> Self {
+        self.internal = Box::new(internal);
+        self
+    }
+
+    /// Converts the errors in the tree into another error type.
+    pub fn map_errors<T, F>(self, f: F) -> ObjectErrors<T>
+    where
+        F: Fn(E) -> T,
+    {
+        self.map_errors_by(&f)
+    }
+
+    pub(super) fn map_errors_by<T>(self, f: &dyn Fn(E) -> T) -> ObjectErrors<T> {
+        let map_properties = |properties: PropertyErrorsMap<E>| {
+            properties
+                .into_iter()
+                .map(|(property, errors)| (property, errors.map_errors_by(f)))
+                .collect()
+        };
+
+        ObjectErrors {
+            errors: self.errors.into_iter().map(f).collect(),
+            properties: map_properties(self.properties),
+            internal: Box::new(map_properties(*self.internal)),
+        }
     }
 }
 
@@ -0,0 +1,121 @@
+use serde_valid_literal::{Literal, Number};
+
+/// The value which the validation rejected.
+///
+/// The value is kept only for the `#[validate(capture_value)]` field,
+/// and the value of the `#[validate(sensitive)]` field is [`RejectedValue::Redacted`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RejectedValue {
+    Number(Number),
+    String(String),
+    Redacted,
+}
+
+impl From<Number> for RejectedValue {
+    fn from(value: Number) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<String> for RejectedValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for RejectedValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<char> for RejectedValue {
+    fn from(value: char) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<Literal> for RejectedValue {
+    fn from(value: Literal) -> Self {
+        match value {
+            Literal::Number(value) => Self::Number(value),
+            value => Self::String(value.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for RejectedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value}"),
+            Self::Redacted => write!(f, "[REDACTED]"),
+        }
+    }
+}
+
+/// The numbers are serialized as the JSON numbers,
+/// except the durations and the byte sizes, which are serialized as the strings like `"30s"`.
+impl serde::Serialize for RejectedValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Number(value) => match value.to_string().parse::<serde_json::Number>() {
+                Ok(number) => number.serialize(serializer),
+                Err(_) => serializer.collect_str(value),
+            },
+            Self::String(value) => serializer.serialize_str(value),
+            Self::Redacted => serializer.collect_str(self),
+        }
+    }
+}
+
+/// The params of the error, which keep the rejected value.
+pub trait ErrorParams {
+    /// The rejected value, which is `None` when the validation doesn't capture it.
+    fn value(&self) -> Option<&RejectedValue>;
+
+    /// Hide the rejected value and the params which may reveal it.
+    fn redact(&mut self);
+
+    /// Keep the rejected value, which `#[validate(capture_value)]` attaches.
+    fn set_value(&mut self, value: RejectedValue);
+
+    /// Drop the rejected value, which is kept only with `#[validate(capture_value)]`.
+    fn clear_value(&mut self);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn rejected_value_serialize() {
+        assert_eq!(
+            serde_json::to_value(RejectedValue::Number(Number::I32(150))).unwrap(),
+            json!(150)
+        );
+        assert_eq!(
+            serde_json::to_value(RejectedValue::Number(Number::F64(1.5))).unwrap(),
+            json!(1.5)
+        );
+        assert_eq!(
+            serde_json::to_value(RejectedValue::Number(Number::Duration(
+                std::time::Duration::from_secs(30)
+            )))
+            .unwrap(),
+            json!("30s")
+        );
+        assert_eq!(
+            serde_json::to_value(RejectedValue::from("taro")).unwrap(),
+            json!("taro")
+        );
+        assert_eq!(
+            serde_json::to_value(RejectedValue::Redacted).unwrap(),
+            json!("[REDACTED]")
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use super::{Error, Errors};
+
+/// The result of [`Validate::validate_with_warnings`](crate::Validate::validate_with_warnings),
+/// which separates the errors from the warnings.
+///
+/// The warnings are reported by the validations with `severity = "warning"`,
+/// and don't make the value invalid.
+#[derive(Debug, Clone)]
+pub struct Report<E = Error> {
+    pub errors: Option<Errors<E>>,
+    pub warnings: Option<Errors<E>>,
+}
+
+impl<E> Report<E> {
+    pub fn new(errors: Option<Errors<E>>, warnings: Option<Errors<E>>) -> Self {
+        Self { errors, warnings }
+    }
+
+    /// Whether the value has no errors, whatever the warnings are.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_none()
+    }
+
+    /// Drops the warnings, like [`Validate::validate`](crate::Validate::validate).
+    pub fn into_result(self) -> Result<(), Errors<E>> {
+        match self.errors {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+
+    /// Converts the errors and the warnings into another error type.
+    pub fn map_errors<T, F>(self, f: F) -> Report<T>
+    where
+        F: Fn(E) -> T,
+    {
+        Report {
+            errors: self.errors.map(|errors| errors.map_errors(&f)),
+            warnings: self.warnings.map(|warnings| warnings.map_errors(&f)),
+        }
+    }
+}
+
+impl<E> Default for Report<E> {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl<E> From<Result<(), Errors<E>>> for Report<E> {
+    fn from(result: Result<(), Errors<E>>) -> Self {
+        Self::new(result.err(), None)
+    }
+}
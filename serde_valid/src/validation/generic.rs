@@ -1,2 +1,4 @@
 mod enumerate;
+mod required;
 pub use enumerate::ValidateEnumerate;
+pub use required::ValidateRequired;
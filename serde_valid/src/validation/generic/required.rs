@@ -0,0 +1,66 @@
+use crate::RequiredError;
+
+/// Required validation, which needs the value to be present.
+///
+/// This is usually used with `when`, like `#[validate(when = "is_business", required)]`.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateRequired};
+///
+/// struct MyType(Option<String>);
+///
+/// impl ValidateRequired for MyType {
+///     fn validate_required(&self) -> Result<(), serde_valid::RequiredError> {
+///         self.0.validate_required()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(required)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct { val: MyType(None) };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value is required."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateRequired {
+    fn validate_required(&self) -> Result<(), RequiredError>;
+}
+
+impl<T> ValidateRequired for Option<T> {
+    fn validate_required(&self) -> Result<(), RequiredError> {
+        match self {
+            Some(_) => Ok(()),
+            None => Err(RequiredError::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_generic_required_is_ok() {
+        assert!(Some(1).validate_required().is_ok());
+    }
+
+    #[test]
+    fn test_validate_generic_required_is_err() {
+        assert!(None::<i32>.validate_required().is_err());
+    }
+}
@@ -1,11 +1,15 @@
 mod exclusive_maximum;
 mod exclusive_minimum;
+mod finite;
 mod maximum;
 mod minimum;
 mod multiple_of;
+mod not_nan;
 
 pub use exclusive_maximum::ValidateExclusiveMaximum;
 pub use exclusive_minimum::ValidateExclusiveMinimum;
+pub use finite::ValidateFinite;
 pub use maximum::ValidateMaximum;
 pub use minimum::ValidateMinimum;
 pub use multiple_of::ValidateMultipleOf;
+pub use not_nan::ValidateNotNan;
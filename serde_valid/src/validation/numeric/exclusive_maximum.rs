@@ -2,6 +2,8 @@ use crate::validation::{
     impl_generic_composited_validation_1args, ValidateCompositedExclusiveMaximum,
 };
 use crate::ExclusiveMaximumError;
+use num_traits::AsPrimitive;
+use serde_valid_literal::ByteSize;
 
 /// Exclusive maximum validation of the number.
 ///
@@ -96,6 +98,33 @@ impl_validate_numeric_exclusive_maximum!(std::num::NonZeroU128);
 impl_validate_numeric_exclusive_maximum!(std::num::NonZeroUsize);
 impl_validate_numeric_exclusive_maximum!(f32);
 impl_validate_numeric_exclusive_maximum!(f64);
+impl_validate_numeric_exclusive_maximum!(std::time::Duration);
+
+macro_rules! impl_validate_byte_size_exclusive_maximum {
+    ($type:ty) => {
+        impl ValidateExclusiveMaximum<ByteSize> for $type {
+            fn validate_exclusive_maximum(
+                &self,
+                exclusive_maximum: ByteSize,
+            ) -> Result<(), crate::ExclusiveMaximumError> {
+                if AsPrimitive::<u128>::as_(*self) < u128::from(exclusive_maximum.as_u64()) {
+                    Ok(())
+                } else {
+                    Err(crate::ExclusiveMaximumError::new(exclusive_maximum))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_byte_size_exclusive_maximum!(u8);
+impl_validate_byte_size_exclusive_maximum!(u16);
+impl_validate_byte_size_exclusive_maximum!(u32);
+impl_validate_byte_size_exclusive_maximum!(u64);
+#[cfg(feature = "i128")]
+impl_validate_byte_size_exclusive_maximum!(u128);
+impl_validate_byte_size_exclusive_maximum!(usize);
+impl_generic_composited_validation_1args!(ExclusiveMaximum, ByteSize);
 
 #[cfg(test)]
 mod tests {
@@ -199,4 +228,34 @@ mod tests {
         )
         .is_ok());
     }
+
+    #[test]
+    fn test_validate_numeric_exclusive_maximum_duration() {
+        use std::time::Duration;
+
+        assert!(ValidateExclusiveMaximum::validate_exclusive_maximum(
+            &Duration::from_secs(30),
+            Duration::from_secs(30)
+        )
+        .is_err());
+        assert!(ValidateExclusiveMaximum::validate_exclusive_maximum(
+            &Duration::from_millis(29_999),
+            Duration::from_secs(30)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_numeric_exclusive_maximum_byte_size() {
+        assert!(ValidateExclusiveMaximum::validate_exclusive_maximum(
+            &1023u64,
+            ByteSize::new(1024)
+        )
+        .is_ok());
+        assert!(ValidateExclusiveMaximum::validate_exclusive_maximum(
+            &1024u64,
+            ByteSize::new(1024)
+        )
+        .is_err());
+    }
 }
@@ -2,6 +2,8 @@ use crate::validation::{
     impl_generic_composited_validation_1args, ValidateCompositedExclusiveMinimum,
 };
 use crate::ExclusiveMinimumError;
+use num_traits::AsPrimitive;
+use serde_valid_literal::ByteSize;
 
 /// Exclusive minimum validation of the number.
 ///
@@ -96,6 +98,33 @@ impl_validate_numeric_exclusive_minimum!(std::num::NonZeroU128);
 impl_validate_numeric_exclusive_minimum!(std::num::NonZeroUsize);
 impl_validate_numeric_exclusive_minimum!(f32);
 impl_validate_numeric_exclusive_minimum!(f64);
+impl_validate_numeric_exclusive_minimum!(std::time::Duration);
+
+macro_rules! impl_validate_byte_size_exclusive_minimum {
+    ($type:ty) => {
+        impl ValidateExclusiveMinimum<ByteSize> for $type {
+            fn validate_exclusive_minimum(
+                &self,
+                exclusive_minimum: ByteSize,
+            ) -> Result<(), crate::ExclusiveMinimumError> {
+                if AsPrimitive::<u128>::as_(*self) > u128::from(exclusive_minimum.as_u64()) {
+                    Ok(())
+                } else {
+                    Err(crate::ExclusiveMinimumError::new(exclusive_minimum))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_byte_size_exclusive_minimum!(u8);
+impl_validate_byte_size_exclusive_minimum!(u16);
+impl_validate_byte_size_exclusive_minimum!(u32);
+impl_validate_byte_size_exclusive_minimum!(u64);
+#[cfg(feature = "i128")]
+impl_validate_byte_size_exclusive_minimum!(u128);
+impl_validate_byte_size_exclusive_minimum!(usize);
+impl_generic_composited_validation_1args!(ExclusiveMinimum, ByteSize);
 
 #[cfg(test)]
 mod tests {
@@ -200,4 +229,39 @@ mod tests {
         )
         .is_ok());
     }
+
+    #[test]
+    fn test_validate_numeric_exclusive_minimum_duration() {
+        use std::time::Duration;
+
+        assert!(ValidateExclusiveMinimum::validate_exclusive_minimum(
+            &Duration::from_secs(30),
+            Duration::from_secs(30)
+        )
+        .is_err());
+        assert!(ValidateExclusiveMinimum::validate_exclusive_minimum(
+            &Duration::from_millis(29_999),
+            Duration::from_secs(30)
+        )
+        .is_err());
+        assert!(ValidateExclusiveMinimum::validate_exclusive_minimum(
+            &Duration::from_millis(30_001),
+            Duration::from_secs(30)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_numeric_exclusive_minimum_byte_size() {
+        assert!(ValidateExclusiveMinimum::validate_exclusive_minimum(
+            &1025u64,
+            ByteSize::new(1024)
+        )
+        .is_ok());
+        assert!(ValidateExclusiveMinimum::validate_exclusive_minimum(
+            &1024u64,
+            ByteSize::new(1024)
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,79 @@
+use crate::FiniteError;
+
+/// Finite validation of the floating point number.
+///
+/// The value must be neither infinite nor NaN.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateFinite};
+///
+/// struct MyType(f64);
+///
+/// impl ValidateFinite for MyType {
+///     fn validate_finite(&self) -> Result<(), serde_valid::FiniteError> {
+///         self.0.validate_finite()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(finite)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(f64::INFINITY),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The number must be finite."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateFinite {
+    fn validate_finite(&self) -> Result<(), FiniteError>;
+}
+
+macro_rules! impl_validate_numeric_finite {
+    ($type:ty) => {
+        impl ValidateFinite for $type {
+            fn validate_finite(&self) -> Result<(), FiniteError> {
+                if self.is_finite() {
+                    Ok(())
+                } else {
+                    Err(FiniteError::new())
+                }
+            }
+        }
+    };
+}
+
+impl_validate_numeric_finite!(f32);
+impl_validate_numeric_finite!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_numeric_finite_is_true() {
+        assert!(ValidateFinite::validate_finite(&0.0f32).is_ok());
+        assert!(ValidateFinite::validate_finite(&f64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_validate_numeric_finite_is_false() {
+        assert!(ValidateFinite::validate_finite(&f32::INFINITY).is_err());
+        assert!(ValidateFinite::validate_finite(&f64::NEG_INFINITY).is_err());
+        assert!(ValidateFinite::validate_finite(&f64::NAN).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use crate::validation::{impl_generic_composited_validation_1args, ValidateCompositedMaximum};
 use crate::MaximumError;
+use num_traits::AsPrimitive;
+use serde_valid_literal::ByteSize;
 
 /// Maximum validation of the number.
 ///
@@ -90,6 +92,30 @@ impl_validate_numeric_maximum!(std::num::NonZeroU128);
 impl_validate_numeric_maximum!(std::num::NonZeroUsize);
 impl_validate_numeric_maximum!(f32);
 impl_validate_numeric_maximum!(f64);
+impl_validate_numeric_maximum!(std::time::Duration);
+
+macro_rules! impl_validate_byte_size_maximum {
+    ($type:ty) => {
+        impl ValidateMaximum<ByteSize> for $type {
+            fn validate_maximum(&self, maximum: ByteSize) -> Result<(), MaximumError> {
+                if AsPrimitive::<u128>::as_(*self) <= u128::from(maximum.as_u64()) {
+                    Ok(())
+                } else {
+                    Err(MaximumError::new(maximum))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_byte_size_maximum!(u8);
+impl_validate_byte_size_maximum!(u16);
+impl_validate_byte_size_maximum!(u32);
+impl_validate_byte_size_maximum!(u64);
+#[cfg(feature = "i128")]
+impl_validate_byte_size_maximum!(u128);
+impl_validate_byte_size_maximum!(usize);
+impl_generic_composited_validation_1args!(Maximum, ByteSize);
 
 #[cfg(test)]
 mod tests {
@@ -192,4 +218,31 @@ mod tests {
         )
         .is_ok());
     }
+
+    #[test]
+    fn test_validate_numeric_maximum_duration() {
+        use std::time::Duration;
+
+        assert!(ValidateMaximum::validate_maximum(
+            &Duration::from_secs(30),
+            Duration::from_secs(30)
+        )
+        .is_ok());
+        assert!(ValidateMaximum::validate_maximum(
+            &Duration::from_millis(29_999),
+            Duration::from_secs(30)
+        )
+        .is_ok());
+        assert!(ValidateMaximum::validate_maximum(
+            &Duration::from_millis(30_001),
+            Duration::from_secs(30)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_numeric_maximum_byte_size() {
+        assert!(ValidateMaximum::validate_maximum(&1024u64, ByteSize::new(1024)).is_ok());
+        assert!(ValidateMaximum::validate_maximum(&1025u64, ByteSize::new(1024)).is_err());
+    }
 }
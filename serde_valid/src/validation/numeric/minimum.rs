@@ -1,5 +1,7 @@
 use crate::validation::{impl_generic_composited_validation_1args, ValidateCompositedMinimum};
 use crate::MinimumError;
+use num_traits::AsPrimitive;
+use serde_valid_literal::ByteSize;
 
 /// Minimum validation of the number.
 ///
@@ -90,6 +92,30 @@ impl_validate_numeric_minimum!(std::num::NonZeroU128);
 impl_validate_numeric_minimum!(std::num::NonZeroUsize);
 impl_validate_numeric_minimum!(f32);
 impl_validate_numeric_minimum!(f64);
+impl_validate_numeric_minimum!(std::time::Duration);
+
+macro_rules! impl_validate_byte_size_minimum {
+    ($type:ty) => {
+        impl ValidateMinimum<ByteSize> for $type {
+            fn validate_minimum(&self, minimum: ByteSize) -> Result<(), MinimumError> {
+                if AsPrimitive::<u128>::as_(*self) >= u128::from(minimum.as_u64()) {
+                    Ok(())
+                } else {
+                    Err(MinimumError::new(minimum))
+                }
+            }
+        }
+    };
+}
+
+impl_validate_byte_size_minimum!(u8);
+impl_validate_byte_size_minimum!(u16);
+impl_validate_byte_size_minimum!(u32);
+impl_validate_byte_size_minimum!(u64);
+#[cfg(feature = "i128")]
+impl_validate_byte_size_minimum!(u128);
+impl_validate_byte_size_minimum!(usize);
+impl_generic_composited_validation_1args!(Minimum, ByteSize);
 
 #[cfg(test)]
 mod tests {
@@ -192,4 +218,26 @@ mod tests {
         )
         .is_ok());
     }
+
+    #[test]
+    fn test_validate_numeric_minimum_duration() {
+        use std::time::Duration;
+
+        assert!(ValidateMinimum::validate_minimum(
+            &Duration::from_secs(30),
+            Duration::from_secs(30)
+        )
+        .is_ok());
+        assert!(ValidateMinimum::validate_minimum(
+            &Duration::from_millis(29_999),
+            Duration::from_secs(30)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_numeric_minimum_byte_size() {
+        assert!(ValidateMinimum::validate_minimum(&1024u64, ByteSize::new(1024)).is_ok());
+        assert!(ValidateMinimum::validate_minimum(&1023u64, ByteSize::new(1024)).is_err());
+    }
 }
@@ -0,0 +1,76 @@
+use crate::NotNanError;
+
+/// NaN validation of the floating point number.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateNotNan};
+///
+/// struct MyType(f64);
+///
+/// impl ValidateNotNan for MyType {
+///     fn validate_not_nan(&self) -> Result<(), serde_valid::NotNanError> {
+///         self.0.validate_not_nan()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(not_nan)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(f64::NAN),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The number must not be NaN."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateNotNan {
+    fn validate_not_nan(&self) -> Result<(), NotNanError>;
+}
+
+macro_rules! impl_validate_numeric_not_nan {
+    ($type:ty) => {
+        impl ValidateNotNan for $type {
+            fn validate_not_nan(&self) -> Result<(), NotNanError> {
+                if self.is_nan() {
+                    Err(NotNanError::new())
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    };
+}
+
+impl_validate_numeric_not_nan!(f32);
+impl_validate_numeric_not_nan!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_numeric_not_nan_is_true() {
+        assert!(ValidateNotNan::validate_not_nan(&1.5f32).is_ok());
+        assert!(ValidateNotNan::validate_not_nan(&f64::INFINITY).is_ok());
+    }
+
+    #[test]
+    fn test_validate_numeric_not_nan_is_false() {
+        assert!(ValidateNotNan::validate_not_nan(&f32::NAN).is_err());
+        assert!(ValidateNotNan::validate_not_nan(&f64::NAN).is_err());
+    }
+}
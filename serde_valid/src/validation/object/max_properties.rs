@@ -59,7 +59,7 @@ where
         if max_properties >= self.size() {
             Ok(())
         } else {
-            Err(MaxPropertiesError::new(max_properties))
+            Err(MaxPropertiesError::new(max_properties, self.size()))
         }
     }
 }
@@ -113,4 +113,29 @@ mod tests {
 
         assert!(ValidateMaxProperties::validate_max_properties(map, 2).is_err());
     }
+
+    #[test]
+    fn test_validate_object_max_properties_index_map_type() {
+        let mut map = indexmap::IndexMap::new();
+        map.insert("key1".to_string(), "value1".to_string());
+        map.insert("key2".to_string(), "value2".to_string());
+        assert!(ValidateMaxProperties::validate_max_properties(&map, 2).is_ok());
+        assert!(ValidateMaxProperties::validate_max_properties(&map, 1).is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_validate_object_max_properties_toml_table_type() {
+        let table: serde_toml::Table = serde_toml::from_str("key1 = 1\nkey2 = 2").unwrap();
+        assert!(ValidateMaxProperties::validate_max_properties(&table, 2).is_ok());
+        assert!(ValidateMaxProperties::validate_max_properties(&table, 1).is_err());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_validate_object_max_properties_yaml_mapping_type() {
+        let mapping: serde_yaml::Mapping = serde_yaml::from_str("key1: 1\nkey2: 2").unwrap();
+        assert!(ValidateMaxProperties::validate_max_properties(&mapping, 2).is_ok());
+        assert!(ValidateMaxProperties::validate_max_properties(&mapping, 1).is_err());
+    }
 }
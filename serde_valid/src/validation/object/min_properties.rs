@@ -57,7 +57,7 @@ where
         if min_properties <= self.size() {
             Ok(())
         } else {
-            Err(MinPropertiesError::new(min_properties))
+            Err(MinPropertiesError::new(min_properties, self.size()))
         }
     }
 }
@@ -111,4 +111,29 @@ mod tests {
 
         assert!(ValidateMinProperties::validate_min_properties(map, 4).is_err());
     }
+
+    #[test]
+    fn test_validate_object_min_properties_index_map_type() {
+        let mut map = indexmap::IndexMap::new();
+        map.insert("key1".to_string(), "value1".to_string());
+        map.insert("key2".to_string(), "value2".to_string());
+        assert!(ValidateMinProperties::validate_min_properties(&map, 2).is_ok());
+        assert!(ValidateMinProperties::validate_min_properties(&map, 3).is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_validate_object_min_properties_toml_table_type() {
+        let table: serde_toml::Table = serde_toml::from_str("key1 = 1\nkey2 = 2").unwrap();
+        assert!(ValidateMinProperties::validate_min_properties(&table, 2).is_ok());
+        assert!(ValidateMinProperties::validate_min_properties(&table, 3).is_err());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_validate_object_min_properties_yaml_mapping_type() {
+        let mapping: serde_yaml::Mapping = serde_yaml::from_str("key1: 1\nkey2: 2").unwrap();
+        assert!(ValidateMinProperties::validate_min_properties(&mapping, 2).is_ok());
+        assert!(ValidateMinProperties::validate_min_properties(&mapping, 3).is_err());
+    }
 }
@@ -1,6 +1,40 @@
+mod alphanumeric;
+mod ascii;
+mod charset;
+mod contains;
+mod ean;
+#[cfg(feature = "fancy_regex")]
+mod ecma_pattern;
+mod email;
+mod ends_with;
+mod iban;
+mod isbn;
+mod luhn;
 mod max_length;
 mod min_length;
+mod not_blank;
+mod not_contains;
+mod not_pattern;
 mod pattern;
+mod starts_with;
+mod url;
+pub use alphanumeric::ValidateAlphanumeric;
+pub use ascii::ValidateAscii;
+pub use charset::ValidateCharset;
+pub use contains::ValidateContains;
+pub use ean::ValidateEan;
+#[cfg(feature = "fancy_regex")]
+pub use ecma_pattern::{ValidateEcmaNotPattern, ValidateEcmaPattern};
+pub use email::ValidateEmail;
+pub use ends_with::ValidateEndsWith;
+pub use iban::ValidateIban;
+pub use isbn::ValidateIsbn;
+pub use luhn::ValidateLuhn;
 pub use max_length::ValidateMaxLength;
 pub use min_length::ValidateMinLength;
-pub use pattern::ValidatePattern;
+pub use not_blank::ValidateNotBlank;
+pub use not_contains::ValidateNotContains;
+pub use not_pattern::ValidateNotPattern;
+pub use pattern::{PatternMode, ValidatePattern};
+pub use starts_with::ValidateStartsWith;
+pub use url::ValidateUrl;
@@ -0,0 +1,84 @@
+use crate::{traits::LossyStr, AlphanumericError};
+
+/// Alphanumeric validation of the string.
+///
+/// Every character must be a Unicode letter or number, see [`char::is_alphanumeric`].
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateAlphanumeric};
+///
+/// struct MyType(String);
+///
+/// impl ValidateAlphanumeric for MyType {
+///     fn validate_alphanumeric(&self) -> Result<(), serde_valid::AlphanumericError> {
+///         self.0.validate_alphanumeric()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(alphanumeric)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("abc-123")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must contain only alphanumeric characters."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateAlphanumeric {
+    fn validate_alphanumeric(&self) -> Result<(), AlphanumericError>;
+}
+
+impl<T> ValidateAlphanumeric for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_alphanumeric(&self) -> Result<(), AlphanumericError> {
+        if self.lossy_str().chars().all(char::is_alphanumeric) {
+            Ok(())
+        } else {
+            Err(AlphanumericError::new())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_alphanumeric_is_true() {
+        assert!(ValidateAlphanumeric::validate_alphanumeric("abc123").is_ok());
+        assert!(ValidateAlphanumeric::validate_alphanumeric("äbç１２３").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_alphanumeric_is_false() {
+        assert!(ValidateAlphanumeric::validate_alphanumeric("abc-123").is_err());
+    }
+
+    #[test]
+    fn test_validate_string_alphanumeric_other_types() {
+        assert!(ValidateAlphanumeric::validate_alphanumeric(&String::from("abc123")).is_ok());
+        assert!(ValidateAlphanumeric::validate_alphanumeric(&Cow::from("abc123")).is_ok());
+        assert!(ValidateAlphanumeric::validate_alphanumeric(OsStr::new("abc123")).is_ok());
+        assert!(ValidateAlphanumeric::validate_alphanumeric(Path::new("abc123")).is_ok());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{traits::LossyStr, AsciiError};
+
+/// ASCII validation of the string.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateAscii};
+///
+/// struct MyType(String);
+///
+/// impl ValidateAscii for MyType {
+///     fn validate_ascii(&self) -> Result<(), serde_valid::AsciiError> {
+///         self.0.validate_ascii()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(ascii)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("café")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must contain only ASCII characters."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateAscii {
+    fn validate_ascii(&self) -> Result<(), AsciiError>;
+}
+
+impl<T> ValidateAscii for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_ascii(&self) -> Result<(), AsciiError> {
+        if self.lossy_str().is_ascii() {
+            Ok(())
+        } else {
+            Err(AsciiError::new())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_ascii_is_true() {
+        assert!(ValidateAscii::validate_ascii("cafe").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_ascii_is_false() {
+        assert!(ValidateAscii::validate_ascii("café").is_err());
+    }
+
+    #[test]
+    fn test_validate_string_ascii_other_types() {
+        assert!(ValidateAscii::validate_ascii(&String::from("cafe")).is_ok());
+        assert!(ValidateAscii::validate_ascii(&Cow::from("cafe")).is_ok());
+        assert!(ValidateAscii::validate_ascii(OsStr::new("cafe")).is_ok());
+        assert!(ValidateAscii::validate_ascii(Path::new("cafe")).is_ok());
+    }
+}
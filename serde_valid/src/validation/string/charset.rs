@@ -0,0 +1,112 @@
+use crate::{traits::LossyStr, CharsetError};
+
+/// Charset validation of the string.
+///
+/// Every character must be in the charset. The charset is a list of the characters
+/// and the ranges like `"a-z0-9_"`. `-` at the start or the end of the charset is literal.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateCharset};
+///
+/// struct MyType(String);
+///
+/// impl ValidateCharset for MyType {
+///     fn validate_charset(&self, charset: &str) -> Result<(), serde_valid::CharsetError> {
+///         self.0.validate_charset(charset)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(charset = "a-z0-9_")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("Snake_Case")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must consist of the characters in \"a-z0-9_\"."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateCharset {
+    fn validate_charset(&self, charset: &str) -> Result<(), CharsetError>;
+}
+
+impl<T> ValidateCharset for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_charset(&self, charset: &str) -> Result<(), CharsetError> {
+        let chars = charset.chars().collect::<Vec<_>>();
+
+        if self.lossy_str().chars().all(|c| in_charset(&chars, c)) {
+            Ok(())
+        } else {
+            Err(CharsetError::new(charset))
+        }
+    }
+}
+
+fn in_charset(charset: &[char], c: char) -> bool {
+    let mut index = 0;
+    while index < charset.len() {
+        if index + 2 < charset.len() && charset[index + 1] == '-' {
+            if (charset[index]..=charset[index + 2]).contains(&c) {
+                return true;
+            }
+            index += 3;
+        } else {
+            if charset[index] == c {
+                return true;
+            }
+            index += 1;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_charset_is_true() {
+        assert!(ValidateCharset::validate_charset("snake_case_1", "a-z0-9_").is_ok());
+        assert!(ValidateCharset::validate_charset("", "a-z").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_charset_is_false() {
+        assert!(ValidateCharset::validate_charset("Snake_Case", "a-z0-9_").is_err());
+        assert!(ValidateCharset::validate_charset("a-b", "a-z").is_err());
+    }
+
+    #[test]
+    fn test_validate_string_charset_literal_hyphen() {
+        assert!(ValidateCharset::validate_charset("a-b", "-a-z").is_ok());
+        assert!(ValidateCharset::validate_charset("a-b", "a-z-").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_charset_other_types() {
+        assert!(ValidateCharset::validate_charset(&String::from("abc"), "a-z").is_ok());
+        assert!(ValidateCharset::validate_charset(&Cow::from("abc"), "a-z").is_ok());
+        assert!(ValidateCharset::validate_charset(OsStr::new("abc"), "a-z").is_ok());
+        assert!(ValidateCharset::validate_charset(Path::new("abc"), "a-z").is_ok());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{traits::LossyStr, ContainsError};
+
+/// Substring validation of the string.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateContains};
+///
+/// struct MyType(String);
+///
+/// impl ValidateContains for MyType {
+///     fn validate_contains(&self, contains: &str) -> Result<(), serde_valid::ContainsError> {
+///         self.0.validate_contains(contains)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(contains = "@")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("example.com")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must contain \"@\"."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateContains {
+    fn validate_contains(&self, contains: &str) -> Result<(), ContainsError>;
+}
+
+impl<T> ValidateContains for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_contains(&self, contains: &str) -> Result<(), ContainsError> {
+        if self.lossy_str().contains(contains) {
+            Ok(())
+        } else {
+            Err(ContainsError::new(contains))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_contains_is_true() {
+        assert!(ValidateContains::validate_contains("user@example.com", "@").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_contains_is_false() {
+        assert!(ValidateContains::validate_contains("example.com", "@").is_err());
+    }
+
+    #[test]
+    fn test_validate_string_contains_other_types() {
+        assert!(
+            ValidateContains::validate_contains(&String::from("user@example.com"), "@").is_ok()
+        );
+        assert!(ValidateContains::validate_contains(&Cow::from("user@example.com"), "@").is_ok());
+        assert!(ValidateContains::validate_contains(OsStr::new("user@example.com"), "@").is_ok());
+        assert!(ValidateContains::validate_contains(Path::new("user@example.com"), "@").is_ok());
+    }
+}
@@ -0,0 +1,92 @@
+use super::luhn::digits_without_separators;
+use crate::{traits::LossyStr, EanError};
+
+/// EAN (GTIN-8, GTIN-12, GTIN-13 or GTIN-14) check digit validation of the string.
+///
+/// Spaces and hyphens between the digits are ignored.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateEan};
+///
+/// struct MyType(String);
+///
+/// impl ValidateEan for MyType {
+///     fn validate_ean(&self) -> Result<(), serde_valid::EanError> {
+///         self.0.validate_ean()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(ean)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("4006381333932")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must be a valid EAN."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateEan {
+    fn validate_ean(&self) -> Result<(), EanError>;
+}
+
+impl<T> ValidateEan for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_ean(&self) -> Result<(), EanError> {
+        if is_ean(&self.lossy_str()) {
+            Ok(())
+        } else {
+            Err(EanError::new())
+        }
+    }
+}
+
+pub(crate) fn is_ean(value: &str) -> bool {
+    let digits = match digits_without_separators(value) {
+        Some(digits) if matches!(digits.len(), 8 | 12 | 13 | 14) => digits,
+        _ => return false,
+    };
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| if index % 2 == 0 { digit } else { digit * 3 })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_string_ean_is_true() {
+        assert!(ValidateEan::validate_ean("4006381333931").is_ok());
+        assert!(ValidateEan::validate_ean("96385074").is_ok());
+        assert!(ValidateEan::validate_ean("036000291452").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_ean_is_false() {
+        assert!(ValidateEan::validate_ean("4006381333932").is_err());
+        assert!(ValidateEan::validate_ean("400638133393").is_err());
+        assert!(ValidateEan::validate_ean("40063813339a1").is_err());
+    }
+}
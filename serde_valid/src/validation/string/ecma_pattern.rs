@@ -0,0 +1,112 @@
+use crate::{traits::LossyStr, NotPatternError, PatternError, PatternMode};
+use fancy_regex::Regex;
+
+/// Pattern validation of the string with the ECMA-262 like syntax, such as lookaround.
+///
+/// The [`PatternError::mode`] is [`PatternMode::Ecma`].
+/// The string which exceeds the backtrack limit of the regex engine is treated as invalid.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(pattern(r"^(?=.*\d)(?=.*[a-z]).{8,}$", ecma))]
+///     val: String,
+/// }
+///
+/// let s = TestStruct {
+///     val: String::from("password"),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": [r#"The value must match the pattern of "^(?=.*\d)(?=.*[a-z]).{8,}$"."#]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateEcmaPattern {
+    fn validate_ecma_pattern(&self, ecma_pattern: &Regex) -> Result<(), PatternError>;
+}
+
+impl<T> ValidateEcmaPattern for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_ecma_pattern(&self, ecma_pattern: &Regex) -> Result<(), PatternError> {
+        if let Ok(true) = ecma_pattern.is_match(&self.lossy_str()) {
+            Ok(())
+        } else {
+            Err(PatternError::new(ecma_pattern).with_mode(PatternMode::Ecma))
+        }
+    }
+}
+
+/// Negative pattern validation of the string with the ECMA-262 like syntax, such as lookaround.
+///
+/// The [`NotPatternError::mode`] is [`PatternMode::Ecma`].
+/// The string which exceeds the backtrack limit of the regex engine is treated as invalid.
+///
+/// ```rust
+/// use serde_valid::Validate;
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(not_pattern(r"(\w)\1", ecma))]
+///     val: String,
+/// }
+///
+/// let s = TestStruct {
+///     val: String::from("abcd"),
+/// };
+///
+/// assert!(s.validate().is_ok());
+/// ```
+pub trait ValidateEcmaNotPattern {
+    fn validate_ecma_not_pattern(&self, ecma_not_pattern: &Regex) -> Result<(), NotPatternError>;
+}
+
+impl<T> ValidateEcmaNotPattern for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_ecma_not_pattern(&self, ecma_not_pattern: &Regex) -> Result<(), NotPatternError> {
+        if let Ok(false) = ecma_not_pattern.is_match(&self.lossy_str()) {
+            Ok(())
+        } else {
+            Err(NotPatternError::new(ecma_not_pattern).with_mode(PatternMode::Ecma))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_string_ecma_pattern_lookahead() {
+        let pattern = Regex::new(r"^(?=.*\d).+$").unwrap();
+        assert!(ValidateEcmaPattern::validate_ecma_pattern("abc1", &pattern).is_ok());
+        assert_eq!(
+            ValidateEcmaPattern::validate_ecma_pattern("abc", &pattern)
+                .unwrap_err()
+                .mode,
+            PatternMode::Ecma
+        );
+    }
+
+    #[test]
+    fn test_validate_string_ecma_not_pattern_backreference() {
+        let not_pattern = Regex::new(r"(\w)\1").unwrap();
+        assert!(ValidateEcmaNotPattern::validate_ecma_not_pattern("abcd", &not_pattern).is_ok());
+        assert!(ValidateEcmaNotPattern::validate_ecma_not_pattern("abbc", &not_pattern).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{traits::LossyStr, EmailError};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// The valid email address of the HTML living standard.
+///
+/// See <https://html.spec.whatwg.org/multipage/input.html#valid-e-mail-address>
+static EMAIL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$",
+    )
+    .unwrap()
+});
+
+/// Email address validation of the string.
+///
+/// The address is checked by the syntax of the HTML living standard.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateEmail};
+///
+/// struct MyType(String);
+///
+/// impl ValidateEmail for MyType {
+///     fn validate_email(&self) -> Result<(), serde_valid::EmailError> {
+///         self.0.validate_email()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(email)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("user.example.com")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must be a valid email address."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateEmail {
+    fn validate_email(&self) -> Result<(), EmailError>;
+}
+
+impl<T> ValidateEmail for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_email(&self) -> Result<(), EmailError> {
+        if EMAIL.is_match(&self.lossy_str()) {
+            Ok(())
+        } else {
+            Err(EmailError::new())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_string_email_is_true() {
+        assert!(ValidateEmail::validate_email("user@example.com").is_ok());
+        assert!(ValidateEmail::validate_email("first.last+tag@sub.example.co.jp").is_ok());
+        assert!(ValidateEmail::validate_email("user@localhost").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_email_is_false() {
+        assert!(ValidateEmail::validate_email("").is_err());
+        assert!(ValidateEmail::validate_email("user.example.com").is_err());
+        assert!(ValidateEmail::validate_email("user@").is_err());
+        assert!(ValidateEmail::validate_email("@example.com").is_err());
+        assert!(ValidateEmail::validate_email("user@-example.com").is_err());
+        assert!(ValidateEmail::validate_email("user@example..com").is_err());
+        assert!(ValidateEmail::validate_email("us er@example.com").is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{traits::LossyStr, EndsWithError};
+
+/// Suffix validation of the string.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateEndsWith};
+///
+/// struct MyType(String);
+///
+/// impl ValidateEndsWith for MyType {
+///     fn validate_ends_with(&self, ends_with: &str) -> Result<(), serde_valid::EndsWithError> {
+///         self.0.validate_ends_with(ends_with)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(ends_with = ".json")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("config.yaml")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must end with \".json\"."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateEndsWith {
+    fn validate_ends_with(&self, ends_with: &str) -> Result<(), EndsWithError>;
+}
+
+impl<T> ValidateEndsWith for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_ends_with(&self, ends_with: &str) -> Result<(), EndsWithError> {
+        if self.lossy_str().ends_with(ends_with) {
+            Ok(())
+        } else {
+            Err(EndsWithError::new(ends_with))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_ends_with_is_true() {
+        assert!(ValidateEndsWith::validate_ends_with("config.json", ".json").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_ends_with_is_false() {
+        assert!(ValidateEndsWith::validate_ends_with("config.yaml", ".json").is_err());
+    }
+
+    #[test]
+    fn test_validate_string_ends_with_other_types() {
+        assert!(
+            ValidateEndsWith::validate_ends_with(&String::from("config.json"), ".json").is_ok()
+        );
+        assert!(ValidateEndsWith::validate_ends_with(&Cow::from("config.json"), ".json").is_ok());
+        assert!(ValidateEndsWith::validate_ends_with(OsStr::new("config.json"), ".json").is_ok());
+        assert!(ValidateEndsWith::validate_ends_with(Path::new("config.json"), ".json").is_ok());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{traits::LossyStr, IbanError};
+
+/// IBAN (International Bank Account Number) validation of the string.
+///
+/// Spaces are ignored and the letters are case insensitive.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateIban};
+///
+/// struct MyType(String);
+///
+/// impl ValidateIban for MyType {
+///     fn validate_iban(&self) -> Result<(), serde_valid::IbanError> {
+///         self.0.validate_iban()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(iban)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("GB82 WEST 1234 5698 7654 31")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must be a valid IBAN."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateIban {
+    fn validate_iban(&self) -> Result<(), IbanError>;
+}
+
+impl<T> ValidateIban for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_iban(&self) -> Result<(), IbanError> {
+        if is_iban(&self.lossy_str()) {
+            Ok(())
+        } else {
+            Err(IbanError::new())
+        }
+    }
+}
+
+fn is_iban(value: &str) -> bool {
+    let chars = value
+        .chars()
+        .filter(|c| *c != ' ')
+        .map(|c| c.to_ascii_uppercase())
+        .collect::<Vec<_>>();
+
+    if !(15..=34).contains(&chars.len())
+        || !chars[..2].iter().all(char::is_ascii_uppercase)
+        || !chars[2..4].iter().all(char::is_ascii_digit)
+    {
+        return false;
+    }
+
+    let mut remainder = 0u32;
+    for c in chars[4..].iter().chain(&chars[..4]) {
+        remainder = match c.to_digit(36) {
+            Some(value) if value < 10 => (remainder * 10 + value) % 97,
+            Some(value) => (remainder * 100 + value) % 97,
+            None => return false,
+        };
+    }
+    remainder == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_string_iban_is_true() {
+        assert!(ValidateIban::validate_iban("GB82 WEST 1234 5698 7654 32").is_ok());
+        assert!(ValidateIban::validate_iban("de89370400440532013000").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_iban_is_false() {
+        assert!(ValidateIban::validate_iban("GB82 WEST 1234 5698 7654 31").is_err());
+        assert!(ValidateIban::validate_iban("GB82").is_err());
+        assert!(ValidateIban::validate_iban("GB82-WEST-1234-5698-7654-32").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+use super::ean::is_ean;
+use crate::{traits::LossyStr, IsbnError};
+
+/// ISBN-10 or ISBN-13 validation of the string.
+///
+/// Spaces and hyphens between the digits are ignored.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateIsbn};
+///
+/// struct MyType(String);
+///
+/// impl ValidateIsbn for MyType {
+///     fn validate_isbn(&self) -> Result<(), serde_valid::IsbnError> {
+///         self.0.validate_isbn()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(isbn)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("978-3-16-148410-1")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must be a valid ISBN."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateIsbn {
+    fn validate_isbn(&self) -> Result<(), IsbnError>;
+}
+
+impl<T> ValidateIsbn for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_isbn(&self) -> Result<(), IsbnError> {
+        if is_isbn(&self.lossy_str()) {
+            Ok(())
+        } else {
+            Err(IsbnError::new())
+        }
+    }
+}
+
+fn is_isbn(value: &str) -> bool {
+    let chars = value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-'))
+        .collect::<Vec<_>>();
+
+    match chars.len() {
+        10 => {
+            let mut sum = 0;
+            for (index, c) in chars.iter().enumerate() {
+                let digit = match (index, c) {
+                    (9, 'X' | 'x') => 10,
+                    (_, c) => match c.to_digit(10) {
+                        Some(digit) => digit,
+                        None => return false,
+                    },
+                };
+                sum += digit * (10 - index as u32);
+            }
+            sum % 11 == 0
+        }
+        13 => {
+            (chars.starts_with(&['9', '7', '8']) || chars.starts_with(&['9', '7', '9']))
+                && is_ean(&String::from_iter(chars))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_string_isbn_is_true() {
+        assert!(ValidateIsbn::validate_isbn("978-3-16-148410-0").is_ok());
+        assert!(ValidateIsbn::validate_isbn("0-306-40615-2").is_ok());
+        assert!(ValidateIsbn::validate_isbn("080442957X").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_isbn_is_false() {
+        assert!(ValidateIsbn::validate_isbn("978-3-16-148410-1").is_err());
+        assert!(ValidateIsbn::validate_isbn("0-306-40615-3").is_err());
+        assert!(ValidateIsbn::validate_isbn("123").is_err());
+    }
+}
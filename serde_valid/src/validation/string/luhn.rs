@@ -0,0 +1,102 @@
+use crate::{traits::LossyStr, LuhnError};
+
+/// Luhn checksum validation of the string, used by the payment card numbers.
+///
+/// Spaces and hyphens between the digits are ignored.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateLuhn};
+///
+/// struct MyType(String);
+///
+/// impl ValidateLuhn for MyType {
+///     fn validate_luhn(&self) -> Result<(), serde_valid::LuhnError> {
+///         self.0.validate_luhn()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(luhn)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("4111 1111 1111 1112")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must be a valid Luhn number."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateLuhn {
+    fn validate_luhn(&self) -> Result<(), LuhnError>;
+}
+
+impl<T> ValidateLuhn for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_luhn(&self) -> Result<(), LuhnError> {
+        if is_luhn(&self.lossy_str()) {
+            Ok(())
+        } else {
+            Err(LuhnError::new())
+        }
+    }
+}
+
+fn is_luhn(value: &str) -> bool {
+    let digits = match digits_without_separators(value) {
+        Some(digits) if digits.len() >= 2 => digits,
+        _ => return false,
+    };
+
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| match (index % 2, digit * 2) {
+            (0, _) => digit,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+pub(crate) fn digits_without_separators(value: &str) -> Option<Vec<u32>> {
+    value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-'))
+        .map(|c| c.to_digit(10))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_string_luhn_is_true() {
+        assert!(ValidateLuhn::validate_luhn("4111 1111 1111 1111").is_ok());
+        assert!(ValidateLuhn::validate_luhn("79927398713").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_luhn_is_false() {
+        assert!(ValidateLuhn::validate_luhn("4111 1111 1111 1112").is_err());
+        assert!(ValidateLuhn::validate_luhn("0").is_err());
+        assert!(ValidateLuhn::validate_luhn("4111a1111").is_err());
+    }
+}
@@ -54,7 +54,7 @@ where
         if max_length >= self.length() {
             Ok(())
         } else {
-            Err(MaxLengthError::new(max_length))
+            Err(MaxLengthError::new(max_length, self.length()))
         }
     }
 }
@@ -54,7 +54,7 @@ where
         if min_length <= self.length() {
             Ok(())
         } else {
-            Err(MinLengthError::new(min_length))
+            Err(MinLengthError::new(min_length, self.length()))
         }
     }
 }
@@ -0,0 +1,84 @@
+use crate::{traits::LossyStr, NotBlankError};
+
+/// Blank validation of the string.
+///
+/// The value must contain at least one non-whitespace character.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateNotBlank};
+///
+/// struct MyType(String);
+///
+/// impl ValidateNotBlank for MyType {
+///     fn validate_not_blank(&self) -> Result<(), serde_valid::NotBlankError> {
+///         self.0.validate_not_blank()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(not_blank)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("   ")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must not be blank."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateNotBlank {
+    fn validate_not_blank(&self) -> Result<(), NotBlankError>;
+}
+
+impl<T> ValidateNotBlank for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_not_blank(&self) -> Result<(), NotBlankError> {
+        if !self.lossy_str().trim().is_empty() {
+            Ok(())
+        } else {
+            Err(NotBlankError::new())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_not_blank_is_true() {
+        assert!(ValidateNotBlank::validate_not_blank("  a ").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_not_blank_is_false() {
+        assert!(ValidateNotBlank::validate_not_blank(" \t\n").is_err());
+        assert!(ValidateNotBlank::validate_not_blank("").is_err());
+    }
+
+    #[test]
+    fn test_validate_string_not_blank_other_types() {
+        assert!(ValidateNotBlank::validate_not_blank(&String::from("  a ")).is_ok());
+        assert!(ValidateNotBlank::validate_not_blank(&Cow::from("  a ")).is_ok());
+        assert!(ValidateNotBlank::validate_not_blank(OsStr::new("  a ")).is_ok());
+        assert!(ValidateNotBlank::validate_not_blank(Path::new("  a ")).is_ok());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{traits::LossyStr, NotContainsError};
+
+/// Forbidden substring validation of the string.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateNotContains};
+///
+/// struct MyType(String);
+///
+/// impl ValidateNotContains for MyType {
+///     fn validate_not_contains(&self, not_contains: &str) -> Result<(), serde_valid::NotContainsError> {
+///         self.0.validate_not_contains(not_contains)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(not_contains = "..")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("../secret")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must not contain \"..\"."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateNotContains {
+    fn validate_not_contains(&self, not_contains: &str) -> Result<(), NotContainsError>;
+}
+
+impl<T> ValidateNotContains for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_not_contains(&self, not_contains: &str) -> Result<(), NotContainsError> {
+        if !self.lossy_str().contains(not_contains) {
+            Ok(())
+        } else {
+            Err(NotContainsError::new(not_contains))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_not_contains_is_true() {
+        assert!(ValidateNotContains::validate_not_contains("foo/bar", "..").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_not_contains_is_false() {
+        assert!(ValidateNotContains::validate_not_contains("../secret", "..").is_err());
+    }
+
+    #[test]
+    fn test_validate_string_not_contains_other_types() {
+        assert!(ValidateNotContains::validate_not_contains(&String::from("foo/bar"), "..").is_ok());
+        assert!(ValidateNotContains::validate_not_contains(&Cow::from("foo/bar"), "..").is_ok());
+        assert!(ValidateNotContains::validate_not_contains(OsStr::new("foo/bar"), "..").is_ok());
+        assert!(ValidateNotContains::validate_not_contains(Path::new("foo/bar"), "..").is_ok());
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{traits::IsMatch, NotPatternError};
+use regex::Regex;
+
+/// Negative pattern validation of the string.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateNotPattern};
+///
+/// struct MyType(String);
+///
+/// impl ValidateNotPattern for MyType {
+///     fn validate_not_pattern(
+///         &self,
+///         not_pattern: &regex::Regex,
+///     ) -> Result<(), serde_valid::NotPatternError> {
+///         self.0.validate_not_pattern(not_pattern)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(not_pattern = r"^\s|\s$")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from(" padded")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": [r#"The value must not match the pattern of "^\s|\s$"."#]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateNotPattern {
+    fn validate_not_pattern(&self, not_pattern: &Regex) -> Result<(), NotPatternError>;
+}
+
+impl<T> ValidateNotPattern for T
+where
+    T: IsMatch + ?Sized,
+{
+    fn validate_not_pattern(&self, not_pattern: &Regex) -> Result<(), NotPatternError> {
+        if !self.is_match(not_pattern) {
+            Ok(())
+        } else {
+            Err(NotPatternError::new(not_pattern))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_not_pattern_is_true() {
+        assert!(
+            ValidateNotPattern::validate_not_pattern("foo/bar", &Regex::new(r"\.\.").unwrap())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_string_not_pattern_is_false() {
+        assert!(ValidateNotPattern::validate_not_pattern(
+            "../secret",
+            &Regex::new(r"\.\.").unwrap()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_string_not_pattern_other_types() {
+        let not_pattern = Regex::new(r"\.\.").unwrap();
+        assert!(
+            ValidateNotPattern::validate_not_pattern(&String::from("foo/bar"), &not_pattern)
+                .is_ok()
+        );
+        assert!(
+            ValidateNotPattern::validate_not_pattern(&Cow::from("foo/bar"), &not_pattern).is_ok()
+        );
+        assert!(
+            ValidateNotPattern::validate_not_pattern(OsStr::new("foo/bar"), &not_pattern).is_ok()
+        );
+        assert!(
+            ValidateNotPattern::validate_not_pattern(Path::new("foo/bar"), &not_pattern).is_ok()
+        );
+    }
+}
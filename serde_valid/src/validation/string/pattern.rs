@@ -1,6 +1,25 @@
 use crate::{traits::IsMatch, PatternError};
 use regex::Regex;
 
+/// The regex engine used by the pattern validation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PatternMode {
+    /// The [`regex`](https://docs.rs/regex) crate syntax, which has no lookaround and backreference.
+    #[default]
+    Regex,
+    /// The ECMA-262 like syntax backed by the [`fancy-regex`](https://docs.rs/fancy-regex) crate.
+    Ecma,
+}
+
+impl std::fmt::Display for PatternMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Regex => write!(f, "regex"),
+            Self::Ecma => write!(f, "ECMA-262"),
+        }
+    }
+}
+
 /// Pattern validation of the string.
 ///
 /// See <https://json-schema.org/understanding-json-schema/reference/string.html#regular-expressions>
@@ -43,6 +62,35 @@ use regex::Regex;
 ///     .to_string()
 /// );
 /// ```
+///
+/// The options follow the pattern in the list form, like `pattern(r"^[a-z]+$", case_insensitive)`.
+///
+/// - `case_insensitive`: ignores the case of the letters.
+/// - `full_match`: the pattern must match the whole value.
+/// - `ecma`: ECMA-262 like syntax, such as lookaround (requires the `fancy_regex` feature).
+///
+/// The pattern can also be a path to a shared regex, like `pattern = MY_REGEX`.
+/// Any path which derefs to [`Regex`](regex::Regex) is allowed.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::export::{Lazy, Regex};
+/// use serde_valid::Validate;
+///
+/// static DATE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap());
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(pattern = DATE)]
+///     val: String,
+/// }
+///
+/// let s = TestStruct {
+///     val: String::from("2020-09-10"),
+/// };
+///
+/// assert!(s.validate().is_ok());
+/// ```
 pub trait ValidatePattern {
     fn validate_pattern(&self, pattern: &Regex) -> Result<(), PatternError>;
 }
@@ -60,6 +108,50 @@ where
     }
 }
 
+/// Define the named patterns, which can be shared across the validations.
+///
+/// Each pattern is compiled on the first use, and panics if it is invalid.
+///
+/// ```rust
+/// use serde_valid::Validate;
+///
+/// mod patterns {
+///     serde_valid::patterns! {
+///         pub ZIP_CODE = r"^\d{5}$";
+///         pub DATE = r"^\d{4}-\d{2}-\d{2}$";
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(pattern = patterns::ZIP_CODE)]
+///     zip_code: String,
+///     #[validate(pattern = patterns::DATE)]
+///     date: String,
+/// }
+///
+/// let s = TestStruct {
+///     zip_code: String::from("12345"),
+///     date: String::from("2020-09-10"),
+/// };
+///
+/// assert!(s.validate().is_ok());
+/// ```
+#[macro_export]
+macro_rules! patterns {
+    ($($(#[$meta:meta])* $vis:vis $name:ident = $pattern:expr;)*) => {
+        $(
+            $(#[$meta])*
+            $vis static $name: $crate::export::Lazy<$crate::export::Regex> =
+                $crate::export::Lazy::new(|| {
+                    $crate::export::Regex::new($pattern).unwrap_or_else(|error| {
+                        panic!("invalid pattern `{}`: {}", stringify!($name), error)
+                    })
+                });
+        )*
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
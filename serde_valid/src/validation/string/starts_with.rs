@@ -0,0 +1,101 @@
+use crate::{traits::LossyStr, StartsWithError};
+
+/// Prefix validation of the string.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateStartsWith};
+///
+/// struct MyType(String);
+///
+/// impl ValidateStartsWith for MyType {
+///     fn validate_starts_with(&self, starts_with: &str) -> Result<(), serde_valid::StartsWithError> {
+///         self.0.validate_starts_with(starts_with)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(starts_with = "https://")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("http://example.com")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must start with \"https://\"."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateStartsWith {
+    fn validate_starts_with(&self, starts_with: &str) -> Result<(), StartsWithError>;
+}
+
+impl<T> ValidateStartsWith for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_starts_with(&self, starts_with: &str) -> Result<(), StartsWithError> {
+        if self.lossy_str().starts_with(starts_with) {
+            Ok(())
+        } else {
+            Err(StartsWithError::new(starts_with))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    #[test]
+    fn test_validate_string_starts_with_is_true() {
+        assert!(
+            ValidateStartsWith::validate_starts_with("https://example.com", "https://").is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_string_starts_with_is_false() {
+        assert!(
+            ValidateStartsWith::validate_starts_with("http://example.com", "https://").is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_string_starts_with_other_types() {
+        assert!(ValidateStartsWith::validate_starts_with(
+            &String::from("https://example.com"),
+            "https://"
+        )
+        .is_ok());
+        assert!(ValidateStartsWith::validate_starts_with(
+            &Cow::from("https://example.com"),
+            "https://"
+        )
+        .is_ok());
+        assert!(ValidateStartsWith::validate_starts_with(
+            OsStr::new("https://example.com"),
+            "https://"
+        )
+        .is_ok());
+        assert!(ValidateStartsWith::validate_starts_with(
+            Path::new("https://example.com"),
+            "https://"
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{traits::LossyStr, UrlError};
+
+/// The schemes which need the host, like `http://example.com`.
+const SPECIAL_SCHEMES: [&str; 6] = ["http", "https", "ftp", "ws", "wss", "file"];
+
+/// The schemes without the authority, like `mailto:user@example.com`.
+const OPAQUE_SCHEMES: [&str; 5] = ["mailto", "urn", "tel", "data", "news"];
+
+/// Absolute URL validation of the string.
+///
+/// The value needs the scheme followed by `://`, like `https://example.com`,
+/// and the host for `http`, `https` and the other special schemes.
+/// Only the schemes without the authority, like `mailto:` and `urn:`, are allowed without `//`,
+/// so that `localhost:8080` is not taken as the `localhost` scheme.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateUrl};
+///
+/// struct MyType(String);
+///
+/// impl ValidateUrl for MyType {
+///     fn validate_url(&self) -> Result<(), serde_valid::UrlError> {
+///         self.0.validate_url()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(url)]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("example.com/index.html")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must be a valid URL."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateUrl {
+    fn validate_url(&self) -> Result<(), UrlError>;
+}
+
+impl<T> ValidateUrl for T
+where
+    T: LossyStr + ?Sized,
+{
+    fn validate_url(&self) -> Result<(), UrlError> {
+        if is_url(&self.lossy_str()) {
+            Ok(())
+        } else {
+            Err(UrlError::new())
+        }
+    }
+}
+
+fn is_url(value: &str) -> bool {
+    if value.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return false;
+    }
+    let (scheme, rest) = match value.split_once(':') {
+        Some(pair) => pair,
+        None => return false,
+    };
+    let mut scheme_chars = scheme.chars();
+    let is_valid_scheme = scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme_chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !is_valid_scheme || rest.is_empty() {
+        return false;
+    }
+
+    let scheme = scheme.to_ascii_lowercase();
+    match rest.strip_prefix("//") {
+        Some(authority) if SPECIAL_SCHEMES.contains(&scheme.as_str()) => {
+            let host = authority
+                .split(['/', '?', '#'])
+                .next()
+                .unwrap_or_default()
+                .rsplit('@')
+                .next()
+                .unwrap_or_default();
+            scheme == "file" || !host.is_empty()
+        }
+        Some(_) => true,
+        None => OPAQUE_SCHEMES.contains(&scheme.as_str()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_string_url_is_true() {
+        assert!(ValidateUrl::validate_url("https://example.com").is_ok());
+        assert!(ValidateUrl::validate_url("http://user@example.com:8080/a?b=c#d").is_ok());
+        assert!(ValidateUrl::validate_url("file:///etc/hosts").is_ok());
+        assert!(ValidateUrl::validate_url("mailto:user@example.com").is_ok());
+        assert!(ValidateUrl::validate_url("urn:isbn:0451450523").is_ok());
+        assert!(ValidateUrl::validate_url("git+ssh://git@example.com/repo.git").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_url_is_false() {
+        assert!(ValidateUrl::validate_url("").is_err());
+        assert!(ValidateUrl::validate_url("example.com").is_err());
+        assert!(ValidateUrl::validate_url("https://").is_err());
+        assert!(ValidateUrl::validate_url("https:example.com").is_err());
+        assert!(ValidateUrl::validate_url("1http://example.com").is_err());
+        assert!(ValidateUrl::validate_url("https://exa mple.com").is_err());
+        assert!(ValidateUrl::validate_url("mailto:").is_err());
+        assert!(ValidateUrl::validate_url("localhost:8080").is_err());
+        assert!(ValidateUrl::validate_url("example.com:443/index.html").is_err());
+    }
+}
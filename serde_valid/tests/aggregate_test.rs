@@ -0,0 +1,116 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn sum_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(sum(minimum = 1, maximum = 10))]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val: vec![1, 2, 3, 4],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn sum_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(sum(minimum = 1.0, maximum = 1.5))]
+        weights: Vec<f64>,
+        #[validate(sum(minimum = 1))]
+        counts: Vec<u8>,
+    }
+
+    let s = TestStruct {
+        weights: vec![0.5, 0.75, 0.5],
+        counts: vec![],
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "weights": {
+                    "errors": ["The sum of the items must be `<= 1.5`."]
+                },
+                "counts": {
+                    "errors": ["The sum of the items must be `>= 1`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn sum_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(sum(maximum = 100, message = "Over the budget."))]
+        val: Vec<u32>,
+    }
+
+    let s = TestStruct { val: vec![60, 50] };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["Over the budget."]
+                }
+            }
+        })
+    );
+}
+
+fn is_admin(role: &&str) -> bool {
+    *role == "admin"
+}
+
+#[test]
+fn count_where_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(count_where(is_admin, min = 1, max = 2))]
+        roles: Vec<&'static str>,
+    }
+
+    let s = TestStruct {
+        roles: vec!["admin", "user"],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn count_where_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(count_where(is_admin, max = 1))]
+        roles: Vec<&'static str>,
+        #[validate(count_where(is_admin, min = 1))]
+        owners: Vec<&'static str>,
+    }
+
+    let s = TestStruct {
+        roles: vec!["admin", "user", "admin"],
+        owners: vec!["user"],
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "roles": {
+                    "errors": ["The count of the matching items must be `<= 1`."]
+                },
+                "owners": {
+                    "errors": ["The count of the matching items must be `>= 1`."]
+                }
+            }
+        })
+    );
+}
@@ -12,7 +12,7 @@ fn items_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_items = 5)]
-        #[validate(max_items = 2)]
+        #[validate(max_items = 10)]
         #[validate]
         val: Vec<TestChildStruct>,
     }
@@ -39,10 +39,7 @@ fn items_err_message() {
             "errors": [],
             "properties": {
                 "val": {
-                    "errors": [
-                        "The length of the items must be `>= 5`.",
-                        "The length of the items must be `<= 2`.",
-                    ],
+                    "errors": ["The length of the items must be `>= 5`."],
                     "items": {
                         "0": {
                             "errors": ["Rule error."],
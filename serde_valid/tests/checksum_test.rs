@@ -0,0 +1,146 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn checksum_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(luhn)]
+        card_number: String,
+        #[validate(ean)]
+        ean: String,
+        #[validate(iban)]
+        iban: String,
+        #[validate(isbn)]
+        isbn: String,
+    }
+
+    let s = TestStruct {
+        card_number: String::from("4111 1111 1111 1111"),
+        ean: String::from("4006381333931"),
+        iban: String::from("GB82 WEST 1234 5698 7654 32"),
+        isbn: String::from("978-3-16-148410-0"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn checksum_option_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(isbn)]
+        val: Option<String>,
+    }
+
+    assert!(TestStruct {
+        val: Some(String::from("0-306-40615-2"))
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct { val: None }.validate().is_ok());
+}
+
+#[test]
+fn checksum_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(luhn)]
+        card_number: String,
+        #[validate(ean)]
+        ean: String,
+        #[validate(iban)]
+        iban: String,
+        #[validate(isbn)]
+        isbn: String,
+    }
+
+    let s = TestStruct {
+        card_number: String::from("4111 1111 1111 1112"),
+        ean: String::from("4006381333932"),
+        iban: String::from("GB82 WEST 1234 5698 7654 31"),
+        isbn: String::from("978-3-16-148410-1"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "card_number": {
+                    "errors": ["The value must be a valid Luhn number."]
+                },
+                "ean": {
+                    "errors": ["The value must be a valid EAN."]
+                },
+                "iban": {
+                    "errors": ["The value must be a valid IBAN."]
+                },
+                "isbn": {
+                    "errors": ["The value must be a valid ISBN."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn checksum_vec_type_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(ean)]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![String::from("96385074"), String::from("96385075")],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The value must be a valid EAN."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn checksum_custom_err_message_fn() {
+    fn error_message(_params: &serde_valid::LuhnError) -> String {
+        "this is custom message.".to_string()
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(luhn, message_fn(error_message))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("1234"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["this is custom message."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
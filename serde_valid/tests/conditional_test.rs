@@ -0,0 +1,245 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[derive(Debug, PartialEq)]
+enum Kind {
+    Personal,
+    Business,
+}
+
+fn is_business(s: &TestStruct) -> bool {
+    s.kind == Kind::Business
+}
+
+#[derive(Validate)]
+struct TestStruct {
+    kind: Kind,
+    #[validate(when = "is_business", required)]
+    #[validate(min_length = 2)]
+    company: Option<String>,
+}
+
+#[test]
+fn required_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(required)]
+        val: Option<i32>,
+    }
+
+    assert!(TestStruct { val: Some(1) }.validate().is_ok());
+}
+
+#[test]
+fn required_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(required, message = "val is needed.")]
+        val: Option<i32>,
+    }
+
+    assert_eq!(
+        serde_json::to_value(TestStruct { val: None }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["val is needed."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn when_is_ok() {
+    let s = TestStruct {
+        kind: Kind::Personal,
+        company: None,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        kind: Kind::Personal,
+        company: Some("ab".to_owned()),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        kind: Kind::Business,
+        company: Some("serde_valid".to_owned()),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn when_is_err() {
+    let s = TestStruct {
+        kind: Kind::Business,
+        company: None,
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "company": {
+                    "errors": ["The value is required."]
+                }
+            }
+        })
+    );
+
+    let s = TestStruct {
+        kind: Kind::Business,
+        company: Some("a".to_owned()),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "company": {
+                    "errors": ["The length of the value must be `>= 2`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn when_is_scoped_to_attribute() {
+    let s = TestStruct {
+        kind: Kind::Personal,
+        company: Some("a".to_owned()),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "company": {
+                    "errors": ["The length of the value must be `>= 2`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn when_exclusive_bounds_are_not_contradictory() {
+    fn is_small(s: &TestStruct) -> bool {
+        !s.large
+    }
+
+    fn is_large(s: &TestStruct) -> bool {
+        s.large
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        large: bool,
+        #[validate(when = "is_small", maximum = 5)]
+        #[validate(when = "is_large", minimum = 10)]
+        val: i32,
+    }
+
+    assert!(TestStruct {
+        large: false,
+        val: 3
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        large: true,
+        val: 20
+    }
+    .validate()
+    .is_ok());
+    assert_eq!(
+        serde_json::to_value(
+            TestStruct {
+                large: true,
+                val: 3
+            }
+            .validate()
+            .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `>= 10`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn skip_if_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(skip_if = "str::is_empty", min_length = 2)]
+        #[validate(max_length = 4)]
+        val: &'static str,
+    }
+
+    assert!(TestStruct { val: "" }.validate().is_ok());
+    assert!(TestStruct { val: "ab" }.validate().is_ok());
+    assert!(TestStruct { val: "abcde" }.validate().is_err());
+}
+
+#[test]
+fn skip_if_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(skip_if = "str::is_empty", min_length = 2)]
+        val: &'static str,
+    }
+
+    assert_eq!(
+        serde_json::to_value(TestStruct { val: "a" }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The length of the value must be `>= 2`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn when_enum_variant_is_err() {
+    fn is_strict(s: &TestEnum) -> bool {
+        matches!(s, TestEnum::Value { strict: true, .. })
+    }
+
+    #[derive(Validate)]
+    enum TestEnum {
+        Value {
+            strict: bool,
+            #[validate(when = "is_strict", maximum = 10)]
+            val: i32,
+        },
+    }
+
+    assert!(TestEnum::Value {
+        strict: false,
+        val: 20
+    }
+    .validate()
+    .is_ok());
+    assert!(TestEnum::Value {
+        strict: true,
+        val: 20
+    }
+    .validate()
+    .is_err());
+}
@@ -0,0 +1,288 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct Address {
+    #[validate(min_length = 1)]
+    city: String,
+    #[validate(pattern = r"^\d{3}-\d{4}$")]
+    postal_code: String,
+}
+
+fn invalid_address() -> Address {
+    Address {
+        city: "".to_string(),
+        postal_code: "1234567".to_string(),
+    }
+}
+
+#[test]
+fn nested_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(nested, message = "The address is invalid.")]
+        address: Address,
+    }
+
+    let s = TestStruct {
+        address: invalid_address(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "address": {
+                    "errors": ["The address is invalid."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn nested_items_err_message_fn() {
+    fn addresses_message(errors: &serde_valid::validation::Errors) -> String {
+        match errors {
+            serde_valid::validation::Errors::Array(array) => {
+                format!("{} addresses are invalid.", array.items.len())
+            }
+            _ => "The addresses are invalid.".to_string(),
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(nested, message_fn(addresses_message))]
+        addresses: Vec<Address>,
+    }
+
+    let s = TestStruct {
+        addresses: vec![invalid_address(), invalid_address()],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "addresses": {
+                    "errors": ["2 addresses are invalid."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn nested_err_message_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(nested, message = "The address is invalid.")]
+        address: Address,
+    }
+
+    let s = TestStruct {
+        address: Address {
+            city: "Tokyo".to_string(),
+            postal_code: "100-0001".to_string(),
+        },
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn custom_err_message() {
+    fn is_even(val: &i32) -> Result<(), serde_valid::validation::Error> {
+        if val % 2 == 0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "The value is odd.".to_string(),
+            ))
+        }
+    }
+
+    fn wrapped_message(error: &serde_valid::validation::Error) -> String {
+        format!("Wrapped: {error}")
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(is_even), message = "The value must be even.")]
+        val1: i32,
+        #[validate(custom(is_even, message_fn(wrapped_message)))]
+        val2: i32,
+    }
+
+    let s = TestStruct { val1: 1, val2: 3 };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["The value must be even."]
+                },
+                "val2": {
+                    "errors": ["Wrapped: The value is odd."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn custom_err_message_template() {
+    struct RangeError {
+        limit: i32,
+    }
+
+    impl From<RangeError> for serde_valid::validation::Error {
+        fn from(error: RangeError) -> Self {
+            Self::Custom(format!("The value must be `<= {}`.", error.limit))
+        }
+    }
+
+    fn is_small(val: &i32) -> Result<(), RangeError> {
+        if *val <= 10 {
+            Ok(())
+        } else {
+            Err(RangeError { limit: 10 })
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(is_small), message = "The value is over {limit}.")]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 20 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value is over 10."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_err_message() {
+    fn sample_rule(_val1: &i32, _val2: &i32) -> Result<(), serde_valid::validation::Error> {
+        Err(serde_valid::validation::Error::Custom(
+            "Rule error.".to_string(),
+        ))
+    }
+
+    #[derive(Validate)]
+    #[rule(sample_rule(val1, val2), message = "The values are inconsistent.")]
+    struct TestStruct {
+        val1: i32,
+        val2: i32,
+    }
+
+    let s = TestStruct { val1: 1, val2: 2 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["The values are inconsistent."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_unnamed_struct_err_message() {
+    fn sample_rule(_val1: &i32, _val2: &i32) -> Result<(), serde_valid::validation::Error> {
+        Err(serde_valid::validation::Error::Custom(
+            "Rule error.".to_string(),
+        ))
+    }
+
+    #[derive(Validate)]
+    #[rule(sample_rule(0, 1), message = "The values are inconsistent.")]
+    struct TestStruct(i32, i32);
+
+    let s = TestStruct(1, 2);
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["The values are inconsistent."],
+            "items": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn field_group_rule_err_message() {
+    #[derive(Validate)]
+    #[rule(
+        builtin::at_least_one_of(email, phone),
+        message = "Either of [{fields}] is needed."
+    )]
+    struct TestStruct {
+        email: Option<String>,
+        phone: Option<String>,
+    }
+
+    let s = TestStruct {
+        email: None,
+        phone: None,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["Either of [email, phone] is needed."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[cfg(feature = "fluent")]
+#[test]
+fn nested_err_fluent_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(nested, fluent("address-invalid"))]
+        address: Address,
+    }
+
+    let s = TestStruct {
+        address: invalid_address(),
+    };
+
+    let errors = s.validate().unwrap_err();
+    let error = match &errors {
+        serde_valid::validation::Errors::Object(object) => match &object.properties["address"] {
+            serde_valid::validation::Errors::NewType(errors) => errors[0].clone(),
+            _ => panic!("the address errors must be the new type errors."),
+        },
+        _ => panic!("the errors must be the object errors."),
+    };
+
+    match error {
+        serde_valid::validation::Error::Fluent(message) => {
+            assert_eq!(message.id, "address-invalid")
+        }
+        error => panic!("the error must be the fluent message: {error}"),
+    }
+}
@@ -102,21 +102,26 @@ fn enum_named_variant_validation_is_err() {
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
-            "errors": ["Rule error."],
+            "errors": [],
             "properties": {
-                "a": {
-                    "errors": [],
+                "Named": {
+                    "errors": ["Rule error."],
                     "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 10`."]
-                        }
-                    }
-                },
-                "b": {
-                    "errors": [],
-                    "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 10`."]
+                        "a": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
+                        },
+                        "b": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
                         }
                     }
                 }
@@ -152,21 +157,26 @@ fn enum_unnamed_variant_validation_is_err() {
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
-            "errors": ["Rule error."],
-            "items": {
-                "0": {
-                    "errors": [],
-                    "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 10`."]
-                        }
-                    }
-                },
-                "1": {
-                    "errors": [],
-                    "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 10`."]
+            "errors": [],
+            "properties": {
+                "Named": {
+                    "errors": ["Rule error."],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
+                        },
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
                         }
                     }
                 }
@@ -195,8 +205,138 @@ fn enum_newtype_variant_validation_is_err() {
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
-            "errors": ["Rule error.", "The number must be `>= 5`."]
+            "errors": [],
+            "properties": {
+                "NewType": {
+                    "errors": ["Rule error.", "The number must be `>= 5`."]
+                }
+            }
         })
         .to_string()
     );
 }
+
+#[test]
+fn enum_rename_all_variant_validation_is_err() {
+    #[derive(serde::Deserialize, Validate)]
+    #[serde(rename_all = "snake_case")]
+    enum TestEnum {
+        NamedValue {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+        #[serde(rename = "new_type")]
+        NewType(#[validate(maximum = 10)] i32),
+    }
+
+    assert_eq!(
+        serde_json::to_value(TestEnum::NamedValue { val: 12 }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "named_value": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `<= 10`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(TestEnum::NewType(12).validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "new_type": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn enum_internally_tagged_validation_is_err() {
+    #[derive(serde::Deserialize, Validate)]
+    #[serde(tag = "type")]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+    }
+
+    let s = TestEnum::Named { val: 12 };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn enum_adjacently_tagged_validation_is_err() {
+    #[derive(serde::Deserialize, Validate)]
+    #[serde(tag = "type", content = "value")]
+    enum TestEnum {
+        Unnamed(#[validate(maximum = 10)] i32, #[validate(maximum = 10)] i32),
+    }
+
+    let s = TestEnum::Unnamed(12, 5);
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "value": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["The number must be `<= 10`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn enum_untagged_validation_is_err() {
+    #[derive(serde::Deserialize, Validate)]
+    enum TestEnum {
+        Tagged(#[validate(maximum = 10)] i32),
+        #[serde(untagged)]
+        Untagged(#[validate(maximum = 10)] i32),
+    }
+
+    #[derive(serde::Deserialize, Validate)]
+    #[serde(untagged)]
+    enum TestUntaggedEnum {
+        NewType(#[validate(maximum = 10)] i32),
+    }
+
+    assert_eq!(
+        serde_json::to_value(TestEnum::Untagged(12).validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": ["The number must be `<= 10`."]
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(TestUntaggedEnum::NewType(12).validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": ["The number must be `<= 10`."]
+        })
+    );
+}
@@ -0,0 +1,253 @@
+use serde::Serialize;
+use serde_json::json;
+use serde_valid::validation::{Error, Errors};
+use serde_valid::Validate;
+
+#[derive(Debug, Clone, Serialize)]
+struct AppError {
+    code: &'static str,
+    message: String,
+}
+
+impl From<Error> for AppError {
+    fn from(error: Error) -> Self {
+        Self {
+            code: "invalid",
+            message: error.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+#[cfg(feature = "flatten")]
+impl serde_valid::flatten::IntoFlat for AppError {
+    fn into_flat_at(
+        self,
+        path: &jsonschema::paths::JSONPointer,
+    ) -> serde_valid::flatten::FlatErrors {
+        serde_valid::flatten::FlatErrors::new(vec![serde_valid::flatten::FlatError::new(
+            path.clone(),
+            self.to_string(),
+        )])
+    }
+}
+
+fn is_not_reserved(name: &str) -> Result<(), AppError> {
+    if name == "admin" {
+        Err(AppError {
+            code: "reserved",
+            message: "The name is reserved.".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn is_consistent(min: &i32, max: &i32) -> Result<(), AppError> {
+    if min <= max {
+        Ok(())
+    } else {
+        Err(AppError {
+            code: "inconsistent",
+            message: "`min` must be `<= max`.".to_string(),
+        })
+    }
+}
+
+#[test]
+fn error_type_is_ok() {
+    #[derive(Validate)]
+    #[serde_valid(error = AppError)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 5 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn error_type_err_tree() {
+    #[derive(Validate)]
+    #[serde_valid(error = AppError)]
+    struct Inner {
+        #[validate(min_length = 1)]
+        tag: String,
+    }
+
+    #[derive(Validate)]
+    #[serde_valid(error = AppError)]
+    #[rule(is_consistent(min, max))]
+    struct TestStruct {
+        #[validate(custom(is_not_reserved))]
+        name: String,
+        min: i32,
+        max: i32,
+        #[validate(maximum = 10)]
+        scores: Vec<i32>,
+        #[validate]
+        inner: Inner,
+    }
+
+    let s = TestStruct {
+        name: "admin".to_string(),
+        min: 2,
+        max: 1,
+        scores: vec![1, 20],
+        inner: Inner {
+            tag: "".to_string(),
+        },
+    };
+
+    let errors: Errors<AppError> = s.validate().unwrap_err();
+    assert_eq!(
+        serde_json::to_value(errors).unwrap(),
+        json!({
+            "errors": [
+                {"code": "inconsistent", "message": "`min` must be `<= max`."}
+            ],
+            "properties": {
+                "name": {
+                    "errors": [
+                        {"code": "reserved", "message": "The name is reserved."}
+                    ]
+                },
+                "scores": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [
+                                {"code": "invalid", "message": "The number must be `<= 10`."}
+                            ]
+                        }
+                    }
+                },
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "tag": {
+                            "errors": [
+                                {"code": "invalid", "message": "The length of the value must be `>= 1`."}
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn error_type_nested_default_error() {
+    #[derive(Validate)]
+    struct Inner {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    #[derive(Validate)]
+    #[serde_valid(error = AppError)]
+    struct TestStruct {
+        #[validate]
+        inner: Vec<Inner>,
+    }
+
+    let s = TestStruct {
+        inner: vec![Inner { val: 11 }],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "inner": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": [
+                                        {"code": "invalid", "message": "The number must be `<= 10`."}
+                                    ]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn error_type_enum_err() {
+    #[derive(Validate)]
+    #[serde_valid(error = AppError)]
+    enum TestEnum {
+        Named {
+            #[validate(custom(is_not_reserved))]
+            name: String,
+        },
+    }
+
+    let s = TestEnum::Named {
+        name: "admin".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Named": {
+                    "errors": [],
+                    "properties": {
+                        "name": {
+                            "errors": [
+                                {"code": "reserved", "message": "The name is reserved."}
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn error_type_into_flat() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Validate)]
+    #[serde_valid(error = AppError)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        scores: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        scores: vec![1, 20],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().into_flat()).unwrap(),
+        json!({
+            "errors": [
+                {
+                    "error": "invalid: The number must be `<= 10`.",
+                    "instance_location": "/scores/1"
+                }
+            ]
+        })
+    );
+}
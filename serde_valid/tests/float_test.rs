@@ -0,0 +1,145 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn finite_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(finite)]
+        val: f64,
+    }
+
+    let s = TestStruct { val: 1234.5678 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn finite_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(finite)]
+        val: f32,
+    }
+
+    assert!(TestStruct { val: f32::INFINITY }.validate().is_err());
+    assert!(TestStruct { val: f32::NAN }.validate().is_err());
+}
+
+#[test]
+fn not_nan_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not_nan)]
+        val: f64,
+    }
+
+    let s = TestStruct { val: f64::INFINITY };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn not_nan_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not_nan)]
+        val: f64,
+    }
+
+    let s = TestStruct { val: f64::NAN };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn finite_option_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(finite)]
+        val: Option<f64>,
+    }
+
+    assert!(TestStruct { val: Some(1.0) }.validate().is_ok());
+    assert!(TestStruct { val: None }.validate().is_ok());
+}
+
+#[test]
+fn finite_vec_type_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(finite)]
+        val: Vec<f64>,
+    }
+
+    let s = TestStruct {
+        val: vec![1.0, f64::NEG_INFINITY, 2.0],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The number must be finite."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn float_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(finite)]
+        #[validate(not_nan)]
+        val: f64,
+    }
+
+    let s = TestStruct { val: f64::NAN };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The number must be finite.",
+                        "The number must not be NaN."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn float_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not_nan, message = "this is custom message.")]
+        val: f64,
+    }
+
+    let s = TestStruct { val: f64::NAN };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["this is custom message."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
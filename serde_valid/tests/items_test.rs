@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use serde_json::json;
 use serde_valid::Validate;
 
@@ -29,6 +31,58 @@ fn items_array_type() {
     assert!(s.validate().is_ok());
 }
 
+#[test]
+fn items_collection_types() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(min_items = 2, max_items = 3)]
+        deque: std::collections::VecDeque<i32>,
+        #[validate(min_items = 2, max_items = 3)]
+        hash_set: HashSet<i32>,
+        #[validate(min_items = 2, max_items = 3)]
+        btree_set: std::collections::BTreeSet<i32>,
+        #[validate(min_items = 2, max_items = 3)]
+        index_set: indexmap::IndexSet<i32>,
+        #[validate(min_items = 2, max_items = 3)]
+        slice: &'a [i32],
+        #[validate(min_items = 2, max_items = 3)]
+        boxed: Box<[i32]>,
+    }
+
+    let s = TestStruct {
+        deque: [1, 2].into(),
+        hash_set: [1, 2].into(),
+        btree_set: [1, 2].into(),
+        index_set: [1, 2].into(),
+        slice: &[1, 2],
+        boxed: vec![1, 2].into_boxed_slice(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        deque: [1].into(),
+        hash_set: [1].into(),
+        btree_set: [1, 2, 3, 4].into(),
+        index_set: [1, 2, 3, 4].into(),
+        slice: &[1],
+        boxed: vec![1, 2, 3, 4].into_boxed_slice(),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "deque": {"errors": ["The length of the items must be `>= 2`."]},
+                "hash_set": {"errors": ["The length of the items must be `>= 2`."]},
+                "btree_set": {"errors": ["The length of the items must be `<= 3`."]},
+                "index_set": {"errors": ["The length of the items must be `<= 3`."]},
+                "slice": {"errors": ["The length of the items must be `>= 2`."]},
+                "boxed": {"errors": ["The length of the items must be `<= 3`."]},
+            }
+        })
+    );
+}
+
 #[test]
 fn items_min_items_is_ok() {
     #[derive(Validate)]
@@ -180,22 +234,26 @@ fn items_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_items = 4, message_fn(min_error_message))]
+        val1: Vec<i32>,
         #[validate(max_items = 2, message_fn(max_error_message))]
-        val: Vec<i32>,
+        val2: Vec<i32>,
     }
 
-    let s = TestStruct { val: vec![1, 2, 3] };
+    let s = TestStruct {
+        val1: vec![1, 2, 3],
+        val2: vec![1, 2, 3],
+    };
 
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
         "errors": [],
         "properties": {
-            "val": {
-                "errors": [
-                    "this is min custom message.",
-                    "this is max custom message."
-                ]
+            "val1": {
+                "errors": ["this is min custom message."]
+            },
+            "val2": {
+                "errors": ["this is max custom message."]
             }
         }
         })
@@ -208,25 +266,63 @@ fn items_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_items = 4, message = "this is min custom message.")]
+        val1: Vec<i32>,
         #[validate(max_items = 2, message = "this is max custom message.")]
-        val: Vec<i32>,
+        val2: Vec<i32>,
     }
 
-    let s = TestStruct { val: vec![1, 2, 3] };
+    let s = TestStruct {
+        val1: vec![1, 2, 3],
+        val2: vec![1, 2, 3],
+    };
 
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
         .to_string()
     );
 }
+
+#[test]
+fn items_hash_set_errors_have_no_index() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val1: HashSet<i32>,
+        #[validate(capture_value)]
+        #[validate(maximum = 10, message = "{value} is over {maximum}.")]
+        val2: HashSet<i32>,
+    }
+
+    let s = TestStruct {
+        val1: HashSet::from([1, 15, 20]),
+        val2: HashSet::from([30, 1, 15]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["The number must be `<= 10`."],
+                    "items": {}
+                },
+                "val2": {
+                    "errors": ["15 is over 10.", "30 is over 10."],
+                    "items": {}
+                }
+            }
+        })
+    );
+}
@@ -282,12 +282,14 @@ fn length_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message_fn(custom_min_error_message))]
+        val1: String,
         #[validate(max_length = 3, message_fn(custom_max_error_message))]
-        val: String,
+        val2: String,
     }
 
     let s = TestStruct {
-        val: String::from("test"),
+        val1: String::from("test"),
+        val2: String::from("test"),
     };
 
     assert_eq!(
@@ -295,11 +297,11 @@ fn length_custom_err_message_fn() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
@@ -312,12 +314,14 @@ fn length_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message = "this is min custom message.")]
+        val1: String,
         #[validate(max_length = 3, message = "this is max custom message.")]
-        val: String,
+        val2: String,
     }
 
     let s = TestStruct {
-        val: String::from("test"),
+        val1: String::from("test"),
+        val2: String::from("test"),
     };
 
     assert_eq!(
@@ -325,11 +329,11 @@ fn length_custom_err_message() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
@@ -342,12 +346,14 @@ fn length_vec_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message = "this is min custom message.")]
+        val1: Vec<String>,
         #[validate(max_length = 3, message = "this is max custom message.")]
-        val: Vec<String>,
+        val2: Vec<String>,
     }
 
     let s = TestStruct {
-        val: vec![String::from("test")],
+        val1: vec![String::from("test")],
+        val2: vec![String::from("test")],
     };
 
     assert_eq!(
@@ -355,14 +361,19 @@ fn length_vec_err_message() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
+                "val1": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["this is min custom message."]
+                        }
+                    }
+                },
+                "val2": {
                     "errors": [],
                     "items": {
                         "0": {
-                            "errors": [
-                                "this is min custom message.",
-                                "this is max custom message."
-                            ]
+                            "errors": ["this is max custom message."]
                         }
                     }
                 }
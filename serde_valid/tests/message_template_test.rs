@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn message_template_limit() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 3, message = "must be at most {max_length} characters.")]
+        name: String,
+    }
+
+    let s = TestStruct {
+        name: "taro".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["must be at most 3 characters."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn message_template_measured() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            max_length = 3,
+            message = "must be at most {max_length} characters, got {length}."
+        )]
+        name: String,
+        #[validate(min_items = 2, message = "needs {min_items} items, got {items_count}.")]
+        vals: Vec<i32>,
+        #[validate(
+            max_properties = 1,
+            message = "allows {max_properties} property, got {size}."
+        )]
+        map: HashMap<String, i32>,
+    }
+
+    let s = TestStruct {
+        name: "taro".to_string(),
+        vals: vec![1],
+        map: HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {
+                    "errors": ["must be at most 3 characters, got 4."]
+                },
+                "vals": {
+                    "errors": ["needs 2 items, got 1."]
+                },
+                "map": {
+                    "errors": ["allows 1 property, got 2."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_value() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, message = "{value} is greater than {maximum}.")]
+        #[validate(capture_value)]
+        val: i32,
+        #[validate(capture_value)]
+        #[validate(
+            enumerate("red", "green"),
+            message = "{value} is not in [{enumerate}]."
+        )]
+        color: &'static str,
+    }
+
+    let s = TestStruct {
+        val: 150,
+        color: "blue",
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["150 is greater than 100."]
+                },
+                "color": {
+                    "errors": ["blue is not in [red, green]."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn message_template_escaped_braces() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, message = "{{val}} must be `<= {maximum}`.")]
+        val: i32,
+        #[validate(minimum = 0, message = "{{val2}} must be positive.")]
+        val2: i32,
+    }
+
+    let s = TestStruct { val: 150, val2: -1 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["{val} must be `<= 100`."]
+                },
+                "val2": {
+                    "errors": ["{val2} must be positive."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn message_template_items() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(capture_value)]
+        #[validate(maximum = 10, message = "{value} is over {maximum}.")]
+        #[validate(sum(maximum = 20, message = "The sum is over {max_sum}."))]
+        vals: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        vals: vec![1, 15, 8],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": ["The sum is over 20."],
+                    "items": {
+                        "1": {
+                            "errors": ["15 is over 10."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn message_template_sensitive_value() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(capture_value, sensitive)]
+        #[validate(min_length = 12, message = "{value} is too short.")]
+        password: String,
+    }
+
+    let s = TestStruct {
+        password: "secret".to_string(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "password": {
+                    "errors": ["[REDACTED] is too short."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,148 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn multiple_validations_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = 0, maximum = 10, multiple_of = 2)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 8 };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn multiple_validations_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = 0, maximum = 10, multiple_of = 2)]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 13 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The number must be `<= 10`.",
+                        "The value must be multiple of `2`."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn multiple_validations_with_path_and_list_validations() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_items = 1, unique_items, max_items = 3)]
+        val1: Vec<i32>,
+        #[validate(not_blank, pattern(r"[a-z]+", full_match), max_length = 5)]
+        val2: String,
+    }
+
+    let s = TestStruct {
+        val1: vec![1, 1, 2, 3],
+        val2: String::from("abcdef"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": [
+                        "The items must be unique.",
+                        "The length of the items must be `<= 3`."
+                    ]
+                },
+                "val2": {
+                    "errors": ["The length of the value must be `<= 5`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn multiple_validations_custom_message() {
+    fn min_error_message(_params: &serde_valid::MinimumError) -> String {
+        "this is min custom message_fn.".to_string()
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            minimum(0, message_fn(min_error_message)),
+            maximum(10, message = "this is max custom message.")
+        )]
+        val1: i32,
+        #[validate(
+            minimum(0, message_fn(min_error_message)),
+            maximum(10, message = "this is max custom message.")
+        )]
+        val2: i32,
+        #[validate(unique_items(message = "this is unique custom message."))]
+        val3: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val1: -1,
+        val2: 11,
+        val3: vec![1, 1],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["this is min custom message_fn."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
+                },
+                "val3": {
+                    "errors": ["this is unique custom message."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn single_validation_list_form_with_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum(10), message = "this is custom message.")]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 11 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["this is custom message."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -135,7 +135,7 @@ fn nested_validate_err_message() {
     };
 
     assert_eq!(
-        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
         json!({
             "errors": [],
             "properties": {
@@ -166,26 +166,339 @@ fn nested_validate_err_message() {
                 "named_fields_enum": {
                     "errors": [],
                     "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 0`."]
+                        "Value": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 0`."]
+                                }
+                            }
                         }
                     }
                 },
                 "unnamed_fields_enum": {
                     "errors": [],
+                    "properties": {
+                        "Value": {
+                            "errors": [],
+                            "items": {
+                                "0": {
+                                    "errors": ["The number must be `<= 0`."]
+                                },
+                                "1": {
+                                    "errors": ["The number must be `<= 0`."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "single_unnamed_fields_enum": {
+                    "errors": [],
+                    "properties": {
+                        "Value": {
+                            "errors": [
+                                "The number must be `<= 0`."
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn nested_validate_serde_flatten_err_message() {
+    #[derive(serde::Deserialize, Validate)]
+    struct TestStruct {
+        #[validate(maximum = 0)]
+        val: i32,
+        #[serde(flatten)]
+        #[validate]
+        inner: TestInnerStruct,
+    }
+
+    #[derive(serde::Deserialize, Validate)]
+    #[rule(inner_rule(inner_val))]
+    struct TestInnerStruct {
+        #[validate(maximum = 0)]
+        inner_val: i32,
+        #[validate(min_items = 2)]
+        #[validate]
+        inner_items: Vec<TestItem>,
+    }
+
+    #[derive(serde::Deserialize, Validate)]
+    struct TestItem {
+        #[validate(maximum = 0)]
+        item_val: i32,
+    }
+
+    fn inner_rule(inner_val: &i32) -> Result<(), serde_valid::validation::Error> {
+        if *inner_val > 0 {
+            Err(serde_valid::validation::Error::Custom(
+                "inner_val must not be positive.".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    let s = TestStruct {
+        val: 5,
+        inner: TestInnerStruct {
+            inner_val: 5,
+            inner_items: vec![TestItem { item_val: 5 }],
+        },
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": ["inner_val must not be positive."],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 0`."]
+                },
+                "inner_val": {
+                    "errors": ["The number must be `<= 0`."]
+                },
+                "inner_items": {
+                    "errors": ["The length of the items must be `>= 2`."],
                     "items": {
                         "0": {
-                            "errors": ["The number must be `<= 0`."]
-                        },
+                            "errors": [],
+                            "properties": {
+                                "item_val": {
+                                    "errors": ["The number must be `<= 0`."]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn nested_validate_smart_pointers() {
+    #[derive(Clone, Validate)]
+    struct TestNode {
+        #[validate(maximum = 10)]
+        val: i32,
+        #[validate]
+        next: Option<Box<TestNode>>,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        list: Box<TestNode>,
+        #[validate]
+        shared_rc: std::rc::Rc<TestNode>,
+        #[validate]
+        shared_arc: std::sync::Arc<TestNode>,
+        #[validate]
+        cow: std::borrow::Cow<'static, [TestNode]>,
+    }
+
+    fn node(val: i32) -> TestNode {
+        TestNode { val, next: None }
+    }
+
+    let s = TestStruct {
+        list: Box::new(TestNode {
+            val: 1,
+            next: Some(Box::new(node(11))),
+        }),
+        shared_rc: std::rc::Rc::new(node(1)),
+        shared_arc: std::sync::Arc::new(node(11)),
+        cow: std::borrow::Cow::Owned(vec![node(1), node(11)]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "list": {
+                    "errors": [],
+                    "properties": {
+                        "next": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
+                        }
+                    }
+                },
+                "shared_arc": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `<= 10`."]
+                        }
+                    }
+                },
+                "cow": {
+                    "errors": [],
+                    "items": {
                         "1": {
-                            "errors": ["The number must be `<= 0`."]
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
                         }
                     }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn nested_validate_tuples_and_collections() {
+    #[derive(Validate, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct TestInnerStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        tuple: (TestInnerStruct, TestInnerStruct),
+        #[validate]
+        deque: std::collections::VecDeque<TestInnerStruct>,
+        #[validate]
+        linked_list: std::collections::LinkedList<TestInnerStruct>,
+        #[validate]
+        btree_set: std::collections::BTreeSet<TestInnerStruct>,
+        #[validate]
+        hash_set: std::collections::HashSet<TestInnerStruct>,
+    }
+
+    let s = TestStruct {
+        tuple: (TestInnerStruct { val: 1 }, TestInnerStruct { val: 11 }),
+        deque: [TestInnerStruct { val: 11 }].into_iter().collect(),
+        linked_list: [TestInnerStruct { val: 1 }].into_iter().collect(),
+        btree_set: [TestInnerStruct { val: 12 }, TestInnerStruct { val: 1 }]
+            .into_iter()
+            .collect(),
+        hash_set: [
+            TestInnerStruct { val: 13 },
+            TestInnerStruct { val: 1 },
+            TestInnerStruct { val: 2 },
+        ]
+        .into_iter()
+        .collect(),
+    };
+
+    let item_error = json!({
+        "errors": [],
+        "properties": {
+            "val": {
+                "errors": ["The number must be `<= 10`."]
+            }
+        }
+    });
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "tuple": {
+                    "errors": [],
+                    "items": { "1": item_error }
                 },
-                "single_unnamed_fields_enum": {
-                    "errors": [
-                        "The number must be `<= 0`."
-                    ]
+                "deque": {
+                    "errors": [],
+                    "items": { "0": item_error }
+                },
+                "btree_set": {
+                    "errors": [],
+                    "items": { "1": item_error }
+                },
+                "hash_set": {
+                    "errors": [],
+                    "items": { "2": item_error }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn nested_validate_keyed_items_err_message() {
+    #[derive(Validate)]
+    struct User {
+        id: u32,
+        #[validate(min_length = 1)]
+        name: String,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(key = "id")]
+        users: Vec<User>,
+        #[validate(key = "id")]
+        admins: Option<Vec<User>>,
+    }
+
+    let user = |id: u32, name: &str| User {
+        id,
+        name: name.to_string(),
+    };
+
+    let s = TestStruct {
+        users: vec![user(1, "alice"), user(2, "bob")],
+        admins: None,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        users: vec![user(1, "alice"), user(42, ""), user(42, "")],
+        admins: Some(vec![user(7, "")]),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "users": {
+                    "errors": ["The items must be unique."],
+                    "items": {},
+                    "keyed_items": {
+                        "42": {
+                            "errors": [],
+                            "properties": {
+                                "name": {
+                                    "errors": [
+                                        "The length of the value must be `>= 1`.",
+                                        "The length of the value must be `>= 1`."
+                                    ]
+                                }
+                            }
+                        }
+                    }
+                },
+                "admins": {
+                    "errors": [],
+                    "items": {},
+                    "keyed_items": {
+                        "7": {
+                            "errors": [],
+                            "properties": {
+                                "name": {"errors": ["The length of the value must be `>= 1`."]}
+                            }
+                        }
+                    }
                 }
             }
         })
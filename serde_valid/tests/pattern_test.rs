@@ -1,4 +1,5 @@
 use serde_json::json;
+use serde_valid::export::{Lazy, Regex};
 use serde_valid::{Validate, ValidatePattern};
 use std::borrow::Cow;
 use std::ffi::{OsStr, OsString};
@@ -190,6 +191,194 @@ fn pattern_vec_optional_type_is_ok() {
     assert!(s.validate().is_ok());
 }
 
+#[test]
+fn pattern_static_regex_path_is_ok() {
+    static DATE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap());
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern = DATE)]
+        val1: String,
+        #[validate(pattern(DATE))]
+        val2: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val1: String::from("2020-09-10"),
+        val2: vec![String::from("2020-09-11")],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn pattern_registry_path_is_err() {
+    mod patterns {
+        serde_valid::patterns! {
+            pub ZIP_CODE = r"^\d{5}$";
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern = patterns::ZIP_CODE, message = "invalid zip code.")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("1234"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["invalid zip code."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn pattern_options_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern(r"[a-z]+", case_insensitive, full_match))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("Hello"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn pattern_full_match_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern(r"\d{4}", full_match))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("12345"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value must match the pattern of \"\\d{4}\"."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn pattern_options_compiled_pattern() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern(r"[a-z]+", case_insensitive, full_match))]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![String::from("Hello1")],
+    };
+
+    let error = match s.validate().unwrap_err() {
+        serde_valid::validation::Errors::Object(object) => match &object.properties["val"] {
+            serde_valid::validation::Errors::Array(array) => match &array.items[&0] {
+                serde_valid::validation::Errors::NewType(errors) => errors[0].clone(),
+                _ => panic!("the item errors must be the new type errors."),
+            },
+            _ => panic!("the val errors must be the array errors."),
+        },
+        _ => panic!("the errors must be the object errors."),
+    };
+
+    match error {
+        serde_valid::validation::Error::Pattern(message) => {
+            assert_eq!(message.params().pattern.to_string(), "[a-z]+");
+            assert_eq!(
+                message.params().compiled_pattern.to_string(),
+                "^(?:(?i)[a-z]+)$"
+            );
+        }
+        error => panic!("the error must be the pattern error: {error}"),
+    }
+}
+
+#[test]
+fn not_pattern_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern = r"^[a-z/.]+$")]
+        #[validate(not_pattern = r"\.\.")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("foo/bar"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn not_pattern_is_err() {
+    static PARENT_DIR: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.\.").unwrap());
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not_pattern(r"^/", case_insensitive))]
+        val1: String,
+        #[validate(not_pattern = PARENT_DIR, message = "parent directory is not allowed.")]
+        val2: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val1: String::from("/etc"),
+        val2: vec![String::from("../secret")],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["The value must not match the pattern of \"^/\"."]
+                },
+                "val2": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["parent directory is not allowed."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn pattern_error_mode() {
+    let error = "2020/09/10"
+        .validate_pattern(&Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap())
+        .unwrap_err();
+    assert_eq!(error.mode, serde_valid::PatternMode::Regex);
+}
+
 #[test]
 fn pattern_err_message() {
     #[derive(Validate)]
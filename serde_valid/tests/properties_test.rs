@@ -5,6 +5,23 @@ use serde_json::json;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 
+#[test]
+fn properties_index_map_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_properties = 2)]
+        #[validate(max_properties = 2)]
+        val: indexmap::IndexMap<String, String>,
+    }
+
+    let mut map = indexmap::IndexMap::new();
+    map.insert("key1".to_string(), "value1".to_string());
+    map.insert("key2".to_string(), "value2".to_string());
+
+    let s = TestStruct { val: map };
+    assert!(s.validate().is_ok());
+}
+
 #[test]
 fn properties_hash_map_type() {
     #[derive(Validate)]
@@ -183,15 +200,20 @@ fn properties_custom_err_message_fn() {
     #[derive(Deserialize, Validate)]
     struct TestStruct {
         #[validate(min_properties = 3, message_fn(min_custom_error_message))]
+        val1: serde_json::Map<String, serde_json::Value>,
         #[validate(max_properties = 1, message_fn(max_custom_error_message))]
-        val: serde_json::Map<String, serde_json::Value>,
+        val2: serde_json::Map<String, serde_json::Value>,
     }
 
     let s: TestStruct = serde_json::from_value(json!({
-        "val": {
+        "val1": {
             "key1": "value1",
             "key2": "value2",
-        }
+        },
+        "val2": {
+            "key1": "value1",
+            "key2": "value2",
+        },
     }))
     .unwrap();
 
@@ -200,11 +222,11 @@ fn properties_custom_err_message_fn() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
@@ -217,15 +239,20 @@ fn properties_custom_err_message() {
     #[derive(Deserialize, Validate)]
     struct TestStruct {
         #[validate(min_properties = 3, message = "this is min custom message.")]
+        val1: serde_json::Map<String, serde_json::Value>,
         #[validate(max_properties = 1, message = "this is max custom message.")]
-        val: serde_json::Map<String, serde_json::Value>,
+        val2: serde_json::Map<String, serde_json::Value>,
     }
 
     let s: TestStruct = serde_json::from_value(json!({
-        "val": {
+        "val1": {
             "key1": "value1",
             "key2": "value2",
-        }
+        },
+        "val2": {
+            "key1": "value1",
+            "key2": "value2",
+        },
     }))
     .unwrap();
 
@@ -234,11 +261,11 @@ fn properties_custom_err_message() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                    "this is min custom message.",
-                    "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
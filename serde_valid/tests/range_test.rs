@@ -157,6 +157,43 @@ fn range_vec_type_is_ok() {
     assert!(s.validate().is_ok());
 }
 
+#[test]
+fn range_collection_types_err_message() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(maximum = 10)]
+        deque: std::collections::VecDeque<i32>,
+        #[validate(maximum = 10)]
+        btree_set: std::collections::BTreeSet<i32>,
+        #[validate(maximum = 10)]
+        index_set: indexmap::IndexSet<i32>,
+        #[validate(maximum = 10)]
+        slice: &'a [i32],
+        #[validate(maximum = 10)]
+        boxed: Box<[i32]>,
+    }
+
+    let s = TestStruct {
+        deque: [1, 11].into(),
+        btree_set: [11, 1].into(),
+        index_set: [11, 1].into(),
+        slice: &[1, 11],
+        boxed: vec![1, 2].into_boxed_slice(),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "deque": {"errors": [], "items": {"1": {"errors": ["The number must be `<= 10`."]}}},
+                "btree_set": {"errors": [], "items": {"1": {"errors": ["The number must be `<= 10`."]}}},
+                "index_set": {"errors": [], "items": {"0": {"errors": ["The number must be `<= 10`."]}}},
+                "slice": {"errors": [], "items": {"1": {"errors": ["The number must be `<= 10`."]}}},
+            }
+        })
+    );
+}
+
 #[test]
 fn range_nested_vec_type_is_ok() {
     #[derive(Validate)]
@@ -295,22 +332,23 @@ fn range_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(minimum = 5, message_fn(custom_min_error_message))]
+        val1: i32,
         #[validate(maximum = 3, message_fn(custom_max_error_message))]
-        val: i32,
+        val2: i32,
     }
 
-    let s = TestStruct { val: 4 };
+    let s = TestStruct { val1: 4, val2: 4 };
 
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
@@ -323,23 +361,133 @@ fn range_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(minimum = 5, message = "this is min custom message.")]
+        val1: i32,
         #[validate(maximum = 3, message = "this is max custom message.")]
-        val: i32,
+        val2: i32,
     }
 
-    let s = TestStruct { val: 4 };
+    let s = TestStruct { val1: 4, val2: 4 };
     let result = s.validate().unwrap_err();
 
     assert_eq!(
         serde_json::to_string(&result).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn range_duration_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = "500ms")]
+        #[validate(maximum = "1h 30m")]
+        val: std::time::Duration,
+    }
+
+    let s = TestStruct {
+        val: std::time::Duration::from_secs(30),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn range_duration_option_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(exclusive_minimum = "0s")]
+        #[validate(exclusive_maximum = "30s")]
+        val: Option<std::time::Duration>,
+    }
+
+    let s = TestStruct {
+        val: Some(std::time::Duration::from_millis(29_999)),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn range_duration_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = "1m")]
+        #[validate(maximum = "1h30m")]
+        val: Vec<std::time::Duration>,
+    }
+
+    let s = TestStruct {
+        val: vec![
+            std::time::Duration::from_secs(30),
+            std::time::Duration::from_secs(6000),
+        ],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
         json!({
             "errors": [],
             "properties": {
                 "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["The number must be `>= 1m`."]
+                        },
+                        "1": {
+                            "errors": ["The number must be `<= 1h30m`."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn range_byte_size_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = "1KB")]
+        #[validate(maximum = "10MiB")]
+        val: u64,
+    }
+
+    let s = TestStruct {
+        val: 10 * 1024 * 1024,
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn range_byte_size_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(exclusive_maximum = "10MiB")]
+        val: usize,
+    }
+
+    let s = TestStruct {
+        val: 10 * 1024 * 1024,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `< 10MiB`."]
                 }
             }
         })
@@ -0,0 +1,312 @@
+use serde_json::json;
+use serde_valid::validation::{Error, Errors, Message, Number, RejectedValue, ToDefaultMessage};
+use serde_valid::{MinLengthError, Validate, ValidateMinLength};
+
+fn property_errors<'a>(errors: &'a Errors, property: &str) -> &'a [Error] {
+    match errors {
+        Errors::Object(object) => match &object.properties[property] {
+            Errors::NewType(errors) => errors,
+            _ => panic!("the property errors must be the new type errors."),
+        },
+        _ => panic!("the errors must be the object errors."),
+    }
+}
+
+#[test]
+fn rejected_value_number() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, capture_value)]
+        val: i32,
+    }
+
+    let errors = TestStruct { val: 150 }.validate().unwrap_err();
+    let error = &property_errors(&errors, "val")[0];
+
+    assert_eq!(
+        error.value(),
+        Some(&RejectedValue::Number(Number::I32(150)))
+    );
+    assert_eq!(error.to_string(), "The number must be `<= 100`.");
+}
+
+#[test]
+fn rejected_value_string() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 3, capture_value)]
+        name: String,
+    }
+
+    let errors = TestStruct {
+        name: "taro".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        property_errors(&errors, "name")[0].value(),
+        Some(&RejectedValue::from("taro"))
+    );
+}
+
+#[test]
+fn rejected_value_enumerate() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(enumerate("red", "green"), capture_value)]
+        color: String,
+        #[validate(enumerate(1, 2, 3), capture_value)]
+        level: u8,
+    }
+
+    let errors = TestStruct {
+        color: "blue".to_string(),
+        level: 4,
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        property_errors(&errors, "color")[0].value(),
+        Some(&RejectedValue::from("blue"))
+    );
+    assert_eq!(
+        property_errors(&errors, "level")[0].value(),
+        Some(&RejectedValue::Number(Number::U8(4)))
+    );
+}
+
+#[test]
+fn rejected_value_is_not_captured_by_default() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        val: i32,
+        #[validate(enumerate("red", "green"), sensitive)]
+        color: String,
+    }
+
+    let errors = TestStruct {
+        val: 150,
+        color: "blue".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(property_errors(&errors, "val")[0].value(), None);
+    assert_eq!(property_errors(&errors, "color")[0].value(), None);
+    assert_eq!(
+        property_errors(&errors, "color")[0].to_string(),
+        "The value is not allowed."
+    );
+}
+
+#[test]
+fn rejected_value_sensitive() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_length = 12, capture_value, sensitive)]
+        password: String,
+        #[validate(min_length = 3, capture_value)]
+        name: String,
+    }
+
+    let errors = TestStruct {
+        password: "secret".to_string(),
+        name: "jo".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    let password_error = &property_errors(&errors, "password")[0];
+    assert_eq!(password_error.value(), Some(&RejectedValue::Redacted));
+    assert_eq!(
+        password_error.to_string(),
+        "The length of the value must be `>= 12`."
+    );
+    assert_eq!(
+        property_errors(&errors, "name")[0].value(),
+        Some(&RejectedValue::from("jo"))
+    );
+}
+
+#[test]
+fn rejected_value_sensitive_enumerate_hides_candidates() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(enumerate("alpha", "bravo"), capture_value, sensitive)]
+        code: String,
+    }
+
+    let errors = TestStruct {
+        code: "charlie".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "code": {"errors": ["The value is not allowed."]}
+            }
+        })
+    );
+    assert_eq!(
+        property_errors(&errors, "code")[0].value(),
+        Some(&RejectedValue::Redacted)
+    );
+}
+
+#[test]
+fn rejected_value_sensitive_items() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 3, capture_value, sensitive)]
+        tokens: Vec<String>,
+    }
+
+    let errors = TestStruct {
+        tokens: vec!["abc".to_string(), "abcd".to_string()],
+    }
+    .validate()
+    .unwrap_err();
+
+    let value = match &errors {
+        Errors::Object(object) => match &object.properties["tokens"] {
+            Errors::Array(array) => match &array.items[&1] {
+                Errors::NewType(errors) => errors[0].value().cloned(),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    };
+    assert_eq!(value, Some(RejectedValue::Redacted));
+}
+
+#[test]
+fn rejected_value_captured_items() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10, capture_value)]
+        vals: Vec<i32>,
+    }
+
+    let errors = TestStruct { vals: vec![1, 20] }.validate().unwrap_err();
+
+    let value = match &errors {
+        Errors::Object(object) => match &object.properties["vals"] {
+            Errors::Array(array) => match &array.items[&1] {
+                Errors::NewType(errors) => errors[0].value().cloned(),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    };
+    assert_eq!(value, Some(RejectedValue::Number(Number::I32(20))));
+}
+
+fn strong_password(password: &str) -> Result<(), Error> {
+    password.validate_min_length(12).map_err(|error| {
+        Error::MinLength(Message::new(
+            error.with_value(password),
+            MinLengthError::to_default_message,
+        ))
+    })
+}
+
+#[test]
+fn rejected_value_custom_is_not_captured_by_default() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(strong_password))]
+        password: String,
+    }
+
+    let errors = TestStruct {
+        password: "hunter2".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(property_errors(&errors, "password")[0].value(), None);
+}
+
+#[test]
+fn rejected_value_sensitive_custom() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(sensitive, capture_value, custom(strong_password))]
+        password: String,
+        #[validate(sensitive, custom(strong_password))]
+        secret: String,
+    }
+
+    let errors = TestStruct {
+        password: "hunter2".to_string(),
+        secret: "hunter2".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        property_errors(&errors, "password")[0].value(),
+        Some(&RejectedValue::Redacted)
+    );
+    assert_eq!(property_errors(&errors, "secret")[0].value(), None);
+    #[cfg(feature = "flatten")]
+    {
+        use serde_valid::flatten::IntoFlat;
+
+        let flat_errors = serde_json::to_string(&errors.into_flat()).unwrap();
+        assert!(!flat_errors.contains("hunter2"));
+    }
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn rejected_value_into_flat() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, capture_value)]
+        val: i32,
+        #[validate(min_length = 12, capture_value, sensitive)]
+        password: String,
+        #[validate(min_length = 3)]
+        name: String,
+    }
+
+    let s = TestStruct {
+        val: 150,
+        password: "secret".to_string(),
+        name: "jo".to_string(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().into_flat()).unwrap(),
+        json!({
+            "errors": [
+                {
+                    "error": "The number must be `<= 100`.",
+                    "instance_location": "/val",
+                    "value": 150
+                },
+                {
+                    "error": "The length of the value must be `>= 12`.",
+                    "instance_location": "/password",
+                    "value": "[REDACTED]"
+                },
+                {
+                    "error": "The length of the value must be `>= 3`.",
+                    "instance_location": "/name"
+                }
+            ]
+        })
+    );
+}
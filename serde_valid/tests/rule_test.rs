@@ -112,3 +112,185 @@ fn rule_enum_is_ok() {
     let s4 = TestEnum::NoField;
     assert!(s4.validate().is_ok());
 }
+
+#[test]
+fn rule_at_least_one_of() {
+    #[derive(serde::Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    #[rule(builtin::at_least_one_of(email, phone_number))]
+    struct TestStruct {
+        email: Option<String>,
+        phone_number: Option<String>,
+    }
+
+    let s = TestStruct {
+        email: Some("user@example.com".to_owned()),
+        phone_number: None,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        email: None,
+        phone_number: None,
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["At least one of [email, phoneNumber] must be present."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_exactly_one_of() {
+    #[derive(Validate)]
+    #[rule(builtin::exactly_one_of(a, b, c))]
+    struct TestStruct {
+        a: Option<i32>,
+        b: Option<i32>,
+        c: Option<i32>,
+    }
+
+    assert!(TestStruct {
+        a: Some(1),
+        b: None,
+        c: None
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        a: None,
+        b: None,
+        c: None
+    }
+    .validate()
+    .is_err());
+    assert_eq!(
+        TestStruct {
+            a: Some(1),
+            b: Some(1),
+            c: None
+        }
+        .validate()
+        .unwrap_err()
+        .to_string(),
+        json!({
+            "errors": ["Exactly one of [a, b, c] must be present."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_mutually_exclusive() {
+    #[derive(Validate)]
+    #[rule(builtin::mutually_exclusive(a, b))]
+    struct TestStruct {
+        a: Option<i32>,
+        b: Option<i32>,
+    }
+
+    assert!(TestStruct { a: None, b: None }.validate().is_ok());
+    assert!(TestStruct {
+        a: Some(1),
+        b: None
+    }
+    .validate()
+    .is_ok());
+    assert_eq!(
+        TestStruct {
+            a: Some(1),
+            b: Some(1)
+        }
+        .validate()
+        .unwrap_err()
+        .to_string(),
+        json!({
+            "errors": ["At most one of [a, b] can be present."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_all_or_none() {
+    #[derive(serde::Deserialize, Validate)]
+    enum TestEnum {
+        #[rule(builtin::all_or_none(start, end))]
+        Range {
+            #[serde(rename = "from")]
+            start: Option<i32>,
+            #[serde(rename = "to")]
+            end: Option<i32>,
+        },
+    }
+
+    assert!(TestEnum::Range {
+        start: None,
+        end: None
+    }
+    .validate()
+    .is_ok());
+    assert!(TestEnum::Range {
+        start: Some(1),
+        end: Some(2)
+    }
+    .validate()
+    .is_ok());
+    assert_eq!(
+        TestEnum::Range {
+            start: Some(1),
+            end: None
+        }
+        .validate()
+        .unwrap_err()
+        .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Range": {
+                    "errors": ["All or none of [from, to] must be present."],
+                    "properties": {}
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_user_function_named_like_builtin() {
+    fn at_least_one_of(a: &i32, b: &i32) -> Result<(), serde_valid::validation::Error> {
+        if a + b > 0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "The sum must be positive.".to_owned(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    #[rule(at_least_one_of(a, b))]
+    struct TestStruct {
+        a: i32,
+        b: i32,
+    }
+
+    assert!(TestStruct { a: 1, b: 0 }.validate().is_ok());
+    assert_eq!(
+        TestStruct { a: 0, b: 0 }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The sum must be positive."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
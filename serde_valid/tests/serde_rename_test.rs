@@ -115,7 +115,145 @@ fn serde_rename_enume_is_err() {
         json!({
             "errors": [],
             "properties": {
-                "value": {
+                "Struct": {
+                    "errors": [],
+                    "properties": {
+                        "value": {
+                            "errors": ["The number must be `<= 100`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        first_value: i32,
+        #[validate(maximum = 100)]
+        #[serde(rename = "second")]
+        second_value: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "firstValue": 123, "second": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "firstValue": {
+                    "errors": ["The number must be `<= 100`."]
+                },
+                "second": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_deserialize_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all(serialize = "camelCase", deserialize = "SCREAMING-KEBAB-CASE"))]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        first_value: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "FIRST-VALUE": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "FIRST-VALUE": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_enum_variant_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all_fields = "kebab-case")]
+    enum TestEnum {
+        #[serde(rename_all = "PascalCase")]
+        Struct {
+            #[validate(maximum = 100)]
+            first_value: i32,
+        },
+        Other {
+            #[validate(maximum = 100)]
+            first_value: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "Struct": { "FirstValue": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Struct": {
+                    "errors": [],
+                    "properties": {
+                        "FirstValue": {
+                            "errors": ["The number must be `<= 100`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let err = TestEnum::from_json_value(json!({ "Other": { "first-value": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Other": {
+                    "errors": [],
+                    "properties": {
+                        "first-value": {
+                            "errors": ["The number must be `<= 100`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_alias_is_not_rename() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        #[serde(alias = "value")]
+        val: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "value": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
                     "errors": ["The number must be `<= 100`."]
                 }
             }
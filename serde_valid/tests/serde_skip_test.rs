@@ -0,0 +1,166 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::FromJsonValue;
+use serde_valid::Validate;
+
+#[test]
+fn serde_skip_is_not_validated() {
+    #[derive(Debug, Default, Validate, Deserialize)]
+    struct TestInnerStruct {
+        #[validate(minimum = 1)]
+        val: i32,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        val: i32,
+        #[serde(skip)]
+        #[validate]
+        skipped: TestInnerStruct,
+        #[serde(skip_deserializing)]
+        #[validate(minimum = 1)]
+        skipped_deserializing: i32,
+    }
+
+    let s = TestStruct {
+        val: 123,
+        skipped: TestInnerStruct::default(),
+        skipped_deserializing: 0,
+    };
+    assert_eq!(s.skipped.val, s.skipped_deserializing);
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_skip_internal_is_err() {
+    #[derive(Debug, Default, Validate, Deserialize)]
+    struct TestInnerStruct {
+        #[validate(minimum = 1)]
+        val: i32,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        val: i32,
+        #[serde(skip)]
+        #[validate(internal)]
+        #[validate]
+        skipped: TestInnerStruct,
+        #[serde(skip_deserializing)]
+        #[validate(internal, minimum = 1)]
+        skipped_deserializing: i32,
+    }
+
+    let s = TestStruct {
+        val: 123,
+        skipped: TestInnerStruct::default(),
+        skipped_deserializing: 0,
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            },
+            "internal": {
+                "skipped": {
+                    "errors": [],
+                    "properties": {
+                        "val": {
+                            "errors": ["The number must be `>= 1`."]
+                        }
+                    }
+                },
+                "skipped_deserializing": {
+                    "errors": ["The number must be `>= 1`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_skip_unnamed_is_not_validated() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct(
+        #[validate(maximum = 100)] i32,
+        #[serde(skip)]
+        #[validate(minimum = 1)]
+        i32,
+    );
+
+    let s = TestStruct(10, 0);
+    assert_eq!(s.1, 0);
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn serde_default_is_validated() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[serde(default)]
+        #[validate(minimum = 1)]
+        val: i32,
+    }
+
+    assert!(TestStruct::from_json_value(json!({})).is_err());
+}
+
+#[test]
+fn serde_default_skip_default() {
+    fn default_val() -> i32 {
+        -1
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[serde(default)]
+        #[validate(skip_default, minimum = 1)]
+        val: i32,
+        #[serde(default = "default_val")]
+        #[validate(skip_default)]
+        #[validate(minimum = 1)]
+        val_with_path: i32,
+    }
+
+    assert!(TestStruct::from_json_value(json!({})).is_ok());
+    assert!(TestStruct::from_json_value(json!({ "val": 5, "val_with_path": 5 })).is_ok());
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(
+            &TestStruct::from_json_value(json!({ "val": -5, "val_with_path": 0 }))
+                .unwrap_err()
+                .to_string()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `>= 1`."]
+                },
+                "val_with_path": {
+                    "errors": ["The number must be `>= 1`."]
+                }
+            }
+        })
+    );
+}
@@ -0,0 +1,400 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateKeyedItems};
+
+#[test]
+fn severity_warning_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, severity = "warning")]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 150 };
+    assert!(s.validate().is_ok());
+
+    let report = s.validate_with_warnings();
+    assert!(report.is_valid());
+    assert!(report.errors.is_none());
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_warning_without_warnings() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, severity = "warning")]
+        val: i32,
+    }
+
+    let report = TestStruct { val: 50 }.validate_with_warnings();
+    assert!(report.errors.is_none());
+    assert!(report.warnings.is_none());
+}
+
+#[test]
+fn severity_error_is_default() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100, severity = "error")]
+        val1: i32,
+        #[validate(maximum = 100)]
+        val2: i32,
+    }
+
+    let report = TestStruct {
+        val1: 150,
+        val2: 150,
+    }
+    .validate_with_warnings();
+    assert!(!report.is_valid());
+    assert!(report.warnings.is_none());
+    assert_eq!(
+        serde_json::to_value(report.errors.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {"errors": ["The number must be `<= 100`."]},
+                "val2": {"errors": ["The number must be `<= 100`."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_separates_errors_and_warnings() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 1000)]
+        #[validate(maximum = 100, severity = "warning")]
+        val: i32,
+        #[validate(max_length = 3, severity = "warning")]
+        name: String,
+    }
+
+    let s = TestStruct {
+        val: 2000,
+        name: "taro".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["The number must be `<= 1000`."]}
+            }
+        })
+    );
+
+    let report = s.validate_with_warnings();
+    assert_eq!(
+        serde_json::to_value(report.errors.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["The number must be `<= 1000`."]}
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["The number must be `<= 100`."]},
+                "name": {"errors": ["The length of the value must be `<= 3`."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_warning_multiple_validations() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = 10, maximum = 100, severity = "warning")]
+        val: i32,
+    }
+
+    let report = TestStruct { val: 5 }.validate_with_warnings();
+    assert!(report.errors.is_none());
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["The number must be `>= 10`."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_bounds_are_independent() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        #[validate(minimum = 200, severity = "warning")]
+        val: i32,
+    }
+
+    let report = TestStruct { val: 50 }.validate_with_warnings();
+    assert!(report.is_valid());
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["The number must be `>= 200`."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_warning_items() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_items = 2, severity = "warning")]
+        #[validate(maximum = 10, severity = "warning")]
+        vals: Vec<i32>,
+    }
+
+    let report = TestStruct {
+        vals: vec![1, 20, 3],
+    }
+    .validate_with_warnings();
+    assert!(report.errors.is_none());
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": ["The length of the items must be `<= 2`."],
+                    "items": {
+                        "1": {"errors": ["The number must be `<= 10`."]}
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_warning_nested() {
+    #[derive(Validate)]
+    struct Inner {
+        #[validate(maximum = 100, severity = "warning")]
+        val: i32,
+        #[validate(min_length = 1)]
+        tag: String,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        inner: Inner,
+        #[validate]
+        items: Vec<Inner>,
+    }
+
+    let s = TestStruct {
+        inner: Inner {
+            val: 150,
+            tag: "".to_string(),
+        },
+        items: vec![
+            Inner {
+                val: 1,
+                tag: "a".to_string(),
+            },
+            Inner {
+                val: 200,
+                tag: "b".to_string(),
+            },
+        ],
+    };
+
+    let report = s.validate_with_warnings();
+    assert_eq!(
+        serde_json::to_value(report.errors.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "tag": {"errors": ["The length of the value must be `>= 1`."]}
+                    }
+                }
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "val": {"errors": ["The number must be `<= 100`."]}
+                    }
+                },
+                "items": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "val": {"errors": ["The number must be `<= 100`."]}
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_warning_keyed_items() {
+    #[derive(Validate)]
+    struct Item {
+        id: String,
+        #[validate(maximum = 100, severity = "warning")]
+        val: i32,
+    }
+
+    let items = vec![
+        Item {
+            id: "a".to_string(),
+            val: 1,
+        },
+        Item {
+            id: "b".to_string(),
+            val: 200,
+        },
+    ];
+
+    assert!(items.validate_keyed_items(|item| item.id.clone()).is_ok());
+
+    let report = items.validate_keyed_items_with_warnings(|item| item.id.clone());
+    assert!(report.errors.is_none());
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "items": {},
+            "keyed_items": {
+                "b": {
+                    "errors": [],
+                    "properties": {
+                        "val": {"errors": ["The number must be `<= 100`."]}
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_warning_unnamed_struct() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(maximum = 100, severity = "warning")] i32,
+        #[validate(maximum = 100)] i32,
+    );
+
+    let report = TestStruct(150, 150).validate_with_warnings();
+    assert_eq!(
+        serde_json::to_value(report.errors.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {"errors": ["The number must be `<= 100`."]}
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({
+            "errors": [],
+            "items": {
+                "0": {"errors": ["The number must be `<= 100`."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn severity_warning_newtype_struct() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(maximum = 100, severity = "warning")] i32);
+
+    let report = TestStruct(150).validate_with_warnings();
+    assert!(report.errors.is_none());
+    assert_eq!(
+        serde_json::to_value(report.warnings.unwrap()).unwrap(),
+        json!({"errors": ["The number must be `<= 100`."]})
+    );
+}
+
+#[test]
+fn severity_warning_enum() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 100, severity = "warning")]
+            val: i32,
+        },
+        Unnamed(#[validate(maximum = 100, severity = "warning")] i32),
+    }
+
+    assert!(TestEnum::Named { val: 150 }.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(
+            TestEnum::Named { val: 150 }
+                .validate_with_warnings()
+                .warnings
+                .unwrap()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Named": {
+                    "errors": [],
+                    "properties": {
+                        "val": {"errors": ["The number must be `<= 100`."]}
+                    }
+                }
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(
+            TestEnum::Unnamed(150)
+                .validate_with_warnings()
+                .warnings
+                .unwrap()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Unnamed": {"errors": ["The number must be `<= 100`."]}
+            }
+        })
+    );
+}
@@ -0,0 +1,114 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn sorted_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(sorted)]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val: vec![1, 2, 2, 3],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn sorted_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(sorted)]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct { val: vec![1, 3, 2] };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The items must be sorted."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn sorted_by_field_is_err() {
+    struct Event {
+        at: u64,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(sorted_by = "at")]
+        events: Vec<Event>,
+    }
+
+    let s = TestStruct {
+        events: vec![Event { at: 10 }, Event { at: 20 }, Event { at: 15 }],
+    };
+    assert!(s.validate().is_err());
+
+    let s = TestStruct {
+        events: vec![Event { at: 10 }, Event { at: 10 }, Event { at: 15 }],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn strictly_increasing_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(strictly_increasing)]
+        val: Option<Vec<u32>>,
+    }
+
+    let s = TestStruct {
+        val: Some(vec![1, 2, 2]),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The items must be strictly increasing."]
+                }
+            }
+        })
+    );
+
+    let s = TestStruct { val: None };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn sorted_err_message_fn() {
+    fn error_message(params: &serde_valid::SortedError) -> String {
+        format!("The item at {} is out of order.", params.index)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(sorted, message_fn(error_message))]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct { val: vec![3, 2, 1] };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The item at 1 is out of order."]
+                }
+            }
+        })
+    );
+}
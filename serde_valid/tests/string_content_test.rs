@@ -0,0 +1,189 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn string_content_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains = "@")]
+        #[validate(not_contains = "..")]
+        #[validate(starts_with = "user")]
+        #[validate(ends_with = ".com")]
+        #[validate(not_blank)]
+        #[validate(ascii)]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("user@example.com"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn string_content_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains = "@")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("example.com"),
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn string_charset_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(charset = "a-z0-9_")]
+        val: &'static str,
+    }
+
+    let s = TestStruct {
+        val: "snake_case_1",
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn string_alphanumeric_option_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(alphanumeric)]
+        val: Option<String>,
+    }
+
+    assert!(TestStruct {
+        val: Some(String::from("abc123"))
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct { val: None }.validate().is_ok());
+}
+
+#[test]
+fn string_content_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains = "@")]
+        #[validate(not_contains = " ")]
+        #[validate(starts_with = "https://")]
+        #[validate(ends_with = "/")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("http://example.com /index.html"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must contain \"@\".",
+                        "The value must not contain \" \".",
+                        "The value must start with \"https://\".",
+                        "The value must end with \"/\"."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn string_path_validators_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not_blank)]
+        #[validate(ascii)]
+        #[validate(alphanumeric)]
+        #[validate(charset = "a-z")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("  ☃  "),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must contain only ASCII characters.",
+                        "The value must contain only alphanumeric characters.",
+                        "The value must consist of the characters in \"a-z\"."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn string_not_blank_vec_type_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not_blank)]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![String::from("a"), String::from(" \t")],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The value must not be blank."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn string_content_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(starts_with = "+", message = "the phone number must start with `+`.")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("0123"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["the phone number must start with `+`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
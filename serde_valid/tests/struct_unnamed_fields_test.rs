@@ -61,3 +61,24 @@ fn struct_unnamed_fields_is_err() {
         })
     );
 }
+
+#[test]
+fn struct_newtype_items_is_err() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(min_items = 3, maximum = 10)] Vec<i32>);
+
+    let s = TestStruct(vec![5, 15]);
+    let err = s.validate().unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": ["The length of the items must be `>= 3`."],
+            "items": {
+                "1": {
+                    "errors": ["The number must be `<= 10`."]
+                }
+            }
+        })
+    );
+}
@@ -0,0 +1,10 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(skip_if = "str::is_empty")]
+    #[validate(min_length = 2)]
+    val: &'static str,
+}
+
+fn main() {}
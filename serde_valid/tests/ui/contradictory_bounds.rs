@@ -0,0 +1,15 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(minimum = 10)]
+    #[validate(maximum = 5)]
+    val1: i32,
+    #[validate(exclusive_minimum = 5, exclusive_maximum = 5)]
+    val2: i32,
+    #[validate(min_items = 4)]
+    #[validate(max_items = 2)]
+    val3: Vec<i32>,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+#[rule(builtin::at_least_one_of(email))]
+struct SingleField {
+    email: Option<String>,
+}
+
+#[derive(Validate)]
+#[rule(builtin::at_least_one(email, phone))]
+struct UnknownBuiltin {
+    email: Option<String>,
+    phone: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(max_length = 3, message = "must be at most {max_length} characters, got {count}")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(max_length = 3, message = "{value} is too long.")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    val1: f64,
+    #[validate(minimum = 1.5)]
+    val2: i32,
+    #[validate(maximum = 300)]
+    val3: u8,
+    #[validate(multiple_of = 0)]
+    val4: u32,
+}
+
+fn main() {}
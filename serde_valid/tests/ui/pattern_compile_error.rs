@@ -0,0 +1,9 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(pattern = r"^(\d+$")]
+    val: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(maximum = "30s")]
+    val1: u64,
+    #[validate(maximum = "10MiB")]
+    val2: i64,
+    #[validate(maximum = "10 parsecs")]
+    val3: u64,
+    #[validate(maximum = true)]
+    val4: u64,
+}
+
+fn main() {}
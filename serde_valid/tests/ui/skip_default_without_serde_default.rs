@@ -0,0 +1,9 @@
+use serde_valid::Validate;
+
+#[derive(serde::Deserialize, Validate)]
+struct TestStruct {
+    #[validate(skip_default, minimum = 1)]
+    val: i32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(max_length = -1)]
+    val1: String,
+    #[validate(min_items = 1.5)]
+    val2: Vec<i32>,
+}
+
+fn main() {}
@@ -27,6 +27,44 @@ fn unique_items_slice_type() {
     assert!(s.validate().is_ok());
 }
 
+#[test]
+fn unique_items_collection_types() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(unique_items)]
+        deque: std::collections::VecDeque<i32>,
+        #[validate(unique_items)]
+        hash_set: std::collections::HashSet<i32>,
+        #[validate(unique_items)]
+        btree_set: std::collections::BTreeSet<i32>,
+        #[validate(unique_items)]
+        index_set: indexmap::IndexSet<i32>,
+        #[validate(unique_items)]
+        slice: &'a [i32],
+        #[validate(unique_items)]
+        boxed: Box<[i32]>,
+    }
+
+    let s = TestStruct {
+        deque: [1, 2, 1].into(),
+        hash_set: [1, 2, 1].into(),
+        btree_set: [1, 2, 1].into(),
+        index_set: [1, 2, 1].into(),
+        slice: &[1, 2, 1],
+        boxed: vec![1, 2, 3].into_boxed_slice(),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "deque": {"errors": ["The items must be unique."]},
+                "slice": {"errors": ["The items must be unique."]},
+            }
+        })
+    );
+}
+
 #[test]
 fn unique_items_is_err() {
     #[derive(Validate)]
@@ -126,3 +164,138 @@ fn unique_items_custom_err_message() {
         .to_string()
     );
 }
+
+#[test]
+fn unique_items_duplicates_message_fn() {
+    fn error_message(params: &serde_valid::UniqueItemsError) -> String {
+        format!("duplicated at {:?}.", params.duplicates)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_items, message_fn(error_message))]
+        val: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val: vec![1, 2, 1, 3, 2],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["duplicated at [2, 4]."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn unique_items_partial_eq_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_items(partial_eq))]
+        val: Vec<f64>,
+        #[validate(unique_items(partial_eq, message = "floats must be unique."))]
+        opt: Option<Vec<f64>>,
+    }
+
+    let s = TestStruct {
+        val: vec![0.5, 1.5],
+        opt: None,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        val: vec![0.5, 1.5, 0.5],
+        opt: Some(vec![0.5, 0.5]),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["The items must be unique."]},
+                "opt": {"errors": ["floats must be unique."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn unique_by_field_name() {
+    struct User {
+        id: u32,
+        profile: Profile,
+    }
+
+    struct Profile {
+        email: String,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_by = "id")]
+        #[validate(unique_by("profile.email"))]
+        users: Vec<User>,
+    }
+
+    let user = |id: u32, email: &str| User {
+        id,
+        profile: Profile {
+            email: email.to_string(),
+        },
+    };
+
+    let s = TestStruct {
+        users: vec![user(1, "a@example.com"), user(2, "b@example.com")],
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        users: vec![
+            user(1, "a@example.com"),
+            user(2, "a@example.com"),
+            user(1, "c@example.com"),
+        ],
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "users": {
+                    "errors": ["The items must be unique.", "The items must be unique."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn unique_by_key_fn() {
+    fn duplicates_message(params: &serde_valid::UniqueItemsError) -> String {
+        format!("duplicated at {:?}.", params.duplicates)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_by(str::to_lowercase), message_fn(duplicates_message))]
+        tags: Vec<String>,
+    }
+
+    let s = TestStruct {
+        tags: vec!["Rust".to_string(), "serde".to_string(), "rust".to_string()],
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "tags": {"errors": ["duplicated at [2]."]}
+            }
+        })
+    );
+}
@@ -0,0 +1,235 @@
+use serde_json::json;
+use serde_valid::export::{Lazy, Regex};
+use serde_valid::Validate;
+use std::collections::HashMap;
+
+static LOWER_CASE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z]+$").unwrap());
+
+#[test]
+fn validator_compat_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(length(min = 1, max = 5))]
+        val1: String,
+        #[validate(length(min = 1, max = 5))]
+        val2: Vec<i32>,
+        #[validate(length(equal = 2))]
+        val3: HashMap<String, i32>,
+        #[validate(range(min = 0, max = 10))]
+        val4: i32,
+        #[validate(range(exclusive_min = 0.0, exclusive_max = 1.0))]
+        val5: f64,
+        #[validate(email, url(message = "invalid url."))]
+        val6: Option<String>,
+        #[validate(contains(pattern = "@"), does_not_contain(pattern = ".."))]
+        val7: String,
+        #[validate(regex(path = "LOWER_CASE"), credit_card)]
+        val8: String,
+    }
+
+    let s = TestStruct {
+        val1: String::from("abc"),
+        val2: vec![1, 2, 3],
+        val3: HashMap::from([(String::from("a"), 1), (String::from("b"), 2)]),
+        val4: 5,
+        val5: 0.5,
+        val6: None,
+        val7: String::from("user@example.com"),
+        val8: String::from("abc"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val8": {
+                    "errors": ["The value must be a valid Luhn number."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn validator_compat_length_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(length(min = 4, message = "this is length custom message."))]
+        val1: String,
+        #[validate(length(max = 2))]
+        val2: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        val1: String::from("abc"),
+        val2: vec![1, 2, 3],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["this is length custom message."]
+                },
+                "val2": {
+                    "errors": ["The length of the items must be `<= 2`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn validator_compat_range_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(range(min = 1, max = 10))]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 0 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `>= 1`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn validator_compat_range_float_field() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(range(min = 1, max = 10))]
+        val: f64,
+    }
+
+    assert!(TestStruct { val: 5.5 }.validate().is_ok());
+    assert_eq!(
+        TestStruct { val: 10.5 }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The number must be `<= 10.0`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn validator_compat_custom_and_nested() {
+    fn custom_validation(val: &i32) -> Result<(), serde_valid::validation::Error> {
+        if *val % 2 == 0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "must be even.".to_string(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    struct Inner {
+        #[validate(email)]
+        email: String,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(nested)]
+        inner: Inner,
+        #[validate(custom(function = "custom_validation"))]
+        val: i32,
+    }
+
+    let s = TestStruct {
+        inner: Inner {
+            email: String::from("user.example.com"),
+        },
+        val: 1,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "email": {
+                            "errors": ["The value must be a valid email address."]
+                        }
+                    }
+                },
+                "val": {
+                    "errors": ["must be even."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn validator_compat_custom_validation_error() {
+    /// Stands in for `validator::ValidationError`, which is not a serde_valid error.
+    struct ValidationError {
+        code: &'static str,
+    }
+
+    impl std::fmt::Display for ValidationError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Validation error: {}", self.code)
+        }
+    }
+
+    fn validate_even(val: &i32) -> Result<(), ValidationError> {
+        if *val % 2 == 0 {
+            Ok(())
+        } else {
+            Err(ValidationError { code: "even" })
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom = "validate_even")]
+        val1: i32,
+        #[validate(custom(function = "validate_even", message = "must be even."))]
+        val2: i32,
+    }
+
+    let s = TestStruct { val1: 1, val2: 3 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["Validation error: even"]
+                },
+                "val2": {
+                    "errors": ["must be even."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
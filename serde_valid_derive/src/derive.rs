@@ -1,21 +1,27 @@
 mod enum_derive;
+mod error_type;
 mod named_struct_derive;
 mod unnamed_struct_derive;
 
 use enum_derive::expand_enum_validate_derive;
+use error_type::find_error_type;
 use named_struct_derive::expand_named_struct_derive;
 use proc_macro2::TokenStream;
 use unnamed_struct_derive::expand_unnamed_struct_derive;
 
 pub fn expand_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    let error_type = find_error_type(&input.attrs)?;
+
     match &input.data {
         syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
-            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields),
-            syn::Fields::Unnamed(fields) => expand_unnamed_struct_derive(input, fields),
+            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields, &error_type),
+            syn::Fields::Unnamed(fields) => {
+                expand_unnamed_struct_derive(input, fields, &error_type)
+            }
             syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_support(input)]),
         },
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-            expand_enum_validate_derive(input, variants)
+            expand_enum_validate_derive(input, variants, &error_type)
         }
         syn::Data::Union(_) => Err(vec![crate::Error::union_not_support(input)]),
     }
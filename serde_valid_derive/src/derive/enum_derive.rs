@@ -1,8 +1,12 @@
+use super::error_type::ErrorType;
 use super::named_struct_derive::collect_named_fields_validators_list;
 use super::unnamed_struct_derive::collect_unnamed_fields_validators_list;
-use crate::error::{array_errors_tokens, new_type_errors_tokens, object_errors_tokens};
+use crate::error::{
+    array_errors_tokens, new_type_errors_tokens, object_errors_tokens, report_tokens,
+};
 use crate::rule::{collect_rules_from_named_struct, collect_rules_from_unnamed_struct};
-use crate::serde::rename::collect_serde_rename_map;
+use crate::serde::rename::{collect_serde_rename_map, find_serde_rename_rule};
+use crate::serde::representation::EnumRepresentation;
 use crate::types::CommaSeparatedTokenStreams;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -14,9 +18,11 @@ pub type Variants = syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>
 pub fn expand_enum_validate_derive(
     input: &syn::DeriveInput,
     variants: &Variants,
+    error_type: &ErrorType,
 ) -> Result<TokenStream, crate::Errors> {
     let ident = &input.ident;
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let representation = EnumRepresentation::new(input);
 
     let mut errors = vec![];
 
@@ -24,7 +30,14 @@ pub fn expand_enum_validate_derive(
         TokenStream::from_iter(variants.iter().enumerate().map(|(index, variant)| {
             match &variant.fields {
                 syn::Fields::Named(named_fields) => {
-                    match expand_enum_variant_named_fields(index, ident, variant, named_fields) {
+                    match expand_enum_variant_named_fields(
+                        index,
+                        input,
+                        &representation,
+                        variant,
+                        named_fields,
+                        error_type,
+                    ) {
                         Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
                         Err(variant_errors) => {
                             errors.extend(variant_errors);
@@ -35,9 +48,11 @@ pub fn expand_enum_validate_derive(
                 syn::Fields::Unnamed(unnamed_fields) => {
                     match expand_enum_variant_unnamed_fields_varidation(
                         index,
-                        ident,
+                        input,
+                        &representation,
                         variant,
                         unnamed_fields,
+                        error_type,
                     ) {
                         Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
                         Err(variant_errors) => {
@@ -52,11 +67,15 @@ pub fn expand_enum_validate_derive(
 
     if errors.is_empty() {
         Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+            impl #impl_generics ::serde_valid::Validate<#error_type> for #ident #type_generics #where_clause {
+                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors<#error_type>> {
+                    ::serde_valid::Validate::<#error_type>::validate_with_warnings(self).into_result()
+                }
+
+                fn validate_with_warnings(&self) -> ::serde_valid::validation::Report<#error_type> {
                     #validations_and_rules
 
-                    Ok(())
+                    ::serde_valid::validation::Report::default()
                 }
             }
         ))
@@ -67,63 +86,82 @@ pub fn expand_enum_validate_derive(
 
 fn expand_enum_variant_named_fields(
     index: usize,
-    ident: &syn::Ident,
+    input: &syn::DeriveInput,
+    representation: &EnumRepresentation,
     variant: &syn::Variant,
     named_fields: &syn::FieldsNamed,
+    error_type: &ErrorType,
 ) -> Result<TokenStream, crate::Errors> {
     let mut errors = vec![];
 
+    let ident = &input.ident;
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
     let else_token = make_else_token(index);
-    let rename_map = collect_serde_rename_map(named_fields);
+    let rename_map = collect_serde_rename_map(
+        named_fields,
+        find_serde_rename_rule(&[
+            (&variant.attrs, "rename_all"),
+            (&input.attrs, "rename_all_fields"),
+        ]),
+    );
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&variant.attrs) {
+    let (rule_fields, rules) = match collect_rules_from_named_struct(&variant.attrs, &rename_map) {
         Ok(field_rules) => field_rules,
         Err(variant_errors) => {
-            errors.extend(variant_errors.into_iter());
+            errors.extend(variant_errors);
             (HashSet::new(), quote!())
         }
     };
 
-    let validates = match collect_named_fields_validators_list(named_fields, &rename_map) {
-        Ok(field_validators_list) => {
-            TokenStream::from_iter(field_validators_list.iter().map(|validators| {
-                let field_ident = validators.ident();
+    let validates =
+        match collect_named_fields_validators_list(named_fields, &rename_map, error_type) {
+            Ok(field_validators_list) => {
+                TokenStream::from_iter(field_validators_list.iter().map(|validators| {
+                    let field_ident = validators.ident();
 
-                if let Some(token) = validators.get_tokens() {
-                    fields_idents.push(quote!(#field_ident));
-                    quote!(#token)
-                } else {
-                    if rule_fields.contains(field_ident) {
+                    if let Some(token) = validators.get_tokens() {
                         fields_idents.push(quote!(#field_ident));
+                        quote!(#token)
                     } else {
-                        fields_idents.push(quote!(#field_ident: _));
+                        if rule_fields.contains(field_ident) {
+                            fields_idents.push(quote!(#field_ident));
+                        } else {
+                            fields_idents.push(quote!(#field_ident: _));
+                        }
+                        quote!()
                     }
-                    quote!()
-                }
-            }))
-        }
-        Err(fields_errors) => {
-            errors.extend(fields_errors.into_iter());
-            quote!()
-        }
-    };
+                }))
+            }
+            Err(fields_errors) => {
+                errors.extend(fields_errors);
+                quote!()
+            }
+        };
 
-    let variant_errors = object_errors_tokens();
+    let report = report_tokens(
+        &[
+            quote!(__rule_vec_errors),
+            quote!(__property_vec_errors_map),
+            quote!(__internal_vec_errors_map),
+        ],
+        representation.variant_errors_tokens(input, variant, object_errors_tokens()),
+    );
 
     if errors.is_empty() {
         Ok(quote!(
             #else_token if let #ident::#variant_ident{#fields_idents} = &self {
-                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::<#error_type>::new();
+                let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
+                let mut __internal_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
+                let mut __warning_rule_vec_errors = ::serde_valid::validation::VecErrors::<#error_type>::new();
+                let mut __warning_property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
+                let mut __warning_internal_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
 
                 #validates
                 #rules
 
-                if !(__rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty()) {
-                    Err(#variant_errors)?
-                }
+                return #report;
             }
         ))
     } else {
@@ -133,12 +171,16 @@ fn expand_enum_variant_named_fields(
 
 fn expand_enum_variant_unnamed_fields_varidation(
     index: usize,
-    ident: &syn::Ident,
+    input: &syn::DeriveInput,
+    representation: &EnumRepresentation,
     variant: &syn::Variant,
     unnamed_fields: &syn::FieldsUnnamed,
+    error_type: &ErrorType,
 ) -> Result<TokenStream, crate::Errors> {
     let mut errors = vec![];
 
+    let ident = &input.ident;
+
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
     let else_token = make_else_token(index);
@@ -146,12 +188,12 @@ fn expand_enum_variant_unnamed_fields_varidation(
     let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&variant.attrs) {
         Ok(field_rules) => field_rules,
         Err(variant_errors) => {
-            errors.extend(variant_errors.into_iter());
+            errors.extend(variant_errors);
             (HashSet::new(), quote!())
         }
     };
 
-    let validates = match collect_unnamed_fields_validators_list(unnamed_fields) {
+    let validates = match collect_unnamed_fields_validators_list(unnamed_fields, error_type) {
         Ok(field_validators_list) => {
             TokenStream::from_iter(field_validators_list.iter().map(|validators| {
                 let field_ident = validators.ident();
@@ -170,29 +212,36 @@ fn expand_enum_variant_unnamed_fields_varidation(
             }))
         }
         Err(fields_errors) => {
-            errors.extend(fields_errors.into_iter());
+            errors.extend(fields_errors);
             quote!()
         }
     };
 
-    let variant_errors = if unnamed_fields.unnamed.len() != 1 {
-        array_errors_tokens()
-    } else {
-        new_type_errors_tokens()
-    };
+    let report = report_tokens(
+        &[quote!(__rule_vec_errors), quote!(__item_vec_errors_map)],
+        representation.variant_errors_tokens(
+            input,
+            variant,
+            if unnamed_fields.unnamed.len() != 1 {
+                array_errors_tokens()
+            } else {
+                new_type_errors_tokens()
+            },
+        ),
+    );
 
     if errors.is_empty() {
         Ok(quote!(
             #else_token if let #ident::#variant_ident(#fields_idents) = &self {
-                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::<#error_type>::new();
+                let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
+                let mut __warning_rule_vec_errors = ::serde_valid::validation::VecErrors::<#error_type>::new();
+                let mut __warning_item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
 
                 #validates
                 #rules
 
-                if !(__rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty()) {
-                    Err(#variant_errors)?
-                }
+                return #report;
             }
         ))
     } else {
@@ -0,0 +1,86 @@
+use crate::validate::ContainerOption;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::str::FromStr;
+use syn::parse::{Parse, ParseStream};
+use syn::parse_quote;
+
+/// The error type of the errors tree, which is replaced with `#[serde_valid(error = MyError)]`.
+///
+/// The error type needs `From<serde_valid::validation::Error>`.
+pub struct ErrorType(Option<syn::Type>);
+
+impl ErrorType {
+    /// Whether the error type is replaced, whose errors are kept as they are.
+    pub fn is_custom(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+impl ToTokens for ErrorType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match &self.0 {
+            Some(error_type) => error_type.to_tokens(tokens),
+            None => tokens.extend(quote!(::serde_valid::validation::Error)),
+        }
+    }
+}
+
+pub fn find_error_type(attrs: &[syn::Attribute]) -> Result<ErrorType, crate::Errors> {
+    let mut errors = vec![];
+    let mut error_type: Option<syn::Type> = None;
+
+    for attribute in attrs
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(serde_valid))
+    {
+        let options = match attribute.parse_args_with(
+            syn::punctuated::Punctuated::<ContainerOptionItem, syn::Token![,]>::parse_terminated,
+        ) {
+            Ok(options) => options,
+            Err(error) => {
+                errors.push(crate::Error::container_option_parse_error(
+                    attribute, &error,
+                ));
+                continue;
+            }
+        };
+        for ContainerOptionItem { name, value } in options {
+            match ContainerOption::from_str(&name.to_string()) {
+                Ok(ContainerOption::Error) if error_type.is_some() => {
+                    errors.push(crate::Error::container_option_duplicated(&name))
+                }
+                Ok(ContainerOption::Error) => error_type = Some(value),
+                Err(unknown) => errors.push(crate::Error::validate_unknown_type(
+                    &syn::Path::from(name),
+                    &unknown,
+                    &ContainerOption::iter()
+                        .map(|x| x.name())
+                        .collect::<Vec<_>>(),
+                )),
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(ErrorType(error_type))
+}
+
+/// `name = Type`, whose value syn does not accept as a name value meta.
+struct ContainerOptionItem {
+    name: syn::Ident,
+    value: syn::Type,
+}
+
+impl Parse for ContainerOptionItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let value = input.parse()?;
+
+        Ok(Self { name, value })
+    }
+}
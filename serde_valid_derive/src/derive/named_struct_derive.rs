@@ -1,8 +1,13 @@
-use crate::error::object_errors_tokens;
+use super::error_type::ErrorType;
+use crate::error::{object_errors_tokens, report_tokens};
 use crate::rule::collect_rules_from_named_struct;
-use crate::serde::rename::{collect_serde_rename_map, RenameMap};
+use crate::serde::rename::{collect_serde_rename_map, find_serde_rename_rule, RenameMap};
+use crate::serde::skip::is_serde_skip_deserializing;
 use crate::types::{Field, NamedField};
-use crate::validate::{extract_meta_validator, FieldValidators};
+use crate::validate::{
+    check_field_bounds, extract_field_condition, extract_meta_validator, find_field_option,
+    FieldOption, FieldValidators,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
@@ -13,14 +18,18 @@ use syn::parse_quote;
 pub fn expand_named_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsNamed,
+    error_type: &ErrorType,
 ) -> Result<TokenStream, crate::Errors> {
     let ident = &input.ident;
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-    let rename_map = collect_serde_rename_map(fields);
+    let rename_map = collect_serde_rename_map(
+        fields,
+        find_serde_rename_rule(&[(&input.attrs, "rename_all")]),
+    );
 
     let mut errors = vec![];
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&input.attrs) {
+    let (rule_fields, rules) = match collect_rules_from_named_struct(&input.attrs, &rename_map) {
         Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
         Err(rule_errors) => {
             errors.extend(rule_errors);
@@ -28,7 +37,7 @@ pub fn expand_named_struct_derive(
         }
     };
 
-    let validates = match collect_named_fields_validators_list(fields, &rename_map) {
+    let validates = match collect_named_fields_validators_list(fields, &rename_map, error_type) {
         Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
             if validator.is_empty() && rule_fields.contains(validator.ident()) {
                 validator.get_field_variable_token()
@@ -37,28 +46,39 @@ pub fn expand_named_struct_derive(
             }
         })),
         Err(validation_errors) => {
-            errors.extend(validation_errors.into_iter());
+            errors.extend(validation_errors);
             quote!()
         }
     };
 
-    let fields_errors = object_errors_tokens();
+    let report = report_tokens(
+        &[
+            quote!(__rule_vec_errors),
+            quote!(__property_vec_errors_map),
+            quote!(__internal_vec_errors_map),
+        ],
+        object_errors_tokens(),
+    );
 
     if errors.is_empty() {
         Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+            impl #impl_generics ::serde_valid::Validate<#error_type> for #ident #type_generics #where_clause {
+                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors<#error_type>> {
+                    ::serde_valid::Validate::<#error_type>::validate_with_warnings(self).into_result()
+                }
+
+                fn validate_with_warnings(&self) -> ::serde_valid::validation::Report<#error_type> {
+                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::<#error_type>::new();
+                    let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
+                    let mut __internal_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
+                    let mut __warning_rule_vec_errors = ::serde_valid::validation::VecErrors::<#error_type>::new();
+                    let mut __warning_property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
+                    let mut __warning_internal_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
 
                     #validates
                     #rules
 
-                    if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+                    #report
                 }
             }
         ))
@@ -70,6 +90,7 @@ pub fn expand_named_struct_derive(
 pub fn collect_named_fields_validators_list<'a>(
     fields: &'a syn::FieldsNamed,
     rename_map: &RenameMap,
+    error_type: &ErrorType,
 ) -> Result<Vec<FieldValidators<'a, NamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -77,7 +98,7 @@ pub fn collect_named_fields_validators_list<'a>(
         .named
         .iter()
         .filter_map(
-            |field| match collect_named_field_validators(field, rename_map) {
+            |field| match collect_named_field_validators(field, rename_map, error_type) {
                 Ok(validators) => Some(validators),
                 Err(ref mut error) => {
                     errors.append(error);
@@ -97,11 +118,21 @@ pub fn collect_named_fields_validators_list<'a>(
 fn collect_named_field_validators<'a>(
     field: &'a syn::Field,
     rename_map: &RenameMap,
+    error_type: &ErrorType,
 ) -> Result<FieldValidators<'a, NamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
-    let named_field = NamedField::new(field);
-    let validators = named_field
+    let mut named_field = NamedField::new(field).with_custom_error_type(error_type.is_custom());
+    let skip_deserializing = is_serde_skip_deserializing(named_field.attrs());
+    let internal = find_field_option(&named_field, FieldOption::Internal);
+    if let Some(option) = &internal {
+        if !skip_deserializing {
+            errors.push(crate::Error::internal_need_serde_skip(option.path()));
+        }
+        named_field = named_field.into_internal();
+    }
+
+    let mut validators = named_field
         .attrs()
         .iter()
         .filter_map(|attribute| {
@@ -121,12 +152,30 @@ fn collect_named_field_validators<'a>(
         })
         .collect::<Vec<_>>();
 
+    if let Err(bounds_errors) = check_field_bounds(&named_field) {
+        errors.extend(bounds_errors);
+    }
+
+    let condition = match extract_field_condition(&named_field) {
+        Ok(condition) => condition,
+        Err(condition_errors) => {
+            errors.extend(condition_errors);
+            None
+        }
+    };
+
     if !errors.is_empty() {
         return Err(errors);
     }
 
-    Ok(FieldValidators::new(
-        Cow::Owned(named_field.clone()),
-        validators,
-    ))
+    // The fields not deserialized are not validated without `#[validate(internal)]`.
+    if skip_deserializing && internal.is_none() {
+        validators.clear();
+    }
+
+    let field_validators = FieldValidators::new(Cow::Owned(named_field.clone()), validators);
+    Ok(match condition {
+        Some(condition) => field_validators.with_condition(condition),
+        None => field_validators,
+    })
 }
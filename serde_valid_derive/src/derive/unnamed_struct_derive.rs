@@ -1,7 +1,12 @@
-use crate::error::{array_errors_tokens, new_type_errors_tokens};
+use super::error_type::ErrorType;
+use crate::error::{array_errors_tokens, new_type_errors_tokens, report_tokens};
 use crate::rule::collect_rules_from_unnamed_struct;
+use crate::serde::skip::is_serde_skip_deserializing;
 use crate::types::{Field, UnnamedField};
-use crate::validate::{extract_meta_validator, FieldValidators};
+use crate::validate::{
+    check_field_bounds, extract_field_condition, extract_meta_validator, find_field_option,
+    FieldOption, FieldValidators,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
@@ -12,6 +17,7 @@ use syn::parse_quote;
 pub fn expand_unnamed_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsUnnamed,
+    error_type: &ErrorType,
 ) -> Result<TokenStream, crate::Errors> {
     let ident = &input.ident;
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
@@ -26,7 +32,7 @@ pub fn expand_unnamed_struct_derive(
         }
     };
 
-    let validates = match collect_unnamed_fields_validators_list(fields) {
+    let validates = match collect_unnamed_fields_validators_list(fields, error_type) {
         Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
             if validator.is_empty() && rule_fields.contains(validator.ident()) {
                 validator.get_field_variable_token()
@@ -35,7 +41,7 @@ pub fn expand_unnamed_struct_derive(
             }
         })),
         Err(validation_errors) => {
-            errors.extend(validation_errors.into_iter());
+            errors.extend(validation_errors);
             quote!()
         }
     };
@@ -45,22 +51,28 @@ pub fn expand_unnamed_struct_derive(
     } else {
         new_type_errors_tokens()
     };
+    let report = report_tokens(
+        &[quote!(__rule_vec_errors), quote!(__item_vec_errors_map)],
+        fields_errors,
+    );
 
     if errors.is_empty() {
         Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+            impl #impl_generics ::serde_valid::Validate<#error_type> for #ident #type_generics #where_clause {
+                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors<#error_type>> {
+                    ::serde_valid::Validate::<#error_type>::validate_with_warnings(self).into_result()
+                }
+
+                fn validate_with_warnings(&self) -> ::serde_valid::validation::Report<#error_type> {
+                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::<#error_type>::new();
+                    let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
+                    let mut __warning_rule_vec_errors = ::serde_valid::validation::VecErrors::<#error_type>::new();
+                    let mut __warning_item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::<::serde_valid::validation::FieldError<#error_type>>::new();
 
                     #validates
                     #rules
 
-                    if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+                    #report
                 }
             }
         ))
@@ -69,22 +81,25 @@ pub fn expand_unnamed_struct_derive(
     }
 }
 
-pub fn collect_unnamed_fields_validators_list(
-    fields: &syn::FieldsUnnamed,
-) -> Result<Vec<FieldValidators<UnnamedField>>, crate::Errors> {
+pub fn collect_unnamed_fields_validators_list<'a>(
+    fields: &'a syn::FieldsUnnamed,
+    error_type: &ErrorType,
+) -> Result<Vec<FieldValidators<'a, UnnamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
     let validators = fields
         .unnamed
         .iter()
         .enumerate()
-        .filter_map(|field| match collect_unnamed_field_validators(field) {
-            Ok(validators) => Some(validators),
-            Err(ref mut error) => {
-                errors.append(error);
-                None
-            }
-        })
+        .filter_map(
+            |field| match collect_unnamed_field_validators(field, error_type) {
+                Ok(validators) => Some(validators),
+                Err(ref mut error) => {
+                    errors.append(error);
+                    None
+                }
+            },
+        )
         .collect();
 
     if !errors.is_empty() {
@@ -94,14 +109,20 @@ pub fn collect_unnamed_fields_validators_list(
     Ok(validators)
 }
 
-fn collect_unnamed_field_validators(
-    (index, field): (usize, &syn::Field),
-) -> Result<FieldValidators<UnnamedField>, crate::Errors> {
+fn collect_unnamed_field_validators<'a>(
+    (index, field): (usize, &'a syn::Field),
+    error_type: &ErrorType,
+) -> Result<FieldValidators<'a, UnnamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
-    let unnamed_field = UnnamedField::new(index, field);
+    let unnamed_field =
+        UnnamedField::new(index, field).with_custom_error_type(error_type.is_custom());
+    let skip_deserializing = is_serde_skip_deserializing(unnamed_field.attrs());
+    if let Some(option) = find_field_option(&unnamed_field, FieldOption::Internal) {
+        errors.push(crate::Error::internal_need_named_field(option.path()));
+    }
 
-    let validators = unnamed_field
+    let mut validators = unnamed_field
         .attrs()
         .iter()
         .filter_map(|attribute| {
@@ -121,9 +142,30 @@ fn collect_unnamed_field_validators(
         })
         .collect::<Vec<_>>();
 
+    if let Err(bounds_errors) = check_field_bounds(&unnamed_field) {
+        errors.extend(bounds_errors);
+    }
+
+    let condition = match extract_field_condition(&unnamed_field) {
+        Ok(condition) => condition,
+        Err(condition_errors) => {
+            errors.extend(condition_errors);
+            None
+        }
+    };
+
     if !errors.is_empty() {
         return Err(errors);
     }
 
-    Ok(FieldValidators::new(Cow::Owned(unnamed_field), validators))
+    // The fields not deserialized are not validated.
+    if skip_deserializing {
+        validators.clear();
+    }
+
+    let field_validators = FieldValidators::new(Cow::Owned(unnamed_field), validators);
+    Ok(match condition {
+        Some(condition) => field_validators.with_condition(condition),
+        None => field_validators,
+    })
 }
@@ -1,5 +1,5 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 
 use crate::types::CommaSeparatedNestedMetas;
@@ -7,139 +7,158 @@ use crate::types::CommaSeparatedNestedMetas;
 use crate::validate::MetaListMessage;
 
 pub fn object_errors_tokens() -> TokenStream {
+    let properties = property_errors_map_tokens(quote!(__property_vec_errors_map));
+    let internal = property_errors_map_tokens(quote!(__internal_vec_errors_map));
+
     quote!(::serde_valid::validation::Errors::Object(
-        ::serde_valid::validation::ObjectErrors::new(
-            __rule_vec_errors,
-            __property_vec_errors_map
-                .into_iter()
-                .map(|(field, errors)| {
-                    let mut __field_items_errors = vec![];
-                    let mut __field_properties_errors = None;
-                    let mut __field_errors: ::serde_valid::validation::VecErrors = errors
-                        .into_iter()
-                        .filter_map(|error| match error {
-                            ::serde_valid::validation::Error::Items(__array_errors) => {
-                                __field_items_errors.push(__array_errors);
-                                None
-                            }
-                            ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
-                                None
-                            }
-                            _ => Some(error),
-                        })
-                        .collect();
-
-                    if let Some(__object_errors) = __field_properties_errors {
-                        __field_errors.extend(__object_errors.errors);
-
-                        (
-                            field,
-                            ::serde_valid::validation::Errors::Object(
-                                ::serde_valid::validation::ObjectErrors::new(
-                                    __field_errors,
-                                    __object_errors.properties,
-                                ),
-                            ),
-                        )
-                    } else if !__field_items_errors.is_empty() {
-                        let __array_errors = __field_items_errors
-                            .into_iter()
-                            .reduce(|a, b| a.merge(b))
-                            .unwrap();
-                        __field_errors.extend(__array_errors.errors);
-
-                        (
-                            field,
-                            ::serde_valid::validation::Errors::Array(
-                                ::serde_valid::validation::ArrayErrors::new(
-                                    __field_errors,
-                                    __array_errors.items,
-                                ),
-                            ),
-                        )
-                    } else {
-                        (
-                            field,
-                            ::serde_valid::validation::Errors::NewType(__field_errors),
-                        )
-                    }
-                })
-                .collect()
-        )
+        ::serde_valid::validation::ObjectErrors::new(__rule_vec_errors, #properties)
+            .with_internal(#internal)
     ))
 }
 
+fn property_errors_map_tokens(property_vec_errors_map: TokenStream) -> TokenStream {
+    let field_errors = field_errors_tokens(quote!(errors));
+
+    quote!(
+        #property_vec_errors_map
+        .into_iter()
+        .map(|(field, errors)| (field, #field_errors))
+        .collect()
+    )
+}
+
 pub fn array_errors_tokens() -> TokenStream {
+    let field_errors = field_errors_tokens(quote!(errors));
+
     quote!(::serde_valid::validation::Errors::Array(
         ::serde_valid::validation::ArrayErrors::new(
             __rule_vec_errors,
             __item_vec_errors_map
                 .into_iter()
-                .map(|(index, errors)| {
-                    let mut __field_items_errors = vec![];
-                    let mut __field_properties_errors = None;
-                    let mut __field_errors: ::serde_valid::validation::VecErrors = errors
-                        .into_iter()
-                        .filter_map(|error| match error {
-                            ::serde_valid::validation::Error::Items(__array_errors) => {
-                                __field_items_errors.push(__array_errors);
-                                None
-                            }
-                            ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
-                                None
-                            }
-                            _ => Some(error),
-                        })
-                        .collect();
-
-                    if let Some(__object_errors) = __field_properties_errors {
-                        __field_errors.extend(__object_errors.errors);
-
-                        (
-                            index,
-                            ::serde_valid::validation::Errors::Object(
-                                ::serde_valid::validation::ObjectErrors::new(
-                                    __field_errors,
-                                    __object_errors.properties,
-                                ),
-                            ),
-                        )
-                    } else if !__field_items_errors.is_empty() {
-                        let __array_errors = __field_items_errors
-                            .into_iter()
-                            .reduce(|a, b| a.merge(b))
-                            .unwrap();
-                        __field_errors.extend(__array_errors.errors);
-
-                        (
-                            index,
-                            ::serde_valid::validation::Errors::Array(
-                                ::serde_valid::validation::ArrayErrors::new(
-                                    __field_errors,
-                                    __array_errors.items,
-                                ),
-                            ),
-                        )
-                    } else {
-                        (
-                            index,
-                            ::serde_valid::validation::Errors::NewType(__field_errors),
-                        )
-                    }
-                })
+                .map(|(index, errors)| (index, #field_errors))
                 .collect()
         )
     ))
 }
 
+/// The errors of the single field are the errors of the new type itself,
+/// because the new type is serialized as the field.
 pub fn new_type_errors_tokens() -> TokenStream {
-    quote!(::serde_valid::validation::Errors::NewType(
-        __rule_vec_errors
+    let field_errors =
+        field_errors_tokens(quote!(__item_vec_errors_map.remove(&0).unwrap_or_default()));
+
+    quote!(
+        match #field_errors {
+            ::serde_valid::validation::Errors::Array(mut __array_errors) => {
+                __rule_vec_errors.extend(__array_errors.errors);
+                __array_errors.errors = __rule_vec_errors;
+                ::serde_valid::validation::Errors::Array(__array_errors)
+            }
+            ::serde_valid::validation::Errors::Object(mut __object_errors) => {
+                __rule_vec_errors.extend(__object_errors.errors);
+                __object_errors.errors = __rule_vec_errors;
+                ::serde_valid::validation::Errors::Object(__object_errors)
+            }
+            ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                __rule_vec_errors.extend(__new_type_errors);
+                ::serde_valid::validation::Errors::NewType(__rule_vec_errors)
+            }
+        }
+    )
+}
+
+/// Build the errors tree of a field from the field errors,
+/// nesting the errors of the items and the properties.
+fn field_errors_tokens(field_vec_errors: TokenStream) -> TokenStream {
+    quote!({
+        let mut __field_items_errors = vec![];
+        let mut __field_properties_errors = None;
+        let mut __field_errors: ::serde_valid::validation::VecErrors<_> = #field_vec_errors
             .into_iter()
-            .chain(__item_vec_errors_map.remove(&0).unwrap().into_iter())
-            .collect()
+            .filter_map(|error| match error {
+                ::serde_valid::validation::FieldError::Items(__array_errors) => {
+                    __field_items_errors.push(__array_errors);
+                    None
+                }
+                ::serde_valid::validation::FieldError::Properties(__object_errors) => {
+                    __field_properties_errors = Some(__object_errors);
+                    None
+                }
+                ::serde_valid::validation::FieldError::Single(error) => Some(error),
+            })
+            .collect();
+
+        if let Some(__object_errors) = __field_properties_errors {
+            __field_errors.extend(__object_errors.errors);
+
+            ::serde_valid::validation::Errors::Object(
+                ::serde_valid::validation::ObjectErrors::new(
+                    __field_errors,
+                    __object_errors.properties,
+                )
+                .with_internal(*__object_errors.internal),
+            )
+        } else if !__field_items_errors.is_empty() {
+            let __array_errors = __field_items_errors
+                .into_iter()
+                .reduce(|a, b| a.merge(b))
+                .unwrap();
+            __field_errors.extend(__array_errors.errors);
+
+            ::serde_valid::validation::Errors::Array(
+                ::serde_valid::validation::ArrayErrors::new(
+                    __field_errors,
+                    __array_errors.items,
+                )
+                .with_keyed_items(*__array_errors.keyed_items),
+            )
+        } else {
+            ::serde_valid::validation::Errors::NewType(__field_errors)
+        }
+    })
+}
+
+/// The variable of the warnings, like `__warning_property_vec_errors_map`,
+/// which pairs with the errors variable.
+pub fn warnings_variable(errors_variable: &TokenStream) -> TokenStream {
+    let errors_variable = errors_variable.to_string();
+    let warnings_variable = format_ident!("__warning_{}", errors_variable.trim_start_matches('_'));
+    quote!(#warnings_variable)
+}
+
+/// Collect the errors of the validations as the warnings,
+/// shadowing the errors variables with the warnings variables.
+pub fn into_warnings_tokens(
+    errors_variables: &[TokenStream],
+    validations: TokenStream,
+) -> TokenStream {
+    let warnings_variables = errors_variables.iter().map(warnings_variable);
+
+    quote!({
+        #(let #errors_variables = &mut #warnings_variables;)*
+        #validations
+    })
+}
+
+/// Build the validation report from the errors variables and the warnings variables.
+pub fn report_tokens(errors_variables: &[TokenStream], errors: TokenStream) -> TokenStream {
+    let warnings_variables = errors_variables.iter().map(warnings_variable);
+
+    quote!(::serde_valid::validation::Report::new(
+        if #(#errors_variables.is_empty())&&* {
+            None
+        } else {
+            Some(#errors)
+        },
+        {
+            #(let mut #errors_variables = #warnings_variables;)*
+
+            if #(#errors_variables.is_empty())&&* {
+                None
+            } else {
+                Some(#errors)
+            }
+        },
     ))
 }
 
@@ -174,6 +193,13 @@ impl Error {
         Self::new(path.span(), "`rule` function needs arguments.")
     }
 
+    pub fn rule_field_group_need_fields(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "The field group rule needs at least 2 fields, like `builtin::at_least_one_of(a, b)`.",
+        )
+    }
+
     pub fn rule_allow_path_arguments(
         rule_fn_name_path: &syn::Path,
         meta: &syn::NestedMeta,
@@ -256,6 +282,17 @@ impl Error {
         )
     }
 
+    pub fn container_option_parse_error(attribute: &syn::Attribute, error: &syn::Error) -> Self {
+        Self::new(
+            attribute.span(),
+            format!("#[serde_valid(...)] on the container needs `error = MyError`: {error}"),
+        )
+    }
+
+    pub fn container_option_duplicated(ident: &syn::Ident) -> Self {
+        Self::new(ident.span(), format!("Duplicated `{ident}` option."))
+    }
+
     pub fn validate_enumerate_need_item(path: &syn::Path) -> Self {
         Self::new(path.span(), "`enumerate` need items.")
     }
@@ -268,6 +305,88 @@ impl Error {
         Self::new(nested.span(), "`custom` support only 1 item.")
     }
 
+    pub fn validate_unique_by_need_item(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "`unique_by` need a field name literal or a key function path.",
+        )
+    }
+
+    pub fn unique_items_allow_partial_eq(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "`unique_items` allow only `partial_eq`.",
+        )
+    }
+
+    pub fn validate_sum_need_item(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "`sum` need `minimum = ...` and/or `maximum = ...`.",
+        )
+    }
+
+    pub fn validate_count_where_need_item(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "`count_where` need a predicate function path and `min = ...` and/or `max = ...`.",
+        )
+    }
+
+    pub fn validate_pattern_need_item(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            format!("`{}` need a regex literal or path.", quote!(#path)),
+        )
+    }
+
+    pub fn pattern_option_need_literal(option: &syn::Path) -> Self {
+        Self::new(
+            option.span(),
+            format!(
+                "`{}` is allowed only with the regex literal.",
+                quote!(#option)
+            ),
+        )
+    }
+
+    pub fn ecma_pattern_need_feature(option: &syn::Path) -> Self {
+        Self::new(
+            option.span(),
+            "`ecma` needs the `fancy_regex` feature of serde_valid.",
+        )
+    }
+
+    pub fn internal_need_serde_skip(option: &syn::Path) -> Self {
+        Self::new(
+            option.span(),
+            "`internal` is allowed only with `#[serde(skip)]` or `#[serde(skip_deserializing)]`.",
+        )
+    }
+
+    pub fn skip_default_need_serde_default(option: &syn::Path) -> Self {
+        Self::new(
+            option.span(),
+            "`skip_default` needs `#[serde(default)]` or `#[serde(default = \"path\")]` on the field.",
+        )
+    }
+
+    pub fn condition_without_validations(option: &syn::Path, name: &str) -> Self {
+        Self::new(
+            option.span(),
+            format!(
+                "`{name}` applies only to the validations of its own #[validate(...)] attribute."
+            ),
+        )
+    }
+
+    pub fn internal_need_named_field(option: &syn::Path) -> Self {
+        Self::new(
+            option.span(),
+            "`internal` is allowed only with the named field.",
+        )
+    }
+
     pub fn message_fn_need_item(path: &syn::Path) -> Self {
         Self::new(path.span(), "`message_fn` need items.")
     }
@@ -312,6 +431,20 @@ impl Error {
         )
     }
 
+    pub fn message_template_invalid(lit_str: &syn::LitStr, reason: &str) -> Self {
+        Self::new(
+            lit_str.span(),
+            format!("Invalid message template: {reason} Write `{{{{` or `}}}}` for the braces."),
+        )
+    }
+
+    pub fn message_value_need_capture_value(lit_str: &syn::LitStr) -> Self {
+        Self::new(
+            lit_str.span(),
+            "`{value}` needs `#[validate(capture_value)]` on the field.",
+        )
+    }
+
     pub fn message_fn_tail_error(nested_meta: &syn::NestedMeta) -> Self {
         Self::new(nested_meta.span(), "`message_fn` support only 1 item.")
     }
@@ -324,6 +457,90 @@ impl Error {
         Self::new(lit.span(), "Allow numeric literal only.")
     }
 
+    pub fn range_literal_only(lit: &syn::Lit) -> Self {
+        Self::new(
+            lit.span(),
+            "Allow numeric literal, duration str literal (e.g. \"30s\") or byte size str literal (e.g. \"10MiB\") only.",
+        )
+    }
+
+    pub fn range_str_literal_parse_error(lit_str: &syn::LitStr) -> Self {
+        Self::new(
+            lit_str.span(),
+            format!(
+                "Invalid duration or byte size: {:?}. e.g. \"30s\", \"1h30m\", \"10MiB\".",
+                lit_str.value()
+            ),
+        )
+    }
+
+    pub fn usize_literal_only(lit: &syn::Lit) -> Self {
+        Self::new(lit.span(), "Allow non-negative integer literal only.")
+    }
+
+    pub fn float_field_needs_float_literal(lit: &syn::Lit, type_name: &str) -> Self {
+        Self::new(
+            lit.span(),
+            format!(
+                "`{type_name}` field needs float literal (e.g. `{}.0`).",
+                quote!(#lit)
+            ),
+        )
+    }
+
+    pub fn integer_field_needs_integer_literal(lit: &syn::Lit, type_name: &str) -> Self {
+        Self::new(
+            lit.span(),
+            format!("`{type_name}` field needs integer literal."),
+        )
+    }
+
+    pub fn literal_out_of_range(lit: &syn::Lit, type_name: &str) -> Self {
+        Self::new(
+            lit.span(),
+            format!(
+                "`{}` is out of range for `{type_name}` field.",
+                quote!(#lit)
+            ),
+        )
+    }
+
+    pub fn duration_literal_needs_duration_field(lit: &syn::Lit, type_name: &str) -> Self {
+        Self::new(
+            lit.span(),
+            format!("Duration literal is not allowed for `{type_name}` field. Use `std::time::Duration` field."),
+        )
+    }
+
+    pub fn byte_size_literal_needs_unsigned_field(lit: &syn::Lit, type_name: &str) -> Self {
+        Self::new(
+            lit.span(),
+            format!("Byte size literal is not allowed for `{type_name}` field. Use unsigned integer field."),
+        )
+    }
+
+    pub fn multiple_of_zero(lit: &syn::Lit) -> Self {
+        Self::new(lit.span(), "`multiple_of` must not be zero.")
+    }
+
+    pub fn pattern_compile_error(lit_str: &syn::LitStr, error: &impl std::fmt::Display) -> Self {
+        Self::new(lit_str.span(), format!("Invalid pattern: {error}"))
+    }
+
+    pub fn contradictory_bounds(
+        (lower_name, lower): (&str, &syn::Lit),
+        (upper_name, upper): (&str, &syn::Lit),
+    ) -> Self {
+        Self::new(
+            upper.span(),
+            format!(
+                "`{lower_name} = {}` and `{upper_name} = {}` are contradictory. No value can satisfy both.",
+                quote!(#lower),
+                quote!(#upper)
+            ),
+        )
+    }
+
     pub fn str_literal_only(lit: &syn::Lit) -> Self {
         Self::new(lit.span(), "Allow str literal only.")
     }
@@ -344,6 +561,45 @@ impl Error {
         Self::new(nested_meta.span(), "Too many list items.")
     }
 
+    pub fn compat_argument_need(nested_meta: &syn::NestedMeta, name: &str) -> Self {
+        Self::new(
+            nested_meta.span(),
+            format!("Need the argument like `{name} = \"...\"`."),
+        )
+    }
+
+    pub fn path_parse_error(lit_str: &syn::LitStr) -> Self {
+        Self::new(
+            lit_str.span(),
+            format!("Invalid path: {}", quote!(#lit_str)),
+        )
+    }
+
+    pub fn message_for_multiple_validations(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "The message for the multiple validations is ambiguous. Use the form like `maximum(10, message = \"...\")`.",
+        )
+    }
+
+    pub fn severity_need_value(meta: &syn::Meta) -> Self {
+        Self::new(
+            meta.span(),
+            "`severity` need the value like `severity = \"warning\"`.",
+        )
+    }
+
+    pub fn severity_unknown(lit: &syn::LitStr, candidates: &[&str]) -> Self {
+        let unknown = lit.value();
+        let filterd_candidates =
+            did_you_mean(&unknown, candidates).unwrap_or_else(|| candidates.to_vec());
+
+        Self::new(
+            lit.span(),
+            format!("Unknown severity: `{unknown}`. Is it one of the following?\n{filterd_candidates:#?}"),
+        )
+    }
+
     pub fn to_compile_error(&self) -> TokenStream {
         self.0.to_compile_error()
     }
@@ -1,3 +1,4 @@
+mod field_group_rule;
 mod named_struct_rule;
 mod unnamed_struct_rule;
 
@@ -0,0 +1,105 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::serde::rename::RenameMap;
+use crate::validate::CustomMessageToken;
+
+/// The built-in rules of the `Option` field group, like `#[rule(builtin::at_least_one_of(email, phone))]`.
+///
+/// The `builtin::` prefix keeps the user's rule functions of the same names callable.
+#[derive(Debug, Clone, Copy)]
+pub enum FieldGroupRule {
+    AtLeastOneOf,
+    ExactlyOneOf,
+    MutuallyExclusive,
+    AllOrNone,
+}
+
+impl FieldGroupRule {
+    pub const PREFIX: &'static str = "builtin";
+
+    pub const NAMES: [&'static str; 4] = [
+        "at_least_one_of",
+        "exactly_one_of",
+        "mutually_exclusive",
+        "all_or_none",
+    ];
+
+    /// Whether the path is in the `builtin::` namespace, like `builtin::at_least_one_of`.
+    pub fn is_builtin_path(path: &syn::Path) -> bool {
+        path.leading_colon.is_none()
+            && path.segments.len() == 2
+            && path.segments[0].ident == Self::PREFIX
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "at_least_one_of" => Some(Self::AtLeastOneOf),
+            "exactly_one_of" => Some(Self::ExactlyOneOf),
+            "mutually_exclusive" => Some(Self::MutuallyExclusive),
+            "all_or_none" => Some(Self::AllOrNone),
+            _ => None,
+        }
+    }
+
+    fn error_name(&self) -> &'static str {
+        match self {
+            Self::AtLeastOneOf => "AtLeastOneOf",
+            Self::ExactlyOneOf => "ExactlyOneOf",
+            Self::MutuallyExclusive => "MutuallyExclusive",
+            Self::AllOrNone => "AllOrNone",
+        }
+    }
+
+    /// The check by the count of the present fields.
+    fn is_valid_tokens(&self, fields_len: usize) -> TokenStream {
+        match self {
+            Self::AtLeastOneOf => quote!(__present_count >= 1),
+            Self::ExactlyOneOf => quote!(__present_count == 1),
+            Self::MutuallyExclusive => quote!(__present_count <= 1),
+            Self::AllOrNone => quote!(__present_count == 0 || __present_count == #fields_len),
+        }
+    }
+
+    pub fn rule_tokens(
+        &self,
+        fields: &[&syn::Ident],
+        custom_message: CustomMessageToken,
+        rename_map: &RenameMap,
+    ) -> TokenStream {
+        let field_names = fields.iter().map(|field| {
+            let field_name = field.to_string();
+            rename_map.get(&field_name).cloned().unwrap_or_else(|| {
+                field_name
+                    .trim_start_matches("r#")
+                    .to_owned()
+                    .to_token_stream()
+            })
+        });
+        let is_valid = self.is_valid_tokens(fields.len());
+        let custom_message = custom_message.into_token();
+        let error_type = syn::Ident::new(
+            &format!("{}Error", self.error_name()),
+            proc_macro2::Span::call_site(),
+        );
+
+        quote!(
+            {
+                let __present_count = [#(::std::option::Option::is_some(#fields)),*]
+                    .iter()
+                    .filter(|__is_present| **__is_present)
+                    .count();
+                if !(#is_valid) {
+                    use ::serde_valid::validation::{IntoError, ToDefaultMessage};
+
+                    __rule_vec_errors.push(::std::convert::From::from(
+                        ::serde_valid::validation::Composited::Single(
+                            ::serde_valid::#error_type::new(vec![#(#field_names),*]),
+                        )
+                        .into_error_by(#custom_message),
+                    ));
+                }
+            }
+        )
+    }
+}
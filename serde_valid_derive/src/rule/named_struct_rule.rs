@@ -5,10 +5,16 @@ use quote::{quote, ToTokens};
 use syn::parse_quote;
 use syn::spanned::Spanned;
 
+use super::field_group_rule::FieldGroupRule;
+use crate::serde::rename::RenameMap;
 use crate::types::CommaSeparatedTokenStreams;
+use crate::validate::{
+    extract_custom_message_tokens_from_items, is_custom_message, CustomMessageToken,
+};
 
 pub fn collect_rules_from_named_struct(
     attributes: &[syn::Attribute],
+    rename_map: &RenameMap,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -17,7 +23,7 @@ pub fn collect_rules_from_named_struct(
         .iter()
         .filter(|attribute| attribute.path == parse_quote!(rule))
         .filter_map(|attribute| match attribute.parse_meta() {
-            Ok(syn::Meta::List(list)) => match collect_rule(&list) {
+            Ok(syn::Meta::List(list)) => match collect_rule(&list, rename_map) {
                 Ok((field_ident, stream)) => {
                     rule_fields.extend(field_ident);
                     Some(stream)
@@ -51,9 +57,21 @@ fn collect_rule(
     syn::MetaList {
         path, ref nested, ..
     }: &syn::MetaList,
+    rename_map: &RenameMap,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
+    // The message of the rule, like `#[rule(sample_rule(val1, val2), message = "...")]`.
+    let (messages, nested): (Vec<_>, Vec<_>) =
+        nested.iter().partition(|meta| is_custom_message(meta));
+    let custom_message = match extract_custom_message_tokens_from_items(&messages) {
+        Ok(custom_message) => custom_message,
+        Err(message_errors) => {
+            errors.extend(message_errors);
+            CustomMessageToken::default()
+        }
+    };
+
     match nested.len() {
         0 => Err(vec![crate::Error::rule_need_function(path)])?,
         2.. => nested.iter().skip(1).for_each(|nested_meta| {
@@ -62,9 +80,9 @@ fn collect_rule(
         _ => {}
     }
 
-    let rule = match &nested[0] {
+    let rule = match nested[0] {
         syn::NestedMeta::Meta(meta) => match meta {
-            syn::Meta::List(list) => extract_rule_from_meta_list(list),
+            syn::Meta::List(list) => extract_rule_from_meta_list(list, custom_message, rename_map),
             syn::Meta::NameValue(name_value) => {
                 Err(vec![crate::Error::meta_name_value_not_support(name_value)])
             }
@@ -81,7 +99,7 @@ fn collect_rule(
                 Err(errors)
             }
         }
-        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors.into_iter()).collect()),
+        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors).collect()),
     }
 }
 
@@ -91,6 +109,8 @@ fn extract_rule_from_meta_list(
         ref nested,
         ..
     }: &syn::MetaList,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -126,11 +146,40 @@ fn extract_rule_from_meta_list(
         return Err(errors);
     }
 
+    if FieldGroupRule::is_builtin_path(rule_fn_name) {
+        let name = &rule_fn_name.segments[1].ident;
+        let field_group_rule = FieldGroupRule::from_name(&name.to_string()).ok_or_else(|| {
+            vec![crate::Error::validate_unknown_type(
+                rule_fn_name,
+                &name.to_string(),
+                &FieldGroupRule::NAMES,
+            )]
+        })?;
+        let fields = nested
+            .iter()
+            .filter_map(|nested_meta| match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => path.get_ident(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if fields.len() < 2 {
+            return Err(vec![crate::Error::rule_field_group_need_fields(
+                rule_fn_name,
+            )]);
+        }
+        let tokens = field_group_rule.rule_tokens(&fields, custom_message, rename_map);
+        return Ok((arg_idents, tokens));
+    }
+
+    let error = custom_message
+        .into_error_token(quote!(__error))
+        .unwrap_or(quote!(__error));
+
     Ok((
         arg_idents,
         quote!(
             if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __rule_vec_errors.push(__error);
+                __rule_vec_errors.push(::std::convert::Into::into(#error));
             };
         ),
     ))
@@ -5,6 +5,9 @@ use quote::quote;
 use syn::parse_quote;
 
 use crate::types::CommaSeparatedTokenStreams;
+use crate::validate::{
+    extract_custom_message_tokens_from_items, is_custom_message, CustomMessageToken,
+};
 
 pub fn collect_rules_from_unnamed_struct(
     attributes: &[syn::Attribute],
@@ -53,6 +56,17 @@ fn collect_rule(
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
+    // The message of the rule, like `#[rule(sample_rule(0, 1), message = "...")]`.
+    let (messages, nested): (Vec<_>, Vec<_>) =
+        nested.iter().partition(|meta| is_custom_message(meta));
+    let custom_message = match extract_custom_message_tokens_from_items(&messages) {
+        Ok(custom_message) => custom_message,
+        Err(message_errors) => {
+            errors.extend(message_errors);
+            CustomMessageToken::default()
+        }
+    };
+
     match nested.len() {
         0 => Err(vec![crate::Error::rule_need_function(path)])?,
         2.. => nested.iter().skip(1).for_each(|nested_meta| {
@@ -61,9 +75,9 @@ fn collect_rule(
         _ => {}
     }
 
-    let rule = match &nested[0] {
+    let rule = match nested[0] {
         syn::NestedMeta::Meta(meta) => match meta {
-            syn::Meta::List(list) => extract_rule_from_meta_list(list),
+            syn::Meta::List(list) => extract_rule_from_meta_list(list, custom_message),
             syn::Meta::NameValue(name_value) => {
                 Err(vec![crate::Error::meta_name_value_not_support(name_value)])
             }
@@ -80,7 +94,7 @@ fn collect_rule(
                 Err(errors)
             }
         }
-        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors.into_iter()).collect()),
+        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors).collect()),
     }
 }
 
@@ -90,6 +104,7 @@ fn extract_rule_from_meta_list(
         ref nested,
         ..
     }: &syn::MetaList,
+    custom_message: CustomMessageToken,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -126,12 +141,15 @@ fn extract_rule_from_meta_list(
         return Err(errors);
     }
 
+    let error = custom_message
+        .into_error_token(quote!(__error))
+        .unwrap_or(quote!(__error));
+
     Ok((
         arg_idents,
         quote!(
             if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __rule_vec_errors
-                    .push(__error);
+                __rule_vec_errors.push(::std::convert::Into::into(#error));
             };
         ),
     ))
@@ -1 +1,45 @@
+pub mod flatten;
 pub mod rename;
+pub mod representation;
+pub mod skip;
+
+use syn::parse_quote;
+
+/// Whether the `#[serde(...)]` attributes have the word, like `#[serde(flatten)]`.
+pub fn has_serde_word(attrs: &[syn::Attribute], word: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(serde))
+        .any(|attribute| match attribute.parse_meta() {
+            Ok(syn::Meta::List(serde_list)) => serde_list.nested.iter().any(|nested_meta| {
+                matches!(
+                    nested_meta,
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident(word)
+                )
+            }),
+            _ => false,
+        })
+}
+
+/// Find the string value of the `#[serde(...)]` attributes, like `#[serde(tag = "type")]`.
+pub fn find_serde_str_value(attrs: &[syn::Attribute], name: &str) -> Option<syn::LitStr> {
+    attrs
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(serde))
+        .find_map(|attribute| match attribute.parse_meta() {
+            Ok(syn::Meta::List(serde_list)) => {
+                serde_list
+                    .nested
+                    .into_iter()
+                    .find_map(|nested_meta| match nested_meta {
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                            path,
+                            lit: syn::Lit::Str(lit_str),
+                            ..
+                        })) if path.is_ident(name) => Some(lit_str),
+                        _ => None,
+                    })
+            }
+            _ => None,
+        })
+}
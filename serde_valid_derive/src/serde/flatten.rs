@@ -0,0 +1,6 @@
+use super::has_serde_word;
+
+/// Whether the field has `#[serde(flatten)]`.
+pub fn is_serde_flatten(attrs: &[syn::Attribute]) -> bool {
+    has_serde_word(attrs, "flatten")
+}
@@ -7,25 +7,166 @@ use syn::parse_quote;
 
 pub type RenameMap = HashMap<String, TokenStream>;
 
-pub fn collect_serde_rename_map(fields: &syn::FieldsNamed) -> RenameMap {
+/// The case conversion rule of `#[serde(rename_all = "...")]`.
+#[derive(Debug, Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(Self::Lower),
+            "UPPERCASE" => Some(Self::Upper),
+            "PascalCase" => Some(Self::Pascal),
+            "camelCase" => Some(Self::Camel),
+            "snake_case" => Some(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            "kebab-case" => Some(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    /// Apply the rule to the field name, which is `snake_case` in Rust.
+    pub fn apply_to_field(&self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule to the variant name, which is `PascalCase` in Rust.
+    pub fn apply_to_variant(&self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => variant.to_owned(),
+                }
+            }
+            Self::Snake => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+}
+
+/// Find the rename rule of the container, like `#[serde(rename_all = "camelCase")]`.
+///
+/// The attribute names are tried in order, such as `rename_all` of the enum variant
+/// and then `rename_all_fields` of the enum.
+pub fn find_serde_rename_rule(attrs_and_names: &[(&[syn::Attribute], &str)]) -> Option<RenameRule> {
+    attrs_and_names.iter().find_map(|(attrs, name)| {
+        attrs
+            .iter()
+            .filter(|attribute| attribute.path == parse_quote!(serde))
+            .find_map(|attribute| find_rename_from_serde_attributes(attribute, name))
+            .and_then(|rule| RenameRule::from_str(&rule.value()))
+    })
+}
+
+pub fn collect_serde_rename_map(
+    fields: &syn::FieldsNamed,
+    rename_rule: Option<RenameRule>,
+) -> RenameMap {
     let mut renames = RenameMap::new();
     for field in fields.named.iter() {
         let named_field = NamedField::new(field);
-        for attribute in named_field.attrs() {
-            if attribute.path == parse_quote!(serde) {
-                if let Some(rename) = find_rename_from_serde_attributes(attribute) {
-                    renames.insert(field.ident.to_token_stream().to_string(), rename);
-                }
-            }
+        let rename = named_field
+            .attrs()
+            .iter()
+            .filter(|attribute| attribute.path == parse_quote!(serde))
+            .find_map(|attribute| find_rename_from_serde_attributes(attribute, "rename"))
+            .map(|rename| rename.to_token_stream())
+            .or_else(|| {
+                let name = named_field.name().trim_start_matches("r#");
+                rename_rule.map(|rule| rule.apply_to_field(name).to_token_stream())
+            });
+        if let Some(rename) = rename {
+            renames.insert(field.ident.to_token_stream().to_string(), rename);
         }
     }
     renames
 }
 
-fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<TokenStream> {
+/// The serialized name of the enum variant, like `#[serde(rename = "...")]`
+/// or `#[serde(rename_all = "...")]` of the enum.
+pub fn find_serde_variant_name(input: &syn::DeriveInput, variant: &syn::Variant) -> String {
+    variant
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path == parse_quote!(serde))
+        .find_map(|attribute| find_rename_from_serde_attributes(attribute, "rename"))
+        .map(|rename| rename.value())
+        .unwrap_or_else(|| {
+            let name = variant.ident.to_string();
+            let name = name.trim_start_matches("r#");
+            match find_serde_rename_rule(&[(&input.attrs, "rename_all")]) {
+                Some(rule) => rule.apply_to_variant(name),
+                None => name.to_owned(),
+            }
+        })
+}
+
+fn find_rename_from_serde_attributes(
+    attribute: &syn::Attribute,
+    name: &str,
+) -> Option<syn::LitStr> {
     if let Ok(syn::Meta::List(serde_list)) = attribute.parse_meta() {
         for serde_nested_meta in serde_list.nested {
             if let syn::NestedMeta::Meta(serde_meta) = &serde_nested_meta {
+                if !serde_meta.path().is_ident(name) {
+                    continue;
+                }
                 if let Some(rename) = find_rename_from_serde_rename_attributes(serde_meta) {
                     return Some(rename);
                 }
@@ -35,11 +176,11 @@ fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<Token
     None
 }
 
-fn find_rename_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<TokenStream> {
+fn find_rename_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<syn::LitStr> {
     match serde_meta {
         syn::Meta::NameValue(rename_name_value) => {
             if let syn::Lit::Str(lit_str) = &rename_name_value.lit {
-                Some(lit_str.to_token_stream())
+                Some(lit_str.clone())
             } else {
                 None
             }
@@ -52,7 +193,7 @@ fn find_rename_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<To
                     }
                     if let syn::Meta::NameValue(deserialize_name_value) = rename_meta {
                         if let syn::Lit::Str(lit_str) = &deserialize_name_value.lit {
-                            return Some(lit_str.to_token_stream());
+                            return Some(lit_str.clone());
                         }
                     }
                 }
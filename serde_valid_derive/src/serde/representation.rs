@@ -0,0 +1,63 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{find_serde_str_value, has_serde_word, rename::find_serde_variant_name};
+
+/// The enum representation of serde.
+///
+/// See <https://serde.rs/enum-representations.html>.
+#[derive(Debug)]
+pub enum EnumRepresentation {
+    /// `{"Variant": {...}}`, which is the default.
+    External,
+    /// `{"type": "Variant", ...}` by `#[serde(tag = "type")]`.
+    Internal,
+    /// `{"t": "Variant", "c": {...}}` by `#[serde(tag = "t", content = "c")]`.
+    Adjacent { content: syn::LitStr },
+    /// `{...}` by `#[serde(untagged)]`.
+    Untagged,
+}
+
+impl EnumRepresentation {
+    pub fn new(input: &syn::DeriveInput) -> Self {
+        if has_serde_word(&input.attrs, "untagged") {
+            return Self::Untagged;
+        }
+        match (
+            find_serde_str_value(&input.attrs, "tag"),
+            find_serde_str_value(&input.attrs, "content"),
+        ) {
+            (Some(_), Some(content)) => Self::Adjacent { content },
+            (Some(_), None) => Self::Internal,
+            _ => Self::External,
+        }
+    }
+
+    /// Wrap the variant errors, so that the error paths follow the representation.
+    pub fn variant_errors_tokens(
+        &self,
+        input: &syn::DeriveInput,
+        variant: &syn::Variant,
+        variant_errors: TokenStream,
+    ) -> TokenStream {
+        if has_serde_word(&variant.attrs, "untagged") {
+            return variant_errors;
+        }
+        let key = match self {
+            Self::External => find_serde_variant_name(input, variant),
+            Self::Adjacent { content } => content.value(),
+            Self::Internal | Self::Untagged => return variant_errors,
+        };
+
+        quote!(::serde_valid::validation::Errors::Object(
+            ::serde_valid::validation::ObjectErrors::new(
+                ::serde_valid::validation::VecErrors::new(),
+                {
+                    let mut __variant_errors_map = ::serde_valid::validation::PropertyErrorsMap::new();
+                    __variant_errors_map.insert(#key, #variant_errors);
+                    __variant_errors_map
+                },
+            )
+        ))
+    }
+}
@@ -0,0 +1,29 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{find_serde_str_value, has_serde_word};
+use crate::types::Field;
+
+/// Whether the field is not deserialized, like `#[serde(skip)]` or `#[serde(skip_deserializing)]`.
+pub fn is_serde_skip_deserializing(attrs: &[syn::Attribute]) -> bool {
+    has_serde_word(attrs, "skip") || has_serde_word(attrs, "skip_deserializing")
+}
+
+/// Whether the field has `#[serde(default)]` or `#[serde(default = "path")]`.
+pub fn has_serde_default(attrs: &[syn::Attribute]) -> bool {
+    has_serde_word(attrs, "default") || find_serde_str_value(attrs, "default").is_some()
+}
+
+/// The default value of the field, like `#[serde(default = "path")]`.
+///
+/// `Default::default()` is used without the path.
+pub fn serde_default_value_tokens(field: &impl Field) -> Result<TokenStream, crate::Errors> {
+    let ty = field.ty();
+    match find_serde_str_value(field.attrs(), "default") {
+        Some(lit_str) => match lit_str.parse::<syn::ExprPath>() {
+            Ok(path) => Ok(quote!(#path())),
+            Err(_) => Err(vec![crate::Error::path_parse_error(&lit_str)]),
+        },
+        None => Ok(quote!(<#ty as ::std::default::Default>::default())),
+    }
+}
@@ -13,11 +13,15 @@ pub trait Field {
 
     fn errors_variable(&self) -> proc_macro2::TokenStream;
 
+    /// All errors variables of the struct, which the validations of the field may collect into.
+    fn errors_variables(&self) -> Vec<proc_macro2::TokenStream>;
+
     fn getter_token(&self) -> proc_macro2::TokenStream;
 
     fn attrs(&self) -> &Vec<syn::Attribute>;
 
-    fn vis(&self) -> &syn::Visibility;
-
     fn ty(&self) -> &syn::Type;
+
+    /// Whether the errors are of the error type of `#[serde_valid(error = MyError)]`.
+    fn has_custom_error_type(&self) -> bool;
 }
@@ -8,6 +8,8 @@ use syn::spanned::Spanned;
 pub struct NamedField<'a> {
     name: String,
     field: Cow<'a, syn::Field>,
+    internal: bool,
+    custom_error_type: bool,
 }
 
 impl<'a> NamedField<'a> {
@@ -18,8 +20,21 @@ impl<'a> NamedField<'a> {
         Self {
             name: field.ident.as_ref().unwrap().to_string(),
             field: Cow::Borrowed(field),
+            internal: false,
+            custom_error_type: false,
         }
     }
+
+    /// The errors are output to `internal`, instead of `properties`.
+    pub fn into_internal(mut self) -> Self {
+        self.internal = true;
+        self
+    }
+
+    pub fn with_custom_error_type(mut self, custom_error_type: bool) -> Self {
+        self.custom_error_type = custom_error_type;
+        self
+    }
 }
 
 impl<'a> Field for NamedField<'a> {
@@ -37,7 +52,19 @@ impl<'a> Field for NamedField<'a> {
     }
 
     fn errors_variable(&self) -> proc_macro2::TokenStream {
-        quote!(__property_vec_errors_map)
+        if self.internal {
+            quote!(__internal_vec_errors_map)
+        } else {
+            quote!(__property_vec_errors_map)
+        }
+    }
+
+    fn errors_variables(&self) -> Vec<proc_macro2::TokenStream> {
+        vec![
+            quote!(__rule_vec_errors),
+            quote!(__property_vec_errors_map),
+            quote!(__internal_vec_errors_map),
+        ]
     }
 
     fn getter_token(&self) -> proc_macro2::TokenStream {
@@ -49,11 +76,11 @@ impl<'a> Field for NamedField<'a> {
         self.field.attrs.as_ref()
     }
 
-    fn vis(&self) -> &syn::Visibility {
-        &self.field.vis
-    }
-
     fn ty(&self) -> &syn::Type {
         &self.field.ty
     }
+
+    fn has_custom_error_type(&self) -> bool {
+        self.custom_error_type
+    }
 }
@@ -11,6 +11,7 @@ pub struct UnnamedField<'a> {
     index: usize,
     ident: syn::Ident,
     field: Cow<'a, syn::Field>,
+    custom_error_type: bool,
 }
 
 impl<'a> UnnamedField<'a> {
@@ -23,8 +24,14 @@ impl<'a> UnnamedField<'a> {
             index,
             ident: syn::Ident::new(&format!("__{}", index), field.span()),
             field: Cow::Borrowed(field),
+            custom_error_type: false,
         }
     }
+
+    pub fn with_custom_error_type(mut self, custom_error_type: bool) -> Self {
+        self.custom_error_type = custom_error_type;
+        self
+    }
 }
 
 impl<'a> Field for UnnamedField<'a> {
@@ -45,6 +52,10 @@ impl<'a> Field for UnnamedField<'a> {
         quote!(__item_vec_errors_map)
     }
 
+    fn errors_variables(&self) -> Vec<proc_macro2::TokenStream> {
+        vec![quote!(__rule_vec_errors), quote!(__item_vec_errors_map)]
+    }
+
     fn getter_token(&self) -> proc_macro2::TokenStream {
         let index = syn::Index::from(self.index);
         quote!(#index)
@@ -54,11 +65,11 @@ impl<'a> Field for UnnamedField<'a> {
         self.field.attrs.as_ref()
     }
 
-    fn vis(&self) -> &syn::Visibility {
-        &self.field.vis
-    }
-
     fn ty(&self) -> &syn::Type {
         &self.field.ty
     }
+
+    fn has_custom_error_type(&self) -> bool {
+        self.custom_error_type
+    }
 }
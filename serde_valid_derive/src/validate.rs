@@ -1,5 +1,7 @@
 mod array;
+mod bounds;
 mod common;
+mod compat;
 mod field;
 mod generic;
 mod meta;
@@ -7,6 +9,15 @@ mod numeric;
 mod object;
 mod string;
 
+pub use bounds::check_field_bounds;
+#[cfg(feature = "fluent")]
 pub use common::MetaListMessage;
-pub use field::{FieldValidators, Validator};
-pub use meta::extract_meta_validator;
+pub use common::{is_custom_message, ContainerOption, CustomMessageToken, FieldOption};
+pub use field::{
+    composited_error_tokens, extract_attribute_condition, extract_field_condition,
+    field_error_tokens, find_field_option, is_attribute_condition, is_field_option,
+    FieldValidators, Validator,
+};
+pub use meta::{
+    extract_custom_message_tokens_from_items, extract_meta_validator, parse_validate_meta,
+};
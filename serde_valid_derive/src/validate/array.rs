@@ -1,4 +1,14 @@
+mod aggregate_items;
 mod length_items;
+mod order_items;
 mod unique_items;
+pub use aggregate_items::{extract_array_count_where_validator, extract_array_sum_validator};
 pub use length_items::{extract_array_max_items_validator, extract_array_min_items_validator};
-pub use unique_items::extract_array_unique_items_validator;
+pub use order_items::{
+    extract_array_sorted_by_validator, extract_array_sorted_validator,
+    extract_array_strictly_increasing_validator,
+};
+pub use unique_items::{
+    extract_array_unique_by_validator, extract_array_unique_items_list_validator,
+    extract_array_unique_items_validator,
+};
@@ -0,0 +1,190 @@
+use crate::{
+    serde::rename::RenameMap,
+    types::{CommaSeparatedNestedMetas, Field},
+    validate::{
+        common::{get_numeric, get_usize, CustomMessageToken},
+        Validator,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// `sum(minimum = 1, maximum = 100)` validates the sum of the items.
+pub fn extract_array_sum_validator(
+    field: &impl Field,
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+    let mut validators = vec![];
+    let mut errors = vec![];
+
+    for (bound, lit) in extract_bounds(path, nested, &["minimum", "maximum"], &mut errors) {
+        match get_numeric(lit) {
+            Ok(limit) => {
+                let (error_type, validation) = if bound == "minimum" {
+                    (
+                        "MinSum",
+                        quote!(::serde_valid::ValidateMinSum::validate_min_sum(#field_ident, #limit)),
+                    )
+                } else {
+                    (
+                        "MaxSum",
+                        quote!(::serde_valid::ValidateMaxSum::validate_max_sum(#field_ident, #limit)),
+                    )
+                };
+                validators.push(inner_extract_array_aggregate_validator(
+                    field,
+                    error_type,
+                    validation,
+                    custom_message.message_fn.clone(),
+                    rename_map,
+                ));
+            }
+            Err(lit_errors) => errors.extend(lit_errors),
+        }
+    }
+
+    if validators.is_empty() && errors.is_empty() {
+        errors.push(crate::Error::validate_sum_need_item(path));
+    }
+    if errors.is_empty() {
+        Ok(quote!(#(#validators)*))
+    } else {
+        Err(errors)
+    }
+}
+
+/// `count_where(is_admin, max = 1)` validates the count of the items
+/// which the predicate function returns `true` for.
+pub fn extract_array_count_where_validator(
+    field: &impl Field,
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+    let mut validators = vec![];
+    let mut errors = vec![];
+
+    let predicate = match nested.first() {
+        Some(syn::NestedMeta::Meta(syn::Meta::Path(predicate))) => predicate,
+        _ => return Err(vec![crate::Error::validate_count_where_need_item(path)]),
+    };
+    let bounds: CommaSeparatedNestedMetas = nested.iter().skip(1).cloned().collect();
+
+    for (bound, lit) in extract_bounds(path, &bounds, &["min", "max"], &mut errors) {
+        match get_usize(lit) {
+            Ok(limit) => {
+                let (error_type, validation) = if bound == "min" {
+                    (
+                        "MinCount",
+                        quote!(::serde_valid::ValidateMinCount::validate_min_count(
+                            #field_ident,
+                            |__item| #predicate(__item),
+                            #limit,
+                        )),
+                    )
+                } else {
+                    (
+                        "MaxCount",
+                        quote!(::serde_valid::ValidateMaxCount::validate_max_count(
+                            #field_ident,
+                            |__item| #predicate(__item),
+                            #limit,
+                        )),
+                    )
+                };
+                validators.push(inner_extract_array_aggregate_validator(
+                    field,
+                    error_type,
+                    validation,
+                    custom_message.message_fn.clone(),
+                    rename_map,
+                ));
+            }
+            Err(lit_errors) => errors.extend(lit_errors),
+        }
+    }
+
+    if validators.is_empty() && errors.is_empty() {
+        errors.push(crate::Error::validate_count_where_need_item(path));
+    }
+    if errors.is_empty() {
+        Ok(quote!(#(#validators)*))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Picks the `name = literal` items, each of which is allowed only once.
+fn extract_bounds<'a>(
+    path: &syn::Path,
+    nested: &'a CommaSeparatedNestedMetas,
+    names: &[&'static str],
+    errors: &mut crate::Errors,
+) -> Vec<(&'static str, &'a syn::Lit)> {
+    let mut bounds: Vec<(&'static str, &'a syn::Lit)> = vec![];
+
+    for nested_meta in nested {
+        let name_value = match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) => name_value,
+            _ => {
+                errors.push(need_item_error(path));
+                continue;
+            }
+        };
+        match names.iter().find(|name| name_value.path.is_ident(name)) {
+            Some(name) if bounds.iter().any(|(bound, _)| bound == name) => {
+                errors.push(crate::Error::too_many_list_items(nested_meta))
+            }
+            Some(name) => bounds.push((name, &name_value.lit)),
+            None => errors.push(need_item_error(path)),
+        }
+    }
+
+    bounds
+}
+
+fn need_item_error(path: &syn::Path) -> crate::Error {
+    if path.is_ident("sum") {
+        crate::Error::validate_sum_need_item(path)
+    } else {
+        crate::Error::validate_count_where_need_item(path)
+    }
+}
+
+fn inner_extract_array_aggregate_validator(
+    field: &impl Field,
+    error_type: &str,
+    validation: TokenStream,
+    message_fn: Option<TokenStream>,
+    rename_map: &RenameMap,
+) -> TokenStream {
+    let field_name = field.name();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let error_variant = format_ident!("{}", error_type);
+    let error_struct = format_ident!("{}Error", error_type);
+    let message_fn = message_fn.unwrap_or(quote!(::serde_valid::#error_struct::to_default_message));
+
+    quote!(
+        if let Err(error_params) = #validation {
+            use ::serde_valid::validation::ToDefaultMessage;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(::serde_valid::validation::FieldError::from_error(
+                    ::serde_valid::validation::Error::#error_variant(
+                        ::serde_valid::validation::Message::new(
+                            error_params,
+                            #message_fn,
+                        )
+                    )
+                ));
+        }
+    )
+}
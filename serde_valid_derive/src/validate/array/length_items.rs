@@ -1,6 +1,6 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::common::get_numeric;
+use crate::validate::common::get_usize;
 use crate::validate::{common::CustomMessageToken, Validator};
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -30,7 +30,7 @@ macro_rules! extract_array_length_validator{
                 let field_ident = field.ident();
                 let field_key = field.key();
                 let rename = rename_map.get(field_name).unwrap_or(&field_key);
-                let [<$ErrorType:snake>] = get_numeric(validation_value)?;
+                let [<$ErrorType:snake>] = get_usize(validation_value)?;
                 let errors = field.errors_variable();
                 let message_fn = custom_message
                     .message_fn.unwrap_or(quote!(::serde_valid::[<$ErrorType Error>]::to_default_message));
@@ -45,10 +45,12 @@ macro_rules! extract_array_length_validator{
                         #errors
                             .entry(#rename)
                             .or_default()
-                            .push(::serde_valid::validation::Error::$ErrorType(
-                                ::serde_valid::validation::Message::new(
-                                    error_params,
-                                    #message_fn,
+                            .push(::serde_valid::validation::FieldError::from_error(
+                                ::serde_valid::validation::Error::$ErrorType(
+                                    ::serde_valid::validation::Message::new(
+                                        error_params,
+                                        #message_fn,
+                                    )
                                 )
                             ));
                     }
@@ -0,0 +1,100 @@
+use crate::{
+    serde::rename::RenameMap,
+    types::Field,
+    validate::{
+        common::{get_members, CustomMessageToken},
+        Validator,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+pub fn extract_array_sorted_validator(
+    field: &impl Field,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_ident = field.ident();
+
+    inner_extract_array_order_validator(
+        field,
+        "Sorted",
+        quote!(::serde_valid::ValidateSorted::validate_sorted(#field_ident)),
+        custom_message,
+        rename_map,
+    )
+}
+
+/// `sorted_by = "created_at"` compares the adjacent items by the field.
+pub fn extract_array_sorted_by_validator(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+    let members = get_members(validation_value)?;
+
+    Ok(inner_extract_array_order_validator(
+        field,
+        "Sorted",
+        quote!(::serde_valid::ValidateSortedBy::validate_sorted_by(
+            #field_ident,
+            |__a, __b| __a #(.#members)* <= __b #(.#members)*,
+        )),
+        custom_message,
+        rename_map,
+    ))
+}
+
+pub fn extract_array_strictly_increasing_validator(
+    field: &impl Field,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_ident = field.ident();
+
+    inner_extract_array_order_validator(
+        field,
+        "StrictlyIncreasing",
+        quote!(::serde_valid::ValidateStrictlyIncreasing::validate_strictly_increasing(#field_ident)),
+        custom_message,
+        rename_map,
+    )
+}
+
+fn inner_extract_array_order_validator(
+    field: &impl Field,
+    error_type: &str,
+    validation: TokenStream,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> TokenStream {
+    let field_name = field.name();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let error_variant = format_ident!("{}", error_type);
+    let error_struct = format_ident!("{}Error", error_type);
+    let message_fn = custom_message
+        .message_fn
+        .unwrap_or(quote!(::serde_valid::#error_struct::to_default_message));
+
+    quote!(
+        if let Err(error_params) = #validation {
+            use ::serde_valid::validation::ToDefaultMessage;
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(::serde_valid::validation::FieldError::from_error(
+                    ::serde_valid::validation::Error::#error_variant(
+                        ::serde_valid::validation::Message::new(
+                            error_params,
+                            #message_fn,
+                        )
+                    )
+                ));
+        }
+    )
+}
@@ -1,7 +1,10 @@
 use crate::{
     serde::rename::RenameMap,
     types::Field,
-    validate::{common::CustomMessageToken, Validator},
+    validate::{
+        common::{get_members, CustomMessageToken},
+        Validator,
+    },
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -11,16 +14,88 @@ pub fn extract_array_unique_items_validator(
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Validator {
-    inner_extract_array_unique_items_validator(field, custom_message, rename_map)
+    let field_ident = field.ident();
+
+    inner_extract_array_unique_items_validator(
+        field,
+        quote!(::serde_valid::ValidateUniqueItems::validate_unique_items(#field_ident)),
+        custom_message,
+        rename_map,
+    )
+}
+
+/// `unique_items(partial_eq)`, for the items which are only `PartialEq`.
+pub fn extract_array_unique_items_list_validator(
+    field: &impl Field,
+    syn::MetaList { nested, .. }: &syn::MetaList,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    match nested.iter().collect::<Vec<_>>().as_slice() {
+        [] => Ok(extract_array_unique_items_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        [syn::NestedMeta::Meta(syn::Meta::Path(path))] if path.is_ident("partial_eq") => {
+            let field_ident = field.ident();
+            Ok(inner_extract_array_unique_items_validator(
+                field,
+                quote!(::serde_valid::ValidateUniqueItemsBy::validate_unique_items_by(
+                    #field_ident,
+                    |__a, __b| __a == __b,
+                )),
+                custom_message,
+                rename_map,
+            ))
+        }
+        [nested_meta, ..] => Err(vec![crate::Error::unique_items_allow_partial_eq(
+            nested_meta,
+        )]),
+    }
+}
+
+/// `unique_by = "id"` compares the items by the field,
+/// and `unique_by(key_fn)` compares the items by the key which the function returns.
+/// The key needs `Eq` and `Hash`.
+pub fn extract_array_unique_by_validator(
+    field: &impl Field,
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let key = match nested.iter().collect::<Vec<_>>().as_slice() {
+        [] => Err(vec![crate::Error::validate_unique_by_need_item(path)]),
+        [syn::NestedMeta::Lit(lit)] => {
+            let members = get_members(lit)?;
+            Ok(quote!(|__item| &__item #(.#members)*))
+        }
+        [syn::NestedMeta::Meta(syn::Meta::Path(key_fn))] => Ok(quote!(|__item| #key_fn(__item))),
+        [syn::NestedMeta::Meta(meta)] => Err(vec![crate::Error::validate_unique_by_need_item(
+            meta.path(),
+        )]),
+        [_, tail @ ..] => Err(tail
+            .iter()
+            .map(|&nested_meta| crate::Error::too_many_list_items(nested_meta))
+            .collect()),
+    }?;
+    let field_ident = field.ident();
+
+    Ok(inner_extract_array_unique_items_validator(
+        field,
+        quote!(::serde_valid::ValidateUniqueItemsByKey::validate_unique_items_by_key(#field_ident, #key)),
+        custom_message,
+        rename_map,
+    ))
 }
 
 fn inner_extract_array_unique_items_validator(
     field: &impl Field,
+    validation: TokenStream,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> TokenStream {
     let field_name = field.name();
-    let field_ident = field.ident();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
@@ -29,18 +104,18 @@ fn inner_extract_array_unique_items_validator(
         .unwrap_or(quote!(::serde_valid::UniqueItemsError::to_default_message));
 
     quote!(
-        if let Err(error_params) = ::serde_valid::ValidateUniqueItems::validate_unique_items(
-            #field_ident
-        ) {
+        if let Err(error_params) = #validation {
             use ::serde_valid::validation::ToDefaultMessage;
 
             #errors
                 .entry(#rename)
                 .or_default()
-                .push(::serde_valid::validation::Error::UniqueItems(
-                    ::serde_valid::validation::Message::new(
-                        error_params,
-                        #message_fn,
+                .push(::serde_valid::validation::FieldError::from_error(
+                    ::serde_valid::validation::Error::UniqueItems(
+                        ::serde_valid::validation::Message::new(
+                            error_params,
+                            #message_fn,
+                        )
                     )
                 ));
         }
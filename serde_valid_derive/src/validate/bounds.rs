@@ -0,0 +1,108 @@
+use crate::types::Field;
+use crate::validate::common::{extract_severity, get_range, is_attribute_option};
+use crate::validate::compat::expand_compat_validation;
+use crate::validate::{extract_attribute_condition, is_attribute_condition, parse_validate_meta};
+use std::cmp::Ordering;
+use syn::parse_quote;
+
+/// The pairs of the lower and the upper bound validations, and whether the pair is exclusive.
+const BOUND_PAIRS: [(&str, &str, bool); 7] = [
+    ("minimum", "maximum", false),
+    ("minimum", "exclusive_maximum", true),
+    ("exclusive_minimum", "maximum", true),
+    ("exclusive_minimum", "exclusive_maximum", true),
+    ("min_length", "max_length", false),
+    ("min_items", "max_items", false),
+    ("min_properties", "max_properties", false),
+];
+
+/// Detect the bounds on the same field which no value can satisfy, like `minimum = 10` and `maximum = 5`.
+///
+/// The bounds are compared only in the same scope, because the bounds under the different
+/// `when` or `skip_if` conditions may never run together,
+/// and the bounds of the different severities, like an error and a `severity = "warning"`, are independent.
+pub fn check_field_bounds(field: &impl Field) -> Result<(), crate::Errors> {
+    let limits = field
+        .attrs()
+        .iter()
+        .filter(|attribute| {
+            attribute.path == parse_quote!(validate) || attribute.path == parse_quote!(serde_valid)
+        })
+        .filter_map(|attribute| match parse_validate_meta(attribute) {
+            Ok(syn::Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .filter_map(|nested| {
+            let conditions = nested
+                .iter()
+                .filter(|meta| is_attribute_condition(meta))
+                .collect::<Vec<_>>();
+            let attribute_options = nested
+                .iter()
+                .filter(|meta| is_attribute_option(meta))
+                .collect::<Vec<_>>();
+            let scope = (
+                extract_attribute_condition(field, &conditions)
+                    .ok()?
+                    .map(|condition| condition.to_string()),
+                extract_severity(&attribute_options).ok()?.name(),
+            );
+            Some(nested.into_iter().map(move |meta| (scope.clone(), meta)))
+        })
+        .flatten()
+        .flat_map(|(scope, nested_meta)| {
+            expand_compat_validation(field, &nested_meta)
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |nested_meta| (scope.clone(), nested_meta))
+        })
+        .filter_map(|(scope, nested_meta)| match nested_meta {
+            // `maximum = 10`
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path, lit, ..
+            })) => Some((scope, path.get_ident()?.to_string(), lit)),
+            // `maximum(10, message = "...")`
+            syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. })) => {
+                match nested.into_iter().next() {
+                    Some(syn::NestedMeta::Lit(lit)) => {
+                        Some((scope, path.get_ident()?.to_string(), lit))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut errors = vec![];
+    for (lower_name, upper_name, exclusive) in BOUND_PAIRS {
+        for (lower_scope, _, lower) in limits.iter().filter(|(_, name, _)| name == lower_name) {
+            for (_, _, upper) in limits
+                .iter()
+                .filter(|(scope, name, _)| scope == lower_scope && name == upper_name)
+            {
+                let ordering = match (get_range(lower), get_range(upper)) {
+                    (Ok(lower_range), Ok(upper_range)) => lower_range.partial_cmp(&upper_range),
+                    _ => None,
+                };
+                let is_contradictory = match ordering {
+                    Some(Ordering::Greater) => true,
+                    Some(Ordering::Equal) => exclusive,
+                    _ => false,
+                };
+                if is_contradictory {
+                    errors.push(crate::Error::contradictory_bounds(
+                        (lower_name, lower),
+                        (upper_name, upper),
+                    ));
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
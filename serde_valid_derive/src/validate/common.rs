@@ -1,8 +1,15 @@
+mod attribute_option;
 mod custom_message;
 mod lit;
 
-pub use custom_message::{extract_custom_message_tokens, CustomMessageToken};
-pub use lit::{get_numeric, get_str};
+pub use attribute_option::{extract_severity, is_attribute_option};
+pub use custom_message::{
+    extract_custom_message_tokens, find_value_placeholder, is_custom_message, CustomMessageToken,
+};
+pub use lit::{
+    check_numeric_type, check_range_type, get_members, get_numeric, get_range, get_str, get_usize,
+    is_float_type,
+};
 
 macro_rules! count {
     () => (0usize);
@@ -71,6 +78,12 @@ enum_str! {
     pub enum MetaListValidation {
         Enumerate = "enumerate",
         Custom = "custom",
+        Pattern = "pattern",
+        NotPattern = "not_pattern",
+        UniqueItems = "unique_items",
+        UniqueBy = "unique_by",
+        Sum = "sum",
+        CountWhere = "count_where",
     }
 }
 
@@ -88,12 +101,89 @@ enum_str! {
         MaxProperties = "max_properties",
         MultipleOf = "multiple_of",
         Pattern = "pattern",
+        NotPattern = "not_pattern",
+        Contains = "contains",
+        NotContains = "not_contains",
+        StartsWith = "starts_with",
+        EndsWith = "ends_with",
+        Charset = "charset",
+        UniqueBy = "unique_by",
+        Key = "key",
+        SortedBy = "sorted_by",
     }
 }
 
 enum_str! {
     pub enum MetaPathValidation {
         UniqueItems = "unique_items",
+        Sorted = "sorted",
+        StrictlyIncreasing = "strictly_increasing",
+        Finite = "finite",
+        NotNan = "not_nan",
+        NotBlank = "not_blank",
+        Ascii = "ascii",
+        Alphanumeric = "alphanumeric",
+        Luhn = "luhn",
+        Iban = "iban",
+        Isbn = "isbn",
+        Ean = "ean",
+        Email = "email",
+        Url = "url",
+        Nested = "nested",
+        Required = "required",
+    }
+}
+
+// The options of the container, like `#[serde_valid(error = MyError)]`.
+enum_str! {
+    pub enum ContainerOption {
+        Error = "error",
+    }
+}
+
+// The options of the field, which change how the validations of the field run.
+enum_str! {
+    pub enum FieldOption {
+        Internal = "internal",
+        SkipDefault = "skip_default",
+        When = "when",
+        SkipIf = "skip_if",
+        Sensitive = "sensitive",
+        CaptureValue = "capture_value",
+    }
+}
+
+// The options of the attribute, which apply to all validations of the attribute.
+enum_str! {
+    pub enum AttributeOption {
+        Severity = "severity",
+    }
+}
+
+// The severities of the validations, like `#[validate(maximum = 100, severity = "warning")]`.
+enum_str! {
+    pub enum Severity {
+        Error = "error",
+        Warning = "warning",
+    }
+}
+
+// The validations of the `validator` crate, which are expanded into the serde_valid validations.
+enum_str! {
+    pub enum CompatValidation {
+        Length = "length",
+        Range = "range",
+        DoesNotContain = "does_not_contain",
+        Regex = "regex",
+        CreditCard = "credit_card",
+    }
+}
+
+enum_str! {
+    pub enum PatternOption {
+        CaseInsensitive = "case_insensitive",
+        FullMatch = "full_match",
+        Ecma = "ecma",
     }
 }
 
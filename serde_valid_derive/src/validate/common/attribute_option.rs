@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use super::{get_str, AttributeOption, Severity};
+
+/// Whether the item is the attribute option, like `severity = "warning"`.
+pub fn is_attribute_option(nested_meta: &syn::NestedMeta) -> bool {
+    match nested_meta {
+        syn::NestedMeta::Meta(meta) => meta
+            .path()
+            .get_ident()
+            .is_some_and(|ident| AttributeOption::from_str(&ident.to_string()).is_ok()),
+        syn::NestedMeta::Lit(_) => false,
+    }
+}
+
+/// Extract the severity from the attribute options, which is `error` by default.
+pub fn extract_severity(options: &[&syn::NestedMeta]) -> Result<Severity, crate::Errors> {
+    let mut errors = vec![];
+    let mut severity = None;
+
+    for option in options {
+        let syn::NestedMeta::Meta(meta) = option else {
+            continue;
+        };
+        if severity.is_some() {
+            errors.push(crate::Error::too_many_list_items(option));
+            continue;
+        }
+        let syn::Meta::NameValue(name_value) = meta else {
+            errors.push(crate::Error::severity_need_value(meta));
+            continue;
+        };
+        match get_str(&name_value.lit) {
+            Ok(lit_str) => match Severity::from_str(&lit_str.value()) {
+                Ok(value) => severity = Some(value),
+                Err(_) => errors.push(crate::Error::severity_unknown(
+                    lit_str,
+                    &Severity::iter().map(|s| s.name()).collect::<Vec<_>>(),
+                )),
+            },
+            Err(lit_errors) => errors.extend(lit_errors),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(severity.unwrap_or(Severity::Error))
+    } else {
+        Err(errors)
+    }
+}
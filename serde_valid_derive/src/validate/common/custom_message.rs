@@ -1,6 +1,6 @@
 use crate::types::{CommaSeparatedNestedMetas, SingleIdentPath};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use std::str::FromStr;
 
 use super::{get_str, MetaListMessage, MetaNameValueMessage, MetaPathMessage};
@@ -29,13 +29,38 @@ impl CustomMessageToken {
         }
     }
 
+    pub fn is_default(&self) -> bool {
+        #[cfg(feature = "fluent")]
+        if self.fluent_message.is_some() {
+            return false;
+        }
+        self.message_fn.is_none()
+    }
+
+    /// The error which replaces the error without the params,
+    /// like the errors of `#[validate(nested)]`, `custom(...)` and `#[rule(...)]`.
+    ///
+    /// The `message_fn` takes the replaced error, and `None` keeps the error as it is.
+    pub fn into_error_token(self, error: TokenStream) -> Option<TokenStream> {
+        #[cfg(feature = "fluent")]
+        if let Some(fluent_message) = self.fluent_message {
+            return Some(quote!(::serde_valid::validation::Error::Fluent(#fluent_message)));
+        }
+        self.message_fn.map(|message_fn| {
+            quote!(::serde_valid::validation::Error::custom_message(&#error, #message_fn))
+        })
+    }
+
     pub fn into_token(self) -> TokenStream {
         let message_fn = self.message_fn.unwrap_or(quote!(
             ::serde_valid::validation::ToDefaultMessage::to_default_message
         ));
 
         #[cfg(feature = "fluent")]
-        let fluent_message = quote!(fluent_message: None,);
+        let fluent_message = match self.fluent_message {
+            Some(fluent_message) => quote!(fluent_message: Some(#fluent_message),),
+            None => quote!(fluent_message: None,),
+        };
         #[cfg(not(feature = "fluent"))]
         let fluent_message = quote!();
 
@@ -48,6 +73,22 @@ impl CustomMessageToken {
     }
 }
 
+/// Whether the item is a custom message, like `message = "..."` or `message_fn(...)`.
+pub fn is_custom_message(nested_meta: &syn::NestedMeta) -> bool {
+    let path = match nested_meta {
+        syn::NestedMeta::Meta(meta) => meta.path(),
+        syn::NestedMeta::Lit(_) => return false,
+    };
+    match path.get_ident().map(|ident| ident.to_string()) {
+        Some(label) => {
+            MetaNameValueMessage::from_str(&label).is_ok()
+                || MetaListMessage::from_str(&label).is_ok()
+                || MetaPathMessage::from_str(&label).is_ok()
+        }
+        None => false,
+    }
+}
+
 pub fn extract_custom_message_tokens(
     nested_meta: &syn::NestedMeta,
 ) -> Result<CustomMessageToken, crate::Errors> {
@@ -179,8 +220,120 @@ fn get_message_fn_from_nested_meta(
     }
 }
 
+/// Find the message template which uses `{value}`, like `message = "{value} is too long."`,
+/// in the item and its nested items.
+pub fn find_value_placeholder(nested_meta: &syn::NestedMeta) -> Option<&syn::LitStr> {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+            path,
+            lit: syn::Lit::Str(lit_str),
+            ..
+        })) if path.is_ident(MetaNameValueMessage::Message.name()) => {
+            parse_message_template(lit_str)
+                .ok()
+                .filter(|template| template.params.iter().any(|param| param == "value"))
+                .map(|_| lit_str)
+        }
+        syn::NestedMeta::Meta(syn::Meta::List(list)) => {
+            list.nested.iter().find_map(find_value_placeholder)
+        }
+        _ => None,
+    }
+}
+
+/// The message template, like `"The length must be <= {max_length}."`.
+///
+/// The placeholders are the fields of the error params,
+/// so the unknown placeholder is a compile error on the type of the params.
 fn get_message_fn_from_lit(lit: &syn::Lit) -> Result<TokenStream, crate::Errors> {
-    get_str(lit).map(|lit_str| quote!(|_| { #lit_str.to_string() }))
+    let lit_str = get_str(lit)?;
+    let template = parse_message_template(lit_str).map_err(|error| vec![error])?;
+
+    if template.params.is_empty() {
+        let message = syn::LitStr::new(&template.message, lit_str.span());
+        return Ok(quote!(|_| { #message.to_string() }));
+    }
+
+    let format = syn::LitStr::new(&template.format, lit_str.span());
+    let params = template.params.iter();
+    Ok(quote_spanned!(lit_str.span()=>
+        |__params| {
+            format!(
+                #format,
+                #(::serde_valid::validation::ToMessageParam::to_message_param(&__params.#params)),*
+            )
+        }
+    ))
+}
+
+struct MessageTemplate {
+    /// The format string of `format!`, with `{}` for the placeholders.
+    format: String,
+    /// The message without the escapes, for the template without the placeholders.
+    message: String,
+    params: Vec<syn::Ident>,
+}
+
+fn parse_message_template(lit_str: &syn::LitStr) -> Result<MessageTemplate, crate::Error> {
+    let value = lit_str.value();
+    let mut template = MessageTemplate {
+        format: String::new(),
+        message: String::new(),
+        params: vec![],
+    };
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                template.format.push_str("{{");
+                template.message.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                template.format.push_str("}}");
+                template.message.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => {
+                            return Err(crate::Error::message_template_invalid(
+                                lit_str,
+                                "`{` is not closed.",
+                            ))
+                        }
+                    }
+                }
+                let param = syn::parse_str::<syn::Ident>(name.trim()).map_err(|_| {
+                    crate::Error::message_template_invalid(
+                        lit_str,
+                        &format!("`{{{name}}}` is not a field name."),
+                    )
+                })?;
+                template.format.push_str("{}");
+                template
+                    .params
+                    .push(syn::Ident::new(&param.to_string(), lit_str.span()));
+            }
+            '}' => {
+                return Err(crate::Error::message_template_invalid(
+                    lit_str,
+                    "`}` is not opened.",
+                ))
+            }
+            c => {
+                template.format.push(c);
+                template.message.push(c);
+            }
+        }
+    }
+
+    Ok(template)
 }
 
 #[cfg(feature = "fluent")]
@@ -1,5 +1,7 @@
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
+use std::cmp::Ordering;
+use std::time::Duration;
 
 pub enum LitNumeric<'a> {
     Int(&'a syn::LitInt),
@@ -15,7 +17,58 @@ impl<'a> ToTokens for LitNumeric<'a> {
     }
 }
 
-pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric, crate::Errors> {
+/// Limit of the range validations.
+///
+/// In addition to the numeric literal, the str literal of duration (`"30s"`)
+/// and byte size (`"10MiB"`) are allowed.
+pub enum LitRange<'a> {
+    Numeric(LitNumeric<'a>),
+    Duration(Duration),
+    ByteSize(u64),
+}
+
+impl<'a> ToTokens for LitRange<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            LitRange::Numeric(numeric) => numeric.to_tokens(tokens),
+            LitRange::Duration(duration) => {
+                let secs = duration.as_secs();
+                let nanos = duration.subsec_nanos();
+                tokens.extend(quote!(::std::time::Duration::new(#secs, #nanos)))
+            }
+            LitRange::ByteSize(bytes) => {
+                tokens.extend(quote!(::serde_valid::validation::ByteSize::new(#bytes)))
+            }
+        }
+    }
+}
+
+impl<'a> LitRange<'a> {
+    /// Compare the limits of the same kind. The limits of different kinds are not comparable.
+    pub fn partial_cmp(&self, other: &LitRange) -> Option<Ordering> {
+        match (self, other) {
+            (LitRange::Numeric(a), LitRange::Numeric(b)) => a.to_f64()?.partial_cmp(&b.to_f64()?),
+            (LitRange::Duration(a), LitRange::Duration(b)) => Some(a.cmp(b)),
+            (LitRange::ByteSize(a), LitRange::ByteSize(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> LitNumeric<'a> {
+    pub fn is_zero(&self) -> bool {
+        self.to_f64() == Some(0.0)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        match self {
+            LitNumeric::Int(int) => int.base10_parse().ok(),
+            LitNumeric::Float(float) => float.base10_parse().ok(),
+        }
+    }
+}
+
+pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric<'_>, crate::Errors> {
     match lit {
         syn::Lit::Int(int) => Ok(LitNumeric::Int(int)),
         syn::Lit::Float(float) => Ok(LitNumeric::Float(float)),
@@ -23,9 +76,243 @@ pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric, crate::Errors> {
     }
 }
 
+pub fn get_range(lit: &syn::Lit) -> Result<LitRange<'_>, crate::Errors> {
+    match lit {
+        syn::Lit::Int(int) => Ok(LitRange::Numeric(LitNumeric::Int(int))),
+        syn::Lit::Float(float) => Ok(LitRange::Numeric(LitNumeric::Float(float))),
+        syn::Lit::Str(lit_str) => {
+            let value = lit_str.value();
+            if let Some(duration) = parse_duration(&value) {
+                Ok(LitRange::Duration(duration))
+            } else if let Some(bytes) = parse_byte_size(&value) {
+                Ok(LitRange::ByteSize(bytes))
+            } else {
+                Err(vec![crate::Error::range_str_literal_parse_error(lit_str)])
+            }
+        }
+        _ => Err(vec![crate::Error::range_literal_only(lit)]),
+    }
+}
+
+pub fn get_usize(lit: &syn::Lit) -> Result<&syn::LitInt, crate::Errors> {
+    match lit {
+        syn::Lit::Int(int)
+            if matches!(int.suffix(), "" | "usize") && int.base10_parse::<usize>().is_ok() =>
+        {
+            Ok(int)
+        }
+        _ => Err(vec![crate::Error::usize_literal_only(lit)]),
+    }
+}
+
 pub fn get_str(lit: &syn::Lit) -> Result<&syn::LitStr, crate::Errors> {
     match lit {
         syn::Lit::Str(lit_str) => Ok(lit_str),
         _ => Err(vec![crate::Error::str_literal_only(lit)]),
     }
 }
+
+/// Parse the field path like `"id"` or `"profile.email"`.
+pub fn get_members(lit: &syn::Lit) -> Result<Vec<syn::Member>, crate::Errors> {
+    let lit_str = get_str(lit)?;
+    lit_str
+        .value()
+        .split('.')
+        .map(syn::parse_str::<syn::Member>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| vec![crate::Error::path_parse_error(lit_str)])
+}
+
+/// Parse the duration like `"30s"`, `"1h 30m"` or `"500ms"`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let mut nanos: u128 = 0;
+    let mut chars = value.trim().chars().peekable();
+
+    chars.peek()?;
+    while chars.peek().is_some() {
+        let mut number = String::new();
+        while let Some(c) = chars.next_if(char::is_ascii_digit) {
+            number.push(c);
+        }
+        let mut unit = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_alphabetic()) {
+            unit.push(c);
+        }
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+        let scale: u128 = match unit.as_str() {
+            "ns" => 1,
+            "us" | "µs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60_000_000_000,
+            "h" => 3_600_000_000_000,
+            "d" => 86_400_000_000_000,
+            _ => return None,
+        };
+        nanos = nanos.checked_add(number.parse::<u128>().ok()?.checked_mul(scale)?)?;
+    }
+
+    Some(Duration::new(
+        u64::try_from(nanos / 1_000_000_000).ok()?,
+        (nanos % 1_000_000_000) as u32,
+    ))
+}
+
+/// Parse the byte size like `"512B"`, `"10MiB"` or `"1 GB"`.
+fn parse_byte_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (number, unit) = value.split_at(value.find(|c: char| !c.is_ascii_digit())?);
+
+    let scale: u64 = match unit.trim_start() {
+        "B" => 1,
+        "KB" | "kB" => 1_000,
+        "MB" => 1_000_000,
+        "GB" => 1_000_000_000,
+        "TB" => 1_000_000_000_000,
+        "PB" => 1_000_000_000_000_000,
+        "KiB" => 1 << 10,
+        "MiB" => 1 << 20,
+        "GiB" => 1 << 30,
+        "TiB" => 1 << 40,
+        "PiB" => 1 << 50,
+        _ => return None,
+    };
+    number.parse::<u64>().ok()?.checked_mul(scale)
+}
+
+/// Check the numeric limit against the field type, when the field type is a primitive.
+pub fn check_numeric_type(
+    lit: &syn::Lit,
+    numeric: &LitNumeric,
+    ty: &syn::Type,
+) -> Result<(), crate::Errors> {
+    let type_name = match primitive_type_name(ty) {
+        Some(type_name) => type_name,
+        None => return Ok(()),
+    };
+
+    let is_valid = match (numeric, type_name.as_str()) {
+        (LitNumeric::Int(_), "f32" | "f64") => {
+            return Err(vec![crate::Error::float_field_needs_float_literal(
+                lit, &type_name,
+            )])
+        }
+        (LitNumeric::Float(_), "f32" | "f64") => true,
+        (LitNumeric::Float(_), _) if is_integer_type_name(&type_name) => {
+            return Err(vec![crate::Error::integer_field_needs_integer_literal(
+                lit, &type_name,
+            )])
+        }
+        (LitNumeric::Int(int), _) if !int.suffix().is_empty() => true,
+        (LitNumeric::Int(int), "i8") => int.base10_parse::<i8>().is_ok(),
+        (LitNumeric::Int(int), "i16") => int.base10_parse::<i16>().is_ok(),
+        (LitNumeric::Int(int), "i32") => int.base10_parse::<i32>().is_ok(),
+        (LitNumeric::Int(int), "i64") => int.base10_parse::<i64>().is_ok(),
+        (LitNumeric::Int(int), "i128") => int.base10_parse::<i128>().is_ok(),
+        (LitNumeric::Int(int), "isize") => int.base10_parse::<isize>().is_ok(),
+        (LitNumeric::Int(int), "u8") => int.base10_parse::<u8>().is_ok(),
+        (LitNumeric::Int(int), "u16") => int.base10_parse::<u16>().is_ok(),
+        (LitNumeric::Int(int), "u32") => int.base10_parse::<u32>().is_ok(),
+        (LitNumeric::Int(int), "u64") => int.base10_parse::<u64>().is_ok(),
+        (LitNumeric::Int(int), "u128") => int.base10_parse::<u128>().is_ok(),
+        (LitNumeric::Int(int), "usize") => int.base10_parse::<usize>().is_ok(),
+        _ => true,
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(vec![crate::Error::literal_out_of_range(lit, &type_name)])
+    }
+}
+
+/// Check the range limit against the field type, when the field type is a primitive or `Duration`.
+pub fn check_range_type(
+    lit: &syn::Lit,
+    range: &LitRange,
+    ty: &syn::Type,
+) -> Result<(), crate::Errors> {
+    match range {
+        LitRange::Numeric(numeric) => check_numeric_type(lit, numeric, ty),
+        LitRange::Duration(_) => match primitive_type_name(ty) {
+            Some(type_name) if type_name != "Duration" => {
+                Err(vec![crate::Error::duration_literal_needs_duration_field(
+                    lit, &type_name,
+                )])
+            }
+            _ => Ok(()),
+        },
+        LitRange::ByteSize(_) => match primitive_type_name(ty) {
+            Some(type_name)
+                if !matches!(
+                    type_name.as_str(),
+                    "u8" | "u16" | "u32" | "u64" | "u128" | "usize"
+                ) =>
+            {
+                Err(vec![crate::Error::byte_size_literal_needs_unsigned_field(
+                    lit, &type_name,
+                )])
+            }
+            _ => Ok(()),
+        },
+    }
+}
+
+fn is_integer_type_name(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "i8" | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+    )
+}
+
+/// Whether the innermost type of the field is `f32` or `f64`.
+pub fn is_float_type(ty: &syn::Type) -> bool {
+    matches!(primitive_type_name(ty).as_deref(), Some("f32" | "f64"))
+}
+
+/// The name of the innermost type of the field, looking through `Option`, `Vec`, arrays and references.
+///
+/// Returns `None` for the generic or the complex types, which can not be checked at expansion time.
+fn primitive_type_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Reference(reference) => primitive_type_name(&reference.elem),
+        syn::Type::Array(array) => primitive_type_name(&array.elem),
+        syn::Type::Paren(paren) => primitive_type_name(&paren.elem),
+        syn::Type::Group(group) => primitive_type_name(&group.elem),
+        syn::Type::Path(type_path) if type_path.qself.is_none() => {
+            let segment = type_path.path.segments.last()?;
+            match &segment.arguments {
+                syn::PathArguments::None => {
+                    let type_name = segment.ident.to_string();
+                    (type_name == "Duration"
+                        || type_name == "f32"
+                        || type_name == "f64"
+                        || is_integer_type_name(&type_name))
+                    .then_some(type_name)
+                }
+                syn::PathArguments::AngleBracketed(arguments)
+                    if matches!(segment.ident.to_string().as_str(), "Option" | "Vec")
+                        && arguments.args.len() == 1 =>
+                {
+                    match &arguments.args[0] {
+                        syn::GenericArgument::Type(ty) => primitive_type_name(ty),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
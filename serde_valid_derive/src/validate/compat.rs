@@ -0,0 +1,297 @@
+//! The attribute syntax of the [`validator`](https://docs.rs/validator) crate.
+//!
+//! The validations like `length(min = 1, max = 5)` are expanded into
+//! the serde_valid validations like `min_length = 1` and `max_length = 5`.
+
+use crate::types::{Field, SingleIdentPath};
+use crate::validate::common::{get_str, is_custom_message, is_float_type, CompatValidation};
+use crate::validate::generic::VALIDATOR_COMPAT_MARKER;
+use proc_macro2::Span;
+use std::str::FromStr;
+use syn::{parse_quote_spanned, spanned::Spanned};
+
+/// Expand the `validator` crate compatible validation into the serde_valid validations.
+///
+/// The other validations are returned as they are.
+pub fn expand_compat_validation(
+    field: &impl Field,
+    nested_meta: &syn::NestedMeta,
+) -> Result<Vec<syn::NestedMeta>, crate::Errors> {
+    let meta = match nested_meta {
+        syn::NestedMeta::Meta(meta) => meta,
+        syn::NestedMeta::Lit(_) => return Ok(vec![nested_meta.clone()]),
+    };
+    let path = meta.path();
+    let label = match path.get_ident() {
+        Some(ident) => ident.to_string(),
+        None => return Ok(vec![nested_meta.clone()]),
+    };
+
+    let (args, messages) = match meta {
+        syn::Meta::List(list) => list
+            .nested
+            .iter()
+            .partition::<Vec<_>, _>(|item| !is_custom_message(item)),
+        _ => (vec![], vec![]),
+    };
+
+    match (CompatValidation::from_str(&label), meta) {
+        (Ok(CompatValidation::Length), syn::Meta::List(_)) => {
+            let (min, max) = match LengthKind::new(field.ty()) {
+                LengthKind::Items => ("min_items", "max_items"),
+                LengthKind::Properties => ("min_properties", "max_properties"),
+                LengthKind::Length => ("min_length", "max_length"),
+            };
+            expand_bounds(
+                path,
+                &args,
+                &messages,
+                &[("min", &[min]), ("max", &[max]), ("equal", &[min, max])],
+            )
+        }
+        (Ok(CompatValidation::Range), syn::Meta::List(_)) => {
+            // `validator` accepts `range(min = 1)` for the float field, which serde_valid writes as `1.0`.
+            let args = if is_float_type(field.ty()) {
+                args.iter().map(|arg| int_to_float_arg(arg)).collect()
+            } else {
+                args.into_iter().cloned().collect::<Vec<_>>()
+            };
+            expand_bounds(
+                path,
+                &args.iter().collect::<Vec<_>>(),
+                &messages,
+                &[
+                    ("min", &["minimum"]),
+                    ("max", &["maximum"]),
+                    ("exclusive_min", &["exclusive_minimum"]),
+                    ("exclusive_max", &["exclusive_maximum"]),
+                ],
+            )
+        }
+        (Ok(CompatValidation::DoesNotContain), syn::Meta::NameValue(name_value)) => {
+            let lit = &name_value.lit;
+            Ok(vec![
+                parse_quote_spanned!(path.span()=> not_contains = #lit),
+            ])
+        }
+        (Ok(CompatValidation::DoesNotContain), syn::Meta::List(_)) => {
+            let lit = get_single_arg(path, &args, "pattern")?;
+            Ok(vec![expand_item(
+                "not_contains",
+                path.span(),
+                lit,
+                &messages,
+            )])
+        }
+        (Ok(CompatValidation::Regex), syn::Meta::NameValue(name_value)) => {
+            let regex_path = get_path(&name_value.lit)?;
+            Ok(vec![
+                parse_quote_spanned!(path.span()=> pattern(#regex_path)),
+            ])
+        }
+        (Ok(CompatValidation::Regex), syn::Meta::List(_)) => {
+            let regex_path = get_path(get_single_arg(path, &args, "path")?)?;
+            Ok(vec![
+                parse_quote_spanned!(path.span()=> pattern(#regex_path #(, #messages)*)),
+            ])
+        }
+        (Ok(CompatValidation::CreditCard), syn::Meta::Path(_)) => {
+            Ok(vec![parse_quote_spanned!(path.span()=> luhn)])
+        }
+        (Ok(CompatValidation::CreditCard), syn::Meta::List(_)) if args.is_empty() => Ok(vec![
+            parse_quote_spanned!(path.span()=> luhn(#(#messages),*)),
+        ]),
+        // `contains(pattern = "...")`
+        (_, syn::Meta::List(_)) if label == "contains" && is_name_value_arg(&args, "pattern") => {
+            let lit = get_single_arg(path, &args, "pattern")?;
+            Ok(vec![expand_item("contains", path.span(), lit, &messages)])
+        }
+        // `custom = "function"` and `custom(function = "function")`,
+        // whose function returns `validator::ValidationError`.
+        (_, syn::Meta::NameValue(name_value)) if label == "custom" => {
+            let function = get_path(&name_value.lit)?;
+            let marker = syn::Ident::new(VALIDATOR_COMPAT_MARKER, path.span());
+            Ok(vec![
+                parse_quote_spanned!(path.span()=> custom(#function, #marker)),
+            ])
+        }
+        (_, syn::Meta::List(_)) if label == "custom" && is_name_value_arg(&args, "function") => {
+            let function = get_path(get_single_arg(path, &args, "function")?)?;
+            let marker = syn::Ident::new(VALIDATOR_COMPAT_MARKER, path.span());
+            Ok(vec![
+                parse_quote_spanned!(path.span()=> custom(#function, #marker #(, #messages)*)),
+            ])
+        }
+        (Ok(_), _) => Err(vec![crate::Error::validate_meta_list_need_value(
+            path, &label,
+        )]),
+        (Err(_), _) => Ok(vec![nested_meta.clone()]),
+    }
+}
+
+/// The kind of the `length` target, which decides the serde_valid validation.
+enum LengthKind {
+    Length,
+    Items,
+    Properties,
+}
+
+impl LengthKind {
+    fn new(ty: &syn::Type) -> Self {
+        match ty {
+            syn::Type::Reference(reference) => Self::new(&reference.elem),
+            syn::Type::Paren(paren) => Self::new(&paren.elem),
+            syn::Type::Group(group) => Self::new(&group.elem),
+            syn::Type::Array(_) | syn::Type::Slice(_) => Self::Items,
+            syn::Type::Path(type_path) => match type_path.path.segments.last() {
+                Some(segment) => match segment.ident.to_string().as_str() {
+                    "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "BinaryHeap" => {
+                        Self::Items
+                    }
+                    "HashMap" | "BTreeMap" | "Map" => Self::Properties,
+                    "Option" => match &segment.arguments {
+                        syn::PathArguments::AngleBracketed(arguments) => {
+                            match arguments.args.first() {
+                                Some(syn::GenericArgument::Type(ty)) => Self::new(ty),
+                                _ => Self::Length,
+                            }
+                        }
+                        _ => Self::Length,
+                    },
+                    _ => Self::Length,
+                },
+                None => Self::Length,
+            },
+            _ => Self::Length,
+        }
+    }
+}
+
+/// Expand the bound arguments like `min = 1` into the validations like `min_length = 1`.
+fn expand_bounds(
+    path: &syn::Path,
+    args: &[&syn::NestedMeta],
+    messages: &[&syn::NestedMeta],
+    names: &[(&str, &[&str])],
+) -> Result<Vec<syn::NestedMeta>, crate::Errors> {
+    if args.is_empty() {
+        return Err(vec![crate::Error::validate_meta_list_need_value(
+            path,
+            &SingleIdentPath::new(path).ident().to_string(),
+        )]);
+    }
+
+    let mut errors = vec![];
+    let mut validations = vec![];
+    for arg in args {
+        match arg {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path: arg_path,
+                lit,
+                ..
+            })) => {
+                let arg_label = SingleIdentPath::new(arg_path).ident().to_string();
+                match names.iter().find(|(name, _)| *name == arg_label) {
+                    Some((_, validation_names)) => {
+                        for validation_name in validation_names.iter() {
+                            validations.push(expand_item(
+                                validation_name,
+                                arg_path.span(),
+                                lit,
+                                messages,
+                            ));
+                        }
+                    }
+                    None => errors.push(crate::Error::validate_unknown_type(
+                        arg_path,
+                        &arg_label,
+                        &names.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+                    )),
+                }
+            }
+            syn::NestedMeta::Meta(meta) => errors.push(crate::Error::literal_only(meta)),
+            syn::NestedMeta::Lit(lit) => errors.push(crate::Error::literal_not_support(lit)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(validations)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Turn the unsuffixed integer argument like `min = 1` into the float argument `min = 1.0`.
+fn int_to_float_arg(arg: &syn::NestedMeta) -> syn::NestedMeta {
+    match arg {
+        syn::NestedMeta::Meta(syn::Meta::NameValue(
+            name_value @ syn::MetaNameValue {
+                lit: syn::Lit::Int(int),
+                ..
+            },
+        )) if int.suffix().is_empty() => {
+            let float = syn::LitFloat::new(&format!("{}.0", int.base10_digits()), int.span());
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Float(float),
+                ..name_value.clone()
+            }))
+        }
+        _ => arg.clone(),
+    }
+}
+
+fn expand_item(
+    validation_name: &str,
+    span: Span,
+    lit: &syn::Lit,
+    messages: &[&syn::NestedMeta],
+) -> syn::NestedMeta {
+    let validation_ident = syn::Ident::new(validation_name, span);
+    parse_quote_spanned!(span=> #validation_ident(#lit #(, #messages)*))
+}
+
+fn is_name_value_arg(args: &[&syn::NestedMeta], name: &str) -> bool {
+    matches!(
+        args,
+        [syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))]
+            if name_value.path.is_ident(name)
+    )
+}
+
+/// Get the value of the single argument like `pattern = "..."`.
+fn get_single_arg<'a>(
+    path: &syn::Path,
+    args: &[&'a syn::NestedMeta],
+    name: &str,
+) -> Result<&'a syn::Lit, crate::Errors> {
+    match args {
+        [syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))]
+            if name_value.path.is_ident(name) =>
+        {
+            Ok(&name_value.lit)
+        }
+        [] => Err(vec![crate::Error::validate_meta_list_need_value(
+            path,
+            &SingleIdentPath::new(path).ident().to_string(),
+        )]),
+        [arg] => Err(vec![crate::Error::compat_argument_need(arg, name)]),
+        [_, tail @ ..] => Err(tail
+            .iter()
+            .map(|&arg| crate::Error::too_many_list_items(arg))
+            .collect()),
+    }
+}
+
+/// Get the path written in the str literal, like `"crate::RE"` and `"*RE"`.
+fn get_path(lit: &syn::Lit) -> Result<syn::Path, crate::Errors> {
+    let lit_str = get_str(lit)?;
+    let value = lit_str.value();
+    syn::parse_str::<syn::Path>(value.trim_start_matches('*'))
+        .map(|mut path| {
+            path.segments
+                .iter_mut()
+                .for_each(|segment| segment.ident.set_span(lit_str.span()));
+            path
+        })
+        .map_err(|_| vec![crate::Error::path_parse_error(lit_str)])
+}
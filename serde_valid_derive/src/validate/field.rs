@@ -1,19 +1,35 @@
+use crate::serde::skip::{has_serde_default, serde_default_value_tokens};
 use crate::types::Field;
+use crate::validate::common::{get_str, FieldOption};
+use crate::validate::parse_validate_meta;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
 use std::iter::FromIterator;
+use std::str::FromStr;
+use syn::parse_quote;
 
 pub type Validator = TokenStream;
 
 pub struct FieldValidators<'a, F: Field + Clone + 'a> {
     field: Cow<'a, F>,
     validators: Vec<Validator>,
+    condition: Option<TokenStream>,
 }
 
 impl<'a, F: Field + Clone> FieldValidators<'a, F> {
     pub fn new(field: Cow<'a, F>, validators: Vec<Validator>) -> Self {
-        Self { field, validators }
+        Self {
+            field,
+            validators,
+            condition: None,
+        }
+    }
+
+    /// Run the validators only when the condition is true.
+    pub fn with_condition(mut self, condition: TokenStream) -> Self {
+        self.condition = Some(condition);
+        self
     }
 
     pub fn ident(&self) -> &syn::Ident {
@@ -27,7 +43,14 @@ impl<'a, F: Field + Clone> FieldValidators<'a, F> {
     pub fn get_tokens(&self) -> Option<TokenStream> {
         if !self.validators.is_empty() {
             let validators = TokenStream::from_iter(self.validators.clone());
-            Some(quote! (#validators))
+            match &self.condition {
+                Some(condition) => Some(quote!(
+                    if #condition {
+                        #validators
+                    }
+                )),
+                None => Some(quote! (#validators)),
+            }
         } else {
             None
         }
@@ -55,3 +78,155 @@ impl<'a, F: Field + Clone> FieldValidators<'a, F> {
         }
     }
 }
+
+/// Whether the item is the field option, like `internal` or `when = "is_business"`.
+pub fn is_field_option(nested_meta: &syn::NestedMeta) -> bool {
+    match nested_meta {
+        syn::NestedMeta::Meta(meta) => meta
+            .path()
+            .get_ident()
+            .is_some_and(|ident| FieldOption::from_str(&ident.to_string()).is_ok()),
+        syn::NestedMeta::Lit(_) => false,
+    }
+}
+
+/// Find the field option, like `#[validate(internal)]`.
+pub fn find_field_option(field: &impl Field, option: FieldOption) -> Option<syn::Meta> {
+    field
+        .attrs()
+        .iter()
+        .filter(|attribute| {
+            attribute.path == parse_quote!(validate) || attribute.path == parse_quote!(serde_valid)
+        })
+        .find_map(|attribute| match parse_validate_meta(attribute) {
+            Ok(syn::Meta::List(list)) => {
+                list.nested
+                    .into_iter()
+                    .find_map(|nested_meta| match nested_meta {
+                        syn::NestedMeta::Meta(meta) if meta.path().is_ident(option.name()) => {
+                            Some(meta)
+                        }
+                        _ => None,
+                    })
+            }
+            _ => None,
+        })
+}
+
+/// Push the error of the field.
+///
+/// The rejected value is kept only with `#[validate(capture_value)]`,
+/// and `#[validate(sensitive)]` hides it and the params which may reveal it.
+pub fn field_error_tokens(field: &impl Field, error: TokenStream) -> TokenStream {
+    let mut error = quote!(#error);
+    if find_field_option(field, FieldOption::CaptureValue).is_none() {
+        error = quote!((#error).without_value());
+    }
+    if find_field_option(field, FieldOption::Sensitive).is_some() {
+        error = quote!((#error).redacted());
+    }
+    quote!(::serde_valid::validation::FieldError::from_error(#error))
+}
+
+/// Push the error of the composited error params `__composited_error_params`.
+///
+/// The validations don't keep the rejected value,
+/// so `#[validate(capture_value)]` attaches the value of the field and its items to the params.
+pub fn composited_error_tokens(field: &impl Field, custom_message: TokenStream) -> TokenStream {
+    let field_ident = field.ident();
+    let error_params = if find_field_option(field, FieldOption::CaptureValue).is_some() {
+        quote!(::serde_valid::validation::CaptureValue::capture_value(
+            #field_ident,
+            __composited_error_params
+        ))
+    } else {
+        quote!(__composited_error_params)
+    };
+    field_error_tokens(field, quote!(#error_params.into_error_by(#custom_message)))
+}
+
+/// The condition to run the validations of the field.
+///
+/// `skip_default` skips the validations while the field equals (`PartialEq`) the `#[serde(default)]` value.
+pub fn extract_field_condition(field: &impl Field) -> Result<Option<TokenStream>, crate::Errors> {
+    let field_ident = field.ident();
+
+    match find_field_option(field, FieldOption::SkipDefault) {
+        Some(meta) if !has_serde_default(field.attrs()) => {
+            Err(vec![crate::Error::skip_default_need_serde_default(
+                meta.path(),
+            )])
+        }
+        Some(_) => serde_default_value_tokens(field)
+            .map(|default_value| Some(quote!(#field_ident != &#default_value))),
+        None => Ok(None),
+    }
+}
+
+/// The condition to run the validations of the same `#[validate(...)]` attribute.
+///
+/// - `when = "predicate"` runs the validations only when `predicate(&self)` is true.
+/// - `skip_if = "predicate"` skips the validations when `predicate(&field)` is true.
+pub fn extract_attribute_condition(
+    field: &impl Field,
+    options: &[&syn::NestedMeta],
+) -> Result<Option<TokenStream>, crate::Errors> {
+    let mut errors = vec![];
+    let mut conditions = vec![];
+    let field_ident = field.ident();
+
+    for option in options {
+        let meta = match option {
+            syn::NestedMeta::Meta(meta) => meta,
+            syn::NestedMeta::Lit(_) => continue,
+        };
+        if meta.path().is_ident(FieldOption::When.name()) {
+            match get_predicate_path(meta, FieldOption::When) {
+                Ok(predicate) => conditions.push(quote!(#predicate(self))),
+                Err(predicate_errors) => errors.extend(predicate_errors),
+            }
+        } else if meta.path().is_ident(FieldOption::SkipIf.name()) {
+            match get_predicate_path(meta, FieldOption::SkipIf) {
+                Ok(predicate) => conditions.push(quote!(!#predicate(#field_ident))),
+                Err(predicate_errors) => errors.extend(predicate_errors),
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    match conditions.as_slice() {
+        [] => Ok(None),
+        [head, tail @ ..] => Ok(Some(quote!(#head #(&& #tail)*))),
+    }
+}
+
+/// Whether the option is scoped to its own `#[validate(...)]` attribute, like `when = "is_business"`.
+pub fn is_attribute_condition(nested_meta: &syn::NestedMeta) -> bool {
+    match nested_meta {
+        syn::NestedMeta::Meta(meta) => {
+            meta.path().is_ident(FieldOption::When.name())
+                || meta.path().is_ident(FieldOption::SkipIf.name())
+        }
+        syn::NestedMeta::Lit(_) => false,
+    }
+}
+
+fn get_predicate_path(
+    meta: &syn::Meta,
+    option: FieldOption,
+) -> Result<syn::ExprPath, crate::Errors> {
+    match meta {
+        syn::Meta::NameValue(name_value) => {
+            let lit_str = get_str(&name_value.lit)?;
+            lit_str
+                .parse::<syn::ExprPath>()
+                .map_err(|_| vec![crate::Error::path_parse_error(lit_str)])
+        }
+        _ => Err(vec![crate::Error::validate_meta_name_value_need_value(
+            meta.path(),
+            option.name(),
+        )]),
+    }
+}
@@ -1,5 +1,7 @@
 mod custom;
 mod enumerate;
+mod required;
 
-pub use custom::extract_generic_custom_validator;
+pub use custom::{extract_generic_custom_validator, VALIDATOR_COMPAT_MARKER};
 pub use enumerate::extract_generic_enumerate_validator;
+pub use required::extract_generic_required_validator;
@@ -1,12 +1,20 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::Validator;
+use crate::validate::common::CustomMessageToken;
+use crate::validate::{field_error_tokens, Validator};
 use proc_macro2::TokenStream;
 use quote::quote;
 
+/// The marker which the `validator` crate compatible `custom = "function"` adds.
+///
+/// The function of the `validator` crate returns `validator::ValidationError`,
+/// so its error is converted into the custom error by the message.
+pub const VALIDATOR_COMPAT_MARKER: &str = "__validator_compat";
+
 pub fn extract_generic_custom_validator(
     field: &impl Field,
     syn::MetaList { path, nested, .. }: &syn::MetaList,
+    custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
     let field_name = field.name();
@@ -15,19 +23,47 @@ pub fn extract_generic_custom_validator(
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
 
+    let is_validator_compat = matches!(
+        nested.iter().nth(1),
+        Some(syn::NestedMeta::Meta(syn::Meta::Path(marker))) if nested.len() == 2 && marker.is_ident(VALIDATOR_COMPAT_MARKER)
+    );
     let custom_fn_name = match nested.len() {
         0 => Err(crate::Error::validate_custom_need_item(path)),
         1 => extract_custom_fn_name(&nested[0]),
+        2 if is_validator_compat => extract_custom_fn_name(&nested[0]),
         _ => Err(crate::Error::validate_custom_tail_error(nested)),
     }
     .map_err(|error| vec![error])?;
 
+    let error = if is_validator_compat {
+        quote!(::serde_valid::validation::Error::Custom(
+            ::std::string::ToString::to_string(&__error)
+        ))
+    } else {
+        quote!(__error)
+    };
+
+    let field_error = match custom_message.into_error_token(error.clone()) {
+        Some(error) => field_error_tokens(field, error),
+        // The error of `#[serde_valid(error = MyError)]` is the user's own, so it is kept as it is.
+        None if field.has_custom_error_type() => {
+            quote!(::serde_valid::validation::FieldError::Single(
+                ::std::convert::Into::into(#error),
+            ))
+        }
+        // The error may keep the rejected value, which is dropped or redacted like the built-in ones.
+        None => field_error_tokens(
+            field,
+            quote!(::std::convert::Into::<::serde_valid::validation::Error>::into(#error)),
+        ),
+    };
+
     Ok(quote!(
         if let Err(__error) = #custom_fn_name(#field_ident) {
             #errors
                 .entry(#rename)
                 .or_default()
-                .push(__error);
+                .push(#field_error);
         };
     ))
 }
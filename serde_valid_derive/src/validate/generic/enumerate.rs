@@ -1,6 +1,7 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
 use crate::validate::common::CustomMessageToken;
+use crate::validate::composited_error_tokens;
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -29,6 +30,7 @@ fn inner_extract_generic_enumerate_validator(
     let errors = field.errors_variable();
     let enumerate = get_enumerate(item_list)?;
     let custom_message = custom_message.into_token();
+    let field_error = composited_error_tokens(field, custom_message);
 
     Ok(quote!(
         if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedEnumerate::validate_composited_enumerate(
@@ -40,14 +42,14 @@ fn inner_extract_generic_enumerate_validator(
             #errors
                 .entry(#rename)
                 .or_default()
-                .push(__composited_error_params.into_error_by(#custom_message));
+                .push(#field_error);
         }
     ))
 }
 
 fn get_enumerate(
     syn::MetaList { path, nested, .. }: &syn::MetaList,
-) -> Result<Lits, crate::Errors> {
+) -> Result<Lits<'_>, crate::Errors> {
     let mut errors = vec![];
     let mut enumerate = Lits::new();
 
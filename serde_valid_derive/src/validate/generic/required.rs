@@ -0,0 +1,36 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::{common::CustomMessageToken, Validator};
+use quote::quote;
+
+/// Required validation, like `required`.
+///
+/// This checks the field itself, so the `Option` is not skipped as other validations.
+pub fn extract_generic_required_validator(
+    field: &impl Field,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let custom_message = custom_message.into_token();
+
+    quote!(
+        if let Err(__error_params) = ::serde_valid::ValidateRequired::validate_required(
+            #field_ident,
+        ) {
+            use ::serde_valid::validation::{IntoError, ToDefaultMessage};
+
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(::serde_valid::validation::FieldError::from_error(
+                    ::serde_valid::validation::Composited::Single(__error_params)
+                        .into_error_by(#custom_message)
+                ));
+        }
+    )
+}
@@ -6,9 +6,12 @@ mod nested_meta_path;
 
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
+use crate::validate::common::{CustomMessageToken, MetaListValidation};
 use crate::validate::Validator;
 use meta_path::extract_validator_from_meta_path;
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
 
+pub use self::meta_list::extract_custom_message_tokens_from_items;
 use self::meta_list::extract_validator_from_meta_list;
 
 pub fn extract_meta_validator(
@@ -16,11 +19,13 @@ pub fn extract_meta_validator(
     attribute: &syn::Attribute,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
-    match attribute.parse_meta() {
+    match parse_validate_meta(attribute) {
         Ok(syn::Meta::List(list)) => {
             extract_validator_from_meta_list(field, attribute, &list, rename_map)
         }
-        Ok(syn::Meta::Path(_)) => extract_validator_from_meta_path(field, rename_map),
+        Ok(syn::Meta::Path(_)) => {
+            extract_validator_from_meta_path(field, CustomMessageToken::default(), rename_map)
+        }
         Ok(syn::Meta::NameValue(name_value)) => {
             Err(vec![crate::Error::validate_meta_name_value_not_support(
                 name_value,
@@ -31,3 +36,62 @@ pub fn extract_meta_validator(
         )]),
     }
 }
+
+/// Parse the `#[validate(...)]` attribute.
+///
+/// syn only accepts literals as the value of a name value meta,
+/// so `pattern = MY_REGEX` is read as `pattern(MY_REGEX)` (and so is `not_pattern`).
+pub fn parse_validate_meta(attribute: &syn::Attribute) -> syn::Result<syn::Meta> {
+    let mut attribute = attribute.clone();
+    attribute.tokens = attribute
+        .tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                let mut new_group =
+                    Group::new(Delimiter::Parenthesis, rewrite_path_values(group.stream()));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group)
+            }
+            _ => token,
+        })
+        .collect();
+    attribute.parse_meta()
+}
+
+fn rewrite_path_values(tokens: TokenStream) -> TokenStream {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut rewritten = vec![];
+    for (index, item) in tokens
+        .split(|token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','))
+        .enumerate()
+    {
+        if index > 0 {
+            rewritten.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+        }
+        match item {
+            [TokenTree::Ident(name), TokenTree::Punct(eq), value @ ..]
+                if (name == MetaListValidation::Pattern.name()
+                    || name == MetaListValidation::NotPattern.name())
+                    && eq.as_char() == '='
+                    && is_path(value) =>
+            {
+                let mut group = Group::new(Delimiter::Parenthesis, value.iter().cloned().collect());
+                group.set_span(eq.span());
+                rewritten.push(TokenTree::Ident(name.clone()));
+                rewritten.push(TokenTree::Group(group));
+            }
+            _ => rewritten.extend(item.iter().cloned()),
+        }
+    }
+    rewritten.into_iter().collect()
+}
+
+fn is_path(tokens: &[TokenTree]) -> bool {
+    !tokens.is_empty()
+        && tokens.iter().all(|token| match token {
+            TokenTree::Ident(ident) => ident != "true" && ident != "false",
+            TokenTree::Punct(punct) => punct.as_char() == ':',
+            _ => false,
+        })
+}
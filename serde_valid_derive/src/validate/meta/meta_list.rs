@@ -1,10 +1,21 @@
 use super::nested_meta_list::extract_validator_from_nested_meta_list;
 use super::nested_meta_name_value::extract_validator_from_nested_meta_name_value;
 use super::nested_meta_path::extract_validator_from_nested_meta_path;
+use crate::error::into_warnings_tokens;
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::common::{extract_custom_message_tokens, CustomMessageToken};
-use crate::validate::Validator;
+use crate::validate::common::{
+    extract_custom_message_tokens, extract_severity, find_value_placeholder, is_attribute_option,
+    is_custom_message, CustomMessageToken, FieldOption, Severity,
+};
+use crate::validate::compat::expand_compat_validation;
+use crate::validate::{
+    extract_attribute_condition, find_field_option, is_attribute_condition, is_field_option,
+    Validator,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::iter::FromIterator;
 
 pub fn extract_validator_from_meta_list(
     field: &impl Field,
@@ -13,28 +24,96 @@ pub fn extract_validator_from_meta_list(
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
     let mut errors = vec![];
-    let custom_message = match nested.len() {
-        0..=1 => CustomMessageToken::default(),
-        2 => match extract_custom_message_tokens(&nested[1]) {
+    // The rejected value is dropped unless it is captured, so `{value}` would be empty.
+    if find_field_option(field, FieldOption::CaptureValue).is_none() {
+        errors.extend(
+            nested
+                .iter()
+                .filter_map(find_value_placeholder)
+                .map(crate::Error::message_value_need_capture_value),
+        );
+    }
+    // The field options are read with the other attributes of the field.
+    let (options, nested): (Vec<_>, Vec<_>) = nested.iter().partition(|meta| is_field_option(meta));
+    // `when` and `skip_if` are the conditions of the validations in this attribute only.
+    let conditions = options
+        .iter()
+        .copied()
+        .filter(|meta| is_attribute_condition(meta))
+        .collect::<Vec<_>>();
+    let condition = match extract_attribute_condition(field, &conditions) {
+        Ok(condition) => condition,
+        Err(condition_errors) => {
+            errors.extend(condition_errors);
+            None
+        }
+    };
+    let (attribute_options, nested): (Vec<_>, Vec<_>) = nested
+        .into_iter()
+        .partition(|meta| is_attribute_option(meta));
+    let severity = match extract_severity(&attribute_options) {
+        Ok(severity) => severity,
+        Err(severity_errors) => {
+            errors.extend(severity_errors);
+            Severity::Error
+        }
+    };
+    let (validations, messages): (Vec<_>, Vec<_>) = nested
+        .into_iter()
+        .partition(|meta| !is_custom_message(meta));
+    let mut validations_buffer = vec![];
+    for meta in validations {
+        match expand_compat_validation(field, meta) {
+            Ok(expanded) => validations_buffer.extend(expanded),
+            Err(compat_errors) => errors.extend(compat_errors),
+        }
+    }
+    let validations = validations_buffer;
+
+    // The message of the single validation form is allowed, like `#[validate(maximum = 10, message = "...")]`.
+    let custom_message = if validations.len() <= 1 {
+        match extract_custom_message_tokens_from_items(&messages) {
             Ok(custom_message) => custom_message,
-            Err(message_fn_errors) => {
-                errors.extend(message_fn_errors);
+            Err(message_errors) => {
+                errors.extend(message_errors);
                 CustomMessageToken::default()
             }
-        },
-        _ => {
-            for meta in nested.iter().skip(1) {
-                errors.push(crate::Error::too_many_list_items(meta));
-            }
-            CustomMessageToken::default()
         }
+    } else {
+        for meta in &messages {
+            errors.push(crate::Error::message_for_multiple_validations(meta));
+        }
+        CustomMessageToken::default()
     };
 
-    if !nested.is_empty() {
-        let meta_item = &nested[0];
-        match meta_item {
+    if validations.is_empty() {
+        if errors.is_empty() && messages.is_empty() && !options.is_empty() {
+            if let [syn::NestedMeta::Meta(meta), ..] = conditions.as_slice() {
+                let name = meta
+                    .path()
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                return Err(vec![crate::Error::condition_without_validations(
+                    meta.path(),
+                    &name,
+                )]);
+            }
+            return Ok(Validator::new());
+        }
+        if errors.is_empty() {
+            errors.push(crate::Error::validate_type_required_error(attribute));
+        }
+        return Err(errors);
+    }
+
+    let mut custom_message = Some(custom_message);
+    let mut validators = vec![];
+    for meta_item in &validations {
+        let validator = match meta_item {
             syn::NestedMeta::Meta(meta) => {
-                let validator = match meta {
+                let custom_message = custom_message.take().unwrap_or_default();
+                match meta {
                     syn::Meta::Path(path) => extract_validator_from_nested_meta_path(
                         field,
                         path,
@@ -43,6 +122,7 @@ pub fn extract_validator_from_meta_list(
                     ),
                     syn::Meta::List(list) => extract_validator_from_nested_meta_list(
                         field,
+                        attribute,
                         list,
                         custom_message,
                         rename_map,
@@ -56,28 +136,48 @@ pub fn extract_validator_from_meta_list(
                             rename_map,
                         )
                     }
-                };
-                match validator {
-                    Ok(validator) => {
-                        if errors.is_empty() {
-                            Ok(validator)
-                        } else {
-                            Err(errors)
-                        }
-                    }
-                    Err(validator_errors) => {
-                        errors.extend(validator_errors);
-                        Err(errors)
-                    }
                 }
             }
             syn::NestedMeta::Lit(lit) => {
-                errors.push(crate::Error::validate_meta_literal_not_support(lit));
-                Err(errors)
+                Err(vec![crate::Error::validate_meta_literal_not_support(lit)])
             }
+        };
+        match validator {
+            Ok(validator) => validators.push(validator),
+            Err(validator_errors) => errors.extend(validator_errors),
         }
-    } else {
-        errors.push(crate::Error::validate_type_required_error(attribute));
-        Err(errors)
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let validators = TokenStream::from_iter(validators);
+    let validators = match severity {
+        Severity::Error => validators,
+        // The warnings are collected apart from the errors, so that they don't fail the validation.
+        Severity::Warning => into_warnings_tokens(&field.errors_variables(), validators),
+    };
+    Ok(match condition {
+        Some(condition) => quote!(
+            if #condition {
+                #validators
+            }
+        ),
+        None => validators,
+    })
+}
+
+/// Extract the custom message from the message items, which allow only one item.
+pub fn extract_custom_message_tokens_from_items(
+    messages: &[&syn::NestedMeta],
+) -> Result<CustomMessageToken, crate::Errors> {
+    match messages {
+        [] => Ok(CustomMessageToken::default()),
+        [message] => extract_custom_message_tokens(message),
+        [_, tail @ ..] => Err(tail
+            .iter()
+            .map(|meta| crate::Error::too_many_list_items(meta))
+            .collect()),
     }
 }
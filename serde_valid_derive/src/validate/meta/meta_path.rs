@@ -1,18 +1,26 @@
-use crate::serde::rename::RenameMap;
+use crate::error::into_warnings_tokens;
+use crate::serde::{flatten::is_serde_flatten, rename::RenameMap};
 use crate::types::Field;
-use crate::validate::Validator;
+use crate::validate::common::{get_members, CustomMessageToken};
+use crate::validate::{field_error_tokens, Validator};
 use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn extract_validator_from_meta_path(
     field: &impl Field,
+    custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
-    Ok(inner_extract_validator_from_meta_path(field, rename_map))
+    Ok(inner_extract_validator_from_meta_path(
+        field,
+        custom_message,
+        rename_map,
+    ))
 }
 
 fn inner_extract_validator_from_meta_path(
     field: &impl Field,
+    custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> TokenStream {
     let field_ident = field.ident();
@@ -21,23 +29,201 @@ fn inner_extract_validator_from_meta_path(
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
 
-    quote!(
-        if let Err(__inner_errors) = #field_ident.validate() {
-            match __inner_errors {
-                ::serde_valid::validation::Errors::Object(__object_errors) => {
-                    #errors.entry(#rename).or_default().push(
-                        ::serde_valid::validation::Error::Properties(__object_errors)
-                    );
-                }
-                ::serde_valid::validation::Errors::Array(__array_errors) => {
-                    #errors.entry(#rename).or_default().push(
-                        ::serde_valid::validation::Error::Items(__array_errors)
-                    );
-                }
-                ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
-                    #errors.entry(#rename).or_default().extend(__new_type_errors);
+    // The properties of the flattened struct are serialized into the parent,
+    // so its errors are merged into the parent errors.
+    if is_serde_flatten(field.attrs()) {
+        // The flattened struct has no property of its own, so the custom message is the error of the parent.
+        if let Some(error) = custom_message.into_error_token(quote!(__inner_errors)) {
+            return report_errors_tokens(
+                field,
+                quote!(#field_ident.validate_with_warnings()),
+                quote!(__rule_vec_errors.push(::std::convert::Into::into(#error));),
+            );
+        }
+
+        let push_errors = quote!(
+                match __inner_errors.map_errors(::std::convert::Into::into) {
+                    ::serde_valid::validation::Errors::Object(__object_errors) => {
+                        __rule_vec_errors.extend(__object_errors.errors);
+                        let __properties = __object_errors
+                            .properties
+                            .into_iter()
+                            .map(|(__key, __errors)| (false, __key, __errors));
+                        let __internal = (*__object_errors.internal)
+                            .into_iter()
+                            .map(|(__key, __errors)| (true, __key, __errors));
+                        for (__is_internal, __key, __property_errors) in __properties.chain(__internal) {
+                            let __parent_errors = if __is_internal {
+                                __internal_vec_errors_map.entry(__key).or_default()
+                            } else {
+                                #errors.entry(__key).or_default()
+                            };
+                            match __property_errors {
+                                ::serde_valid::validation::Errors::Object(__object_errors) => {
+                                    __parent_errors.extend(
+                                        __object_errors
+                                            .errors
+                                            .into_iter()
+                                            .map(::serde_valid::validation::FieldError::Single)
+                                    );
+                                    if !(__object_errors.properties.is_empty()
+                                        && __object_errors.internal.is_empty())
+                                    {
+                                        __parent_errors.push(
+                                            ::serde_valid::validation::FieldError::Properties(
+                                                ::serde_valid::validation::ObjectErrors::new(
+                                                    vec![],
+                                                    __object_errors.properties,
+                                                )
+                                                .with_internal(*__object_errors.internal)
+                                            )
+                                        );
+                                    }
+                                }
+                                ::serde_valid::validation::Errors::Array(__array_errors) => {
+                                    __parent_errors.extend(
+                                        __array_errors
+                                            .errors
+                                            .into_iter()
+                                            .map(::serde_valid::validation::FieldError::Single)
+                                    );
+                                    if !(__array_errors.items.is_empty()
+                                        && __array_errors.keyed_items.is_empty())
+                                    {
+                                        __parent_errors.push(
+                                            ::serde_valid::validation::FieldError::Items(
+                                                ::serde_valid::validation::ArrayErrors::new(
+                                                    vec![],
+                                                    __array_errors.items,
+                                                )
+                                                .with_keyed_items(*__array_errors.keyed_items)
+                                            )
+                                        );
+                                    }
+                                }
+                                ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                                    __parent_errors.extend(
+                                        __new_type_errors
+                                            .into_iter()
+                                            .map(::serde_valid::validation::FieldError::Single)
+                                    );
+                                }
+                            }
+                        }
+                    }
+                    ::serde_valid::validation::Errors::Array(__array_errors) => {
+                        #errors.entry(#rename).or_default().push(
+                            ::serde_valid::validation::FieldError::Items(__array_errors)
+                        );
+                    }
+                    ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                        #errors.entry(#rename).or_default().extend(
+                            __new_type_errors
+                                .into_iter()
+                                .map(::serde_valid::validation::FieldError::Single)
+                        );
+                    }
                 }
+        );
+        return report_errors_tokens(
+            field,
+            quote!(#field_ident.validate_with_warnings()),
+            push_errors,
+        );
+    }
+
+    nested_errors_tokens(
+        field,
+        quote!(#field_ident.validate_with_warnings()),
+        custom_message,
+        rename_map,
+    )
+}
+
+/// `key = "id"` validates the items like `#[validate]`,
+/// but the errors of the items are keyed by the field of the item instead of the index.
+pub fn extract_keyed_validator_from_meta_name_value(
+    field: &impl Field,
+    validation_value: &syn::Lit,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+    let members = get_members(validation_value)?;
+
+    Ok(nested_errors_tokens(
+        field,
+        quote!(::serde_valid::ValidateKeyedItems::validate_keyed_items_with_warnings(
+            #field_ident,
+            |__item| ::std::string::ToString::to_string(&__item #(.#members)*),
+        )),
+        custom_message,
+        rename_map,
+    ))
+}
+
+fn nested_errors_tokens(
+    field: &impl Field,
+    validation: TokenStream,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> TokenStream {
+    let field_name = field.name();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+
+    // The custom message replaces the errors of the nested value with the single error.
+    if let Some(error) = custom_message.into_error_token(quote!(__inner_errors)) {
+        let field_error = field_error_tokens(field, error);
+        return report_errors_tokens(
+            field,
+            validation,
+            quote!(#errors.entry(#rename).or_default().push(#field_error);),
+        );
+    }
+
+    let push_errors = quote!(
+        match __inner_errors.map_errors(::std::convert::Into::into) {
+            ::serde_valid::validation::Errors::Object(__object_errors) => {
+                #errors.entry(#rename).or_default().push(
+                    ::serde_valid::validation::FieldError::Properties(__object_errors)
+                );
+            }
+            ::serde_valid::validation::Errors::Array(__array_errors) => {
+                #errors.entry(#rename).or_default().push(
+                    ::serde_valid::validation::FieldError::Items(__array_errors)
+                );
+            }
+            ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                #errors.entry(#rename).or_default().extend(
+                    __new_type_errors
+                        .into_iter()
+                        .map(::serde_valid::validation::FieldError::Single)
+                );
             }
         }
-    )
+    );
+
+    report_errors_tokens(field, validation, push_errors)
+}
+
+/// Push the errors of the nested report into the errors,
+/// and the warnings of the nested report into the warnings.
+fn report_errors_tokens(
+    field: &impl Field,
+    validation: TokenStream,
+    push_errors: TokenStream,
+) -> TokenStream {
+    let push_warnings = into_warnings_tokens(&field.errors_variables(), push_errors.clone());
+
+    quote!({
+        let __report = #validation;
+        if let Some(__inner_errors) = __report.errors {
+            #push_errors
+        }
+        if let Some(__inner_errors) = __report.warnings {
+            #push_warnings
+        }
+    })
 }
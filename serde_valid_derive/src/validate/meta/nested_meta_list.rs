@@ -1,30 +1,131 @@
+use super::meta_list::extract_custom_message_tokens_from_items;
+use super::nested_meta_name_value::extract_validator_from_nested_meta_name_value;
+use super::nested_meta_path::extract_validator_from_nested_meta_path;
 use crate::serde::rename::RenameMap;
 use crate::types::{Field, SingleIdentPath};
-use crate::validate::common::{CustomMessageToken, MetaListValidation};
+use crate::validate::array::{
+    extract_array_count_where_validator, extract_array_sum_validator,
+    extract_array_unique_by_validator, extract_array_unique_items_list_validator,
+};
+use crate::validate::common::{
+    is_custom_message, CustomMessageToken, MetaListValidation, MetaNameValueValidation,
+    MetaPathValidation,
+};
 use crate::validate::generic::{
     extract_generic_custom_validator, extract_generic_enumerate_validator,
 };
+use crate::validate::string::{
+    extract_string_not_pattern_list_validator, extract_string_pattern_list_validator,
+};
 use crate::validate::Validator;
 use std::str::FromStr;
 
 pub fn extract_validator_from_nested_meta_list(
     field: &impl Field,
+    attribute: &syn::Attribute,
     validation_list: &syn::MetaList,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
     let syn::MetaList {
         path: validation_name,
-        ..
+        paren_token,
+        nested,
     } = validation_list;
     let validation_ident = SingleIdentPath::new(validation_name).ident();
+    let validation_label = validation_ident.to_string();
 
-    match MetaListValidation::from_str(&validation_ident.to_string()) {
+    // The message in the list, like `maximum(10, message = "...")`.
+    let (items, messages): (Vec<_>, Vec<_>) =
+        nested.iter().partition(|meta| !is_custom_message(meta));
+    let custom_message = if messages.is_empty() {
+        custom_message
+    } else if custom_message.is_default() {
+        extract_custom_message_tokens_from_items(&messages)?
+    } else {
+        return Err(messages
+            .into_iter()
+            .map(crate::Error::too_many_list_items)
+            .collect());
+    };
+    let validation_list = &syn::MetaList {
+        path: validation_name.clone(),
+        paren_token: *paren_token,
+        nested: items.iter().map(|&item| item.clone()).collect(),
+    };
+
+    match MetaListValidation::from_str(&validation_label) {
         Ok(MetaListValidation::Enumerate) => {
             extract_generic_enumerate_validator(field, validation_list, custom_message, rename_map)
         }
         Ok(MetaListValidation::Custom) => {
-            extract_generic_custom_validator(field, validation_list, rename_map)
+            extract_generic_custom_validator(field, validation_list, custom_message, rename_map)
+        }
+        Ok(MetaListValidation::Pattern) => extract_string_pattern_list_validator(
+            field,
+            validation_list,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaListValidation::NotPattern) => extract_string_not_pattern_list_validator(
+            field,
+            validation_list,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaListValidation::UniqueItems) => extract_array_unique_items_list_validator(
+            field,
+            validation_list,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaListValidation::UniqueBy) => {
+            extract_array_unique_by_validator(field, validation_list, custom_message, rename_map)
+        }
+        Ok(MetaListValidation::Sum) => {
+            extract_array_sum_validator(field, validation_list, custom_message, rename_map)
+        }
+        Ok(MetaListValidation::CountWhere) => {
+            extract_array_count_where_validator(field, validation_list, custom_message, rename_map)
+        }
+        Err(_) if MetaNameValueValidation::from_str(&validation_label).is_ok() => {
+            match items.as_slice() {
+                [syn::NestedMeta::Lit(lit)] => extract_validator_from_nested_meta_name_value(
+                    field,
+                    attribute,
+                    &syn::MetaNameValue {
+                        path: validation_name.clone(),
+                        eq_token: Default::default(),
+                        lit: lit.clone(),
+                    },
+                    custom_message,
+                    rename_map,
+                ),
+                [] => Err(vec![crate::Error::validate_meta_name_value_need_value(
+                    validation_name,
+                    &validation_label,
+                )]),
+                [syn::NestedMeta::Meta(meta)] => Err(vec![crate::Error::literal_only(meta)]),
+                [_, tail @ ..] => Err(tail
+                    .iter()
+                    .map(|&meta| crate::Error::too_many_list_items(meta))
+                    .collect()),
+            }
+        }
+        Err(_) if MetaPathValidation::from_str(&validation_label).is_ok() => {
+            if items.is_empty() {
+                extract_validator_from_nested_meta_path(
+                    field,
+                    validation_name,
+                    custom_message,
+                    rename_map,
+                )
+            } else {
+                Err(items
+                    .into_iter()
+                    .map(crate::Error::too_many_list_items)
+                    .collect())
+            }
         }
         Err(unknown) => Err(vec![crate::Error::validate_unknown_type(
             validation_name,
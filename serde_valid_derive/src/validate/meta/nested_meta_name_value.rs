@@ -1,7 +1,9 @@
+use super::meta_path::extract_keyed_validator_from_meta_name_value;
 use crate::serde::rename::RenameMap;
 use crate::types::{Field, SingleIdentPath};
 use crate::validate::array::{
     extract_array_max_items_validator, extract_array_min_items_validator,
+    extract_array_sorted_by_validator, extract_array_unique_by_validator,
 };
 use crate::validate::common::{CustomMessageToken, MetaNameValueValidation};
 use crate::validate::numeric::{
@@ -13,8 +15,11 @@ use crate::validate::object::{
     extract_object_max_properties_validator, extract_object_min_properties_validator,
 };
 use crate::validate::string::{
-    extract_string_max_length_validator, extract_string_min_length_validator,
-    extract_string_pattern_validator,
+    extract_string_charset_validator, extract_string_contains_validator,
+    extract_string_ends_with_validator, extract_string_max_length_validator,
+    extract_string_min_length_validator, extract_string_not_contains_validator,
+    extract_string_not_pattern_validator, extract_string_pattern_validator,
+    extract_string_starts_with_validator,
 };
 use crate::validate::Validator;
 use std::str::FromStr;
@@ -87,6 +92,54 @@ pub fn extract_validator_from_nested_meta_name_value(
         Ok(MetaNameValueValidation::Pattern) => {
             extract_string_pattern_validator(field, validation_value, custom_message, rename_map)
         }
+        Ok(MetaNameValueValidation::NotPattern) => extract_string_not_pattern_validator(
+            field,
+            validation_value,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaNameValueValidation::Contains) => {
+            extract_string_contains_validator(field, validation_value, custom_message, rename_map)
+        }
+        Ok(MetaNameValueValidation::NotContains) => extract_string_not_contains_validator(
+            field,
+            validation_value,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaNameValueValidation::StartsWith) => extract_string_starts_with_validator(
+            field,
+            validation_value,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaNameValueValidation::EndsWith) => {
+            extract_string_ends_with_validator(field, validation_value, custom_message, rename_map)
+        }
+        Ok(MetaNameValueValidation::Charset) => {
+            extract_string_charset_validator(field, validation_value, custom_message, rename_map)
+        }
+        Ok(MetaNameValueValidation::Key) => extract_keyed_validator_from_meta_name_value(
+            field,
+            validation_value,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaNameValueValidation::UniqueBy) => extract_array_unique_by_validator(
+            field,
+            &syn::MetaList {
+                path: validation_name.clone(),
+                paren_token: Default::default(),
+                nested: [syn::NestedMeta::Lit(validation_value.clone())]
+                    .into_iter()
+                    .collect(),
+            },
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaNameValueValidation::SortedBy) => {
+            extract_array_sorted_by_validator(field, validation_value, custom_message, rename_map)
+        }
         Err(unknown) => Err(vec![crate::Error::validate_unknown_type(
             validation_name,
             &unknown,
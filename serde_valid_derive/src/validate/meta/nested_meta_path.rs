@@ -1,8 +1,23 @@
+use super::meta_path::extract_validator_from_meta_path;
 use crate::serde::rename::RenameMap;
 use crate::types::{Field, SingleIdentPath};
-use crate::validate::array::extract_array_unique_items_validator;
+use crate::validate::array::{
+    extract_array_sorted_validator, extract_array_strictly_increasing_validator,
+    extract_array_unique_items_validator,
+};
 use crate::validate::common::{
-    CustomMessageToken, MetaListValidation, MetaNameValueValidation, MetaPathValidation,
+    AttributeOption, CompatValidation, CustomMessageToken, FieldOption, MetaListValidation,
+    MetaNameValueValidation, MetaPathValidation,
+};
+use crate::validate::generic::extract_generic_required_validator;
+use crate::validate::numeric::{
+    extract_numeric_finite_validator, extract_numeric_not_nan_validator,
+};
+use crate::validate::string::{
+    extract_string_alphanumeric_validator, extract_string_ascii_validator,
+    extract_string_ean_validator, extract_string_email_validator, extract_string_iban_validator,
+    extract_string_isbn_validator, extract_string_luhn_validator,
+    extract_string_not_blank_validator, extract_string_url_validator,
 };
 use crate::validate::Validator;
 use std::str::FromStr;
@@ -23,6 +38,77 @@ pub fn extract_validator_from_nested_meta_path(
             custom_message,
             rename_map,
         )),
+        Ok(MetaPathValidation::Sorted) => Ok(extract_array_sorted_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::StrictlyIncreasing) => Ok(
+            extract_array_strictly_increasing_validator(field, custom_message, rename_map),
+        ),
+        Ok(MetaPathValidation::Finite) => Ok(extract_numeric_finite_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::NotNan) => Ok(extract_numeric_not_nan_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::NotBlank) => Ok(extract_string_not_blank_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Ascii) => Ok(extract_string_ascii_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Alphanumeric) => Ok(extract_string_alphanumeric_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Luhn) => Ok(extract_string_luhn_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Iban) => Ok(extract_string_iban_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Isbn) => Ok(extract_string_isbn_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Ean) => Ok(extract_string_ean_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Email) => Ok(extract_string_email_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Url) => Ok(extract_string_url_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
+        Ok(MetaPathValidation::Nested) => {
+            extract_validator_from_meta_path(field, custom_message, rename_map)
+        }
+        Ok(MetaPathValidation::Required) => Ok(extract_generic_required_validator(
+            field,
+            custom_message,
+            rename_map,
+        )),
         Err(unknown) => {
             let error = if MetaNameValueValidation::from_str(&validation_name).is_ok() {
                 crate::Error::validate_meta_name_value_need_value(validation, &validation_name)
@@ -35,6 +121,9 @@ pub fn extract_validator_from_nested_meta_path(
                     &(MetaPathValidation::iter().map(|x| x.name()))
                         .chain(MetaNameValueValidation::iter().map(|x| x.name()))
                         .chain(MetaListValidation::iter().map(|x| x.name()))
+                        .chain(CompatValidation::iter().map(|x| x.name()))
+                        .chain(FieldOption::iter().map(|x| x.name()))
+                        .chain(AttributeOption::iter().map(|x| x.name()))
                         .collect::<Vec<_>>(),
                 )
             };
@@ -1,6 +1,8 @@
+mod float;
 mod multiple_of;
 mod range;
 
+pub use float::{extract_numeric_finite_validator, extract_numeric_not_nan_validator};
 pub use multiple_of::extract_numeric_multiple_of_validator;
 pub use range::{
     extract_numeric_exclusive_maximum_validator, extract_numeric_exclusive_minimum_validator,
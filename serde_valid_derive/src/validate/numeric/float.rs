@@ -0,0 +1,53 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::composited_error_tokens;
+use crate::validate::{common::CustomMessageToken, Validator};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Float sanity validation.
+///
+/// Rejects the NaN and the infinite values, which every range comparison silently accepts or rejects.
+macro_rules! extract_numeric_float_validator{
+    ($ErrorType:ident) => {
+        paste::paste! {
+            pub fn [<extract_numeric_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> Validator {
+                [<inner_extract_numeric_ $ErrorType:snake _validator>](field, custom_message, rename_map)
+            }
+
+            fn [<inner_extract_numeric_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> TokenStream {
+                let field_name = field.name();
+                let field_ident = field.ident();
+                let field_key = field.key();
+                let rename = rename_map.get(field_name).unwrap_or(&field_key);
+                let errors = field.errors_variable();
+                let custom_message = custom_message.into_token();
+                let field_error = composited_error_tokens(field, custom_message);
+
+                quote!(
+                    if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
+                        #field_ident,
+                    ) {
+                        use ::serde_valid::validation::{IntoError, ToDefaultMessage};
+
+                        #errors
+                            .entry(#rename)
+                            .or_default()
+                            .push(#field_error);
+                    }
+                )
+            }
+        }
+    }
+}
+
+extract_numeric_float_validator!(Finite);
+extract_numeric_float_validator!(NotNan);
@@ -1,6 +1,7 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::common::{get_numeric, CustomMessageToken};
+use crate::validate::common::{check_numeric_type, get_numeric, CustomMessageToken};
+use crate::validate::composited_error_tokens;
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -26,7 +27,12 @@ fn inner_extract_numeric_multiple_of_validator(
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
     let multiple_of = get_numeric(validation_value)?;
+    check_numeric_type(validation_value, &multiple_of, field.ty())?;
+    if multiple_of.is_zero() {
+        return Err(vec![crate::Error::multiple_of_zero(validation_value)]);
+    }
     let custom_message = custom_message.into_token();
+    let field_error = composited_error_tokens(field, custom_message);
 
     Ok(quote!(
         if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedMultipleOf::validate_composited_multiple_of(
@@ -38,7 +44,7 @@ fn inner_extract_numeric_multiple_of_validator(
             #errors
                 .entry(#rename)
                 .or_default()
-                .push(__composited_error_params.into_error_by(#custom_message));
+                .push(#field_error);
         }
     ))
 }
@@ -1,6 +1,7 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::common::get_numeric;
+use crate::validate::common::get_usize;
+use crate::validate::field_error_tokens;
 use crate::validate::{common::CustomMessageToken, Validator};
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -31,8 +32,12 @@ macro_rules! extract_object_size_validator {
                 let field_key = field.key();
                 let rename = rename_map.get(field_name).unwrap_or(&field_key);
                 let errors = field.errors_variable();
-                let [<$ErrorType:snake>] = get_numeric(validation_value)?;
+                let [<$ErrorType:snake>] = get_usize(validation_value)?;
                 let custom_message = custom_message.into_token();
+                let field_error = field_error_tokens(
+                    field,
+                    quote!(__composited_error_params.into_error_by(#custom_message)),
+                );
 
                 Ok(quote!(
                     if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
@@ -44,7 +49,7 @@ macro_rules! extract_object_size_validator {
                         #errors
                             .entry(#rename)
                             .or_default()
-                            .push(__composited_error_params.into_error_by(#custom_message));
+                            .push(#field_error);
                     }
                 ))
             }
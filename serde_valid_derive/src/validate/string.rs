@@ -1,4 +1,17 @@
+mod content;
 mod length;
 mod pattern;
+pub use content::{
+    extract_string_alphanumeric_validator, extract_string_ascii_validator,
+    extract_string_charset_validator, extract_string_contains_validator,
+    extract_string_ean_validator, extract_string_email_validator,
+    extract_string_ends_with_validator, extract_string_iban_validator,
+    extract_string_isbn_validator, extract_string_luhn_validator,
+    extract_string_not_blank_validator, extract_string_not_contains_validator,
+    extract_string_starts_with_validator, extract_string_url_validator,
+};
 pub use length::{extract_string_max_length_validator, extract_string_min_length_validator};
-pub use pattern::extract_string_pattern_validator;
+pub use pattern::{
+    extract_string_not_pattern_list_validator, extract_string_not_pattern_validator,
+    extract_string_pattern_list_validator, extract_string_pattern_validator,
+};
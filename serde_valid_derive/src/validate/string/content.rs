@@ -0,0 +1,110 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::common::get_str;
+use crate::validate::composited_error_tokens;
+use crate::validate::{common::CustomMessageToken, Validator};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Content validation with the str literal, like `contains = "@"`.
+macro_rules! extract_string_content_validator{
+    ($ErrorType:ident) => {
+        paste::paste! {
+            pub fn [<extract_string_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                validation_value: &syn::Lit,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> Result<Validator, crate::Errors> {
+                [<inner_extract_string_ $ErrorType:snake _validator>](field, validation_value, custom_message, rename_map)
+            }
+
+            fn [<inner_extract_string_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                validation_value: &syn::Lit,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> Result<TokenStream, crate::Errors> {
+                let field_name = field.name();
+                let field_ident = field.ident();
+                let field_key = field.key();
+                let rename = rename_map.get(field_name).unwrap_or(&field_key);
+                let errors = field.errors_variable();
+                let [<$ErrorType:snake>] = get_str(validation_value)?;
+                let custom_message = custom_message.into_token();
+                let field_error = composited_error_tokens(field, custom_message);
+
+                Ok(quote!(
+                    if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
+                        #field_ident,
+                        #[<$ErrorType:snake>],
+                    ) {
+                        use ::serde_valid::validation::{IntoError, ToDefaultMessage};
+
+                        #errors
+                            .entry(#rename)
+                            .or_default()
+                            .push(#field_error);
+                    }
+                ))
+            }
+        }
+    }
+}
+
+/// Content validation without the value, like `not_blank` or `luhn`.
+macro_rules! extract_string_content_path_validator{
+    ($ErrorType:ident) => {
+        paste::paste! {
+            pub fn [<extract_string_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> Validator {
+                [<inner_extract_string_ $ErrorType:snake _validator>](field, custom_message, rename_map)
+            }
+
+            fn [<inner_extract_string_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> TokenStream {
+                let field_name = field.name();
+                let field_ident = field.ident();
+                let field_key = field.key();
+                let rename = rename_map.get(field_name).unwrap_or(&field_key);
+                let errors = field.errors_variable();
+                let custom_message = custom_message.into_token();
+                let field_error = composited_error_tokens(field, custom_message);
+
+                quote!(
+                    if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
+                        #field_ident,
+                    ) {
+                        use ::serde_valid::validation::{IntoError, ToDefaultMessage};
+
+                        #errors
+                            .entry(#rename)
+                            .or_default()
+                            .push(#field_error);
+                    }
+                )
+            }
+        }
+    }
+}
+
+extract_string_content_validator!(Contains);
+extract_string_content_validator!(NotContains);
+extract_string_content_validator!(StartsWith);
+extract_string_content_validator!(EndsWith);
+extract_string_content_validator!(Charset);
+extract_string_content_path_validator!(NotBlank);
+extract_string_content_path_validator!(Ascii);
+extract_string_content_path_validator!(Alphanumeric);
+extract_string_content_path_validator!(Luhn);
+extract_string_content_path_validator!(Iban);
+extract_string_content_path_validator!(Isbn);
+extract_string_content_path_validator!(Ean);
+extract_string_content_path_validator!(Email);
+extract_string_content_path_validator!(Url);
@@ -1,13 +1,13 @@
 use crate::{
     serde::rename::RenameMap,
-    types::Field,
+    types::{Field, SingleIdentPath},
     validate::{
-        common::{get_str, CustomMessageToken},
-        Validator,
+        common::{get_str, CustomMessageToken, PatternOption},
+        composited_error_tokens, Validator,
     },
 };
-use proc_macro2::TokenStream;
 use quote::quote;
+use std::str::FromStr;
 
 pub fn extract_string_pattern_validator(
     field: &impl Field,
@@ -15,40 +15,282 @@ pub fn extract_string_pattern_validator(
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
-    inner_extract_string_pattern_validator(field, validation_value, custom_message, rename_map)
+    let pattern = get_str(validation_value)?;
+    inner_extract_string_pattern_validator(
+        field,
+        PatternSource::Literal(pattern),
+        &[],
+        false,
+        custom_message,
+        rename_map,
+    )
 }
 
-fn inner_extract_string_pattern_validator(
+pub fn extract_string_not_pattern_validator(
     field: &impl Field,
     validation_value: &syn::Lit,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
-) -> Result<TokenStream, crate::Errors> {
+) -> Result<Validator, crate::Errors> {
+    let pattern = get_str(validation_value)?;
+    inner_extract_string_pattern_validator(
+        field,
+        PatternSource::Literal(pattern),
+        &[],
+        true,
+        custom_message,
+        rename_map,
+    )
+}
+
+/// Pattern validation with the options, like `pattern(r"^[a-z]+$", case_insensitive)`.
+///
+/// The pattern can also be the path of the shared regex, like `pattern = MY_REGEX`.
+pub fn extract_string_pattern_list_validator(
+    field: &impl Field,
+    validation_list: &syn::MetaList,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_string_pattern_list_validator(
+        field,
+        validation_list,
+        false,
+        custom_message,
+        rename_map,
+    )
+}
+
+/// Negative pattern validation with the options, like `not_pattern(r"^\s", ecma)`.
+///
+/// The pattern can also be the path of the shared regex, like `not_pattern = MY_REGEX`.
+pub fn extract_string_not_pattern_list_validator(
+    field: &impl Field,
+    validation_list: &syn::MetaList,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_string_pattern_list_validator(
+        field,
+        validation_list,
+        true,
+        custom_message,
+        rename_map,
+    )
+}
+
+enum PatternSource<'a> {
+    Literal(&'a syn::LitStr),
+    Path(&'a syn::Path),
+}
+
+fn inner_extract_string_pattern_list_validator(
+    field: &impl Field,
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    negative: bool,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let mut errors = vec![];
+
+    let source = match nested.first() {
+        Some(syn::NestedMeta::Lit(lit)) => match get_str(lit) {
+            Ok(pattern) => Some(PatternSource::Literal(pattern)),
+            Err(lit_errors) => {
+                errors.extend(lit_errors);
+                None
+            }
+        },
+        Some(syn::NestedMeta::Meta(syn::Meta::Path(regex_path))) => {
+            Some(PatternSource::Path(regex_path))
+        }
+        Some(syn::NestedMeta::Meta(syn::Meta::List(list))) => {
+            errors.push(crate::Error::validate_pattern_need_item(&list.path));
+            None
+        }
+        Some(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))) => {
+            errors.push(crate::Error::meta_name_value_not_support(name_value));
+            None
+        }
+        None => {
+            errors.push(crate::Error::validate_pattern_need_item(path));
+            None
+        }
+    };
+
+    let mut options = vec![];
+    for nested_meta in nested.iter().skip(1) {
+        match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::Path(option_path)) => {
+                let option_ident = SingleIdentPath::new(option_path).ident();
+                match PatternOption::from_str(&option_ident.to_string()) {
+                    Ok(option) => options.push((option, option_path)),
+                    Err(unknown) => errors.push(crate::Error::validate_unknown_type(
+                        option_path,
+                        &unknown,
+                        &PatternOption::iter().map(|x| x.name()).collect::<Vec<_>>(),
+                    )),
+                }
+            }
+            _ => errors.push(crate::Error::too_many_list_items(nested_meta)),
+        }
+    }
+
+    match source {
+        Some(source) if errors.is_empty() => inner_extract_string_pattern_validator(
+            field,
+            source,
+            &options,
+            negative,
+            custom_message,
+            rename_map,
+        ),
+        _ => Err(errors),
+    }
+}
+
+fn inner_extract_string_pattern_validator(
+    field: &impl Field,
+    source: PatternSource,
+    options: &[(PatternOption, &syn::Path)],
+    negative: bool,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
     let field_name = field.name();
     let field_ident = field.ident();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
-    let pattern = get_str(validation_value)?;
-    let pattern_ident = syn::Ident::new(
-        &format!("{}_PATTERN", &field_ident).to_uppercase(),
+    let custom_message = custom_message.into_token();
+    let field_error = composited_error_tokens(field, custom_message);
+
+    let ecma = options
+        .iter()
+        .find(|(option, _)| matches!(option, PatternOption::Ecma))
+        .map(|(_, option_path)| *option_path);
+    if let Some(option_path) = ecma {
+        if cfg!(not(feature = "fancy_regex")) {
+            return Err(vec![crate::Error::ecma_pattern_need_feature(option_path)]);
+        }
+    }
+    let regex_type = if ecma.is_some() {
+        quote!(::serde_valid::export::FancyRegex)
+    } else {
+        quote!(::serde_valid::export::Regex)
+    };
+
+    let mut written_pattern = None;
+    let pattern_tokens = match source {
+        PatternSource::Literal(lit_str) => {
+            let pattern = apply_pattern_options(&lit_str.value(), options);
+            check_pattern(lit_str, &pattern, ecma.is_some())?;
+            if pattern != lit_str.value() {
+                written_pattern = Some(lit_str);
+            }
+            let pattern_ident = syn::Ident::new(
+                &format!(
+                    "{}_{}",
+                    &field_ident,
+                    if negative { "NOT_PATTERN" } else { "PATTERN" }
+                )
+                .to_uppercase(),
+                field_ident.span(),
+            );
+            quote!(
+                static #pattern_ident : ::serde_valid::export::OnceCell<#regex_type> = ::serde_valid::export::OnceCell::new();
+                let __pattern = #pattern_ident.get_or_init(|| #regex_type::new(#pattern).unwrap());
+            )
+        }
+        PatternSource::Path(regex_path) => {
+            if let Some((_, option_path)) = options
+                .iter()
+                .find(|(option, _)| !matches!(option, PatternOption::Ecma))
+            {
+                return Err(vec![crate::Error::pattern_option_need_literal(option_path)]);
+            }
+            quote!(
+                let __pattern: &#regex_type = &#regex_path;
+            )
+        }
+    };
+
+    let validation_name = match (negative, ecma.is_some()) {
+        (false, false) => "pattern",
+        (true, false) => "not_pattern",
+        (false, true) => "ecma_pattern",
+        (true, true) => "ecma_not_pattern",
+    };
+    let validate_trait = syn::Ident::new(
+        &format!(
+            "ValidateComposited{}",
+            validation_name
+                .split('_')
+                .map(|word| word[..1].to_uppercase() + &word[1..])
+                .collect::<String>()
+        ),
+        field_ident.span(),
+    );
+    let validate_method = syn::Ident::new(
+        &format!("validate_composited_{validation_name}"),
         field_ident.span(),
     );
-    let custom_message = custom_message.into_token();
+
+    // The message shows the pattern as written, not the one rewritten by the options.
+    let written_as = written_pattern
+        .map(|lit_str| quote!(let __composited_error_params = __composited_error_params.map(&|params| params.written_as(#lit_str));));
 
     Ok(quote!(
-        static #pattern_ident : ::serde_valid::export::OnceCell<::regex::Regex> = ::serde_valid::export::OnceCell::new();
-        let __pattern = #pattern_ident.get_or_init(|| ::regex::Regex::new(#pattern).unwrap());
-        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedPattern::validate_composited_pattern(
-            #field_ident,
-            __pattern,
-        ) {
-            use ::serde_valid::validation::{IntoError, ToDefaultMessage};
-
-            #errors
-                .entry(#rename)
-                .or_default()
-                .push(__composited_error_params.into_error_by(#custom_message));
+        {
+            #pattern_tokens
+            if let Err(__composited_error_params) = ::serde_valid::validation::#validate_trait::#validate_method(
+                #field_ident,
+                __pattern,
+            ) {
+                use ::serde_valid::validation::{IntoError, ToDefaultMessage};
+                #written_as
+
+                #errors
+                    .entry(#rename)
+                    .or_default()
+                    .push(#field_error);
+            }
         }
     ))
 }
+
+fn apply_pattern_options(pattern: &str, options: &[(PatternOption, &syn::Path)]) -> String {
+    let mut pattern = pattern.to_owned();
+    for (option, _) in options {
+        match option {
+            PatternOption::FullMatch => pattern = format!("^(?:{pattern})$"),
+            PatternOption::CaseInsensitive => pattern = format!("(?i){pattern}"),
+            PatternOption::Ecma => {}
+        }
+    }
+    pattern
+}
+
+fn check_pattern(lit_str: &syn::LitStr, pattern: &str, ecma: bool) -> Result<(), crate::Errors> {
+    let result = if ecma {
+        check_ecma_pattern(pattern)
+    } else {
+        regex::Regex::new(pattern)
+            .map(|_| ())
+            .map_err(|error| error.to_string())
+    };
+    result.map_err(|error| vec![crate::Error::pattern_compile_error(lit_str, &error)])
+}
+
+#[cfg(feature = "fancy_regex")]
+fn check_ecma_pattern(pattern: &str) -> Result<(), String> {
+    fancy_regex::Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|error| error.to_string())
+}
+
+/// `ecma` is rejected before the check without the `fancy_regex` feature.
+#[cfg(not(feature = "fancy_regex"))]
+fn check_ecma_pattern(_pattern: &str) -> Result<(), String> {
+    Ok(())
+}
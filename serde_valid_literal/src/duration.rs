@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+const UNITS: [(&str, u128); 7] = [
+    ("d", 86_400_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("m", 60_000_000_000),
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// Format the duration like `1h30m` or `500ms`.
+pub(crate) fn fmt_duration(
+    duration: &Duration,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let mut nanos = duration.as_nanos();
+    if nanos == 0 {
+        return write!(f, "0s");
+    }
+
+    for (unit, scale) in UNITS {
+        if nanos >= scale {
+            write!(f, "{}{}", nanos / scale, unit)?;
+            nanos %= scale;
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,10 @@
+mod byte_size;
+mod duration;
 mod literal;
 mod number;
 mod pattern;
 
+pub use byte_size::ByteSize;
 pub use literal::Literal;
 pub use number::Number;
 pub use pattern::Pattern;
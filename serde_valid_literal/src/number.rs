@@ -4,8 +4,16 @@ use std::num::{
     NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
     NonZeroU64, NonZeroU8, NonZeroUsize,
 };
+use std::time::Duration;
 
+use crate::ByteSize;
+
+/// Number of the validation limits and the rejected values.
+///
+/// New kinds of limits like [`Duration`] and [`ByteSize`] may be added,
+/// so matches on this enum need a wildcard arm.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[non_exhaustive]
 pub enum Number {
     I8(i8),
     I16(i16),
@@ -37,6 +45,8 @@ pub enum Number {
     NonZeroUsize(NonZeroUsize),
     F32(f32),
     F64(f64),
+    Duration(Duration),
+    ByteSize(ByteSize),
 }
 
 impl std::fmt::Display for Number {
@@ -72,6 +82,8 @@ impl std::fmt::Display for Number {
             Number::NonZeroUsize(num) => write!(f, "{:?}", num),
             Number::F32(num) => write!(f, "{:?}", num),
             Number::F64(num) => write!(f, "{:?}", num),
+            Number::Duration(duration) => crate::duration::fmt_duration(&duration, f),
+            Number::ByteSize(size) => write!(f, "{}", size),
         }
     }
 }
@@ -124,3 +136,5 @@ impl_from_trait!(NonZeroU128);
 impl_from_trait!(NonZeroUsize);
 impl_from_trait!(f32);
 impl_from_trait!(f64);
+impl_from_trait!(Duration);
+impl_from_trait!(ByteSize);
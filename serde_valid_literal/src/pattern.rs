@@ -1,6 +1,13 @@
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd)]
 pub struct Pattern(String);
 
+impl Pattern {
+    /// The pattern from the regex source as written, like `^\d+$`.
+    pub fn from_source(source: &str) -> Self {
+        Self(source.to_string())
+    }
+}
+
 macro_rules! impl_from_trait {
     ($type:ty) => {
         impl From<$type> for Pattern {
@@ -11,11 +18,35 @@ macro_rules! impl_from_trait {
     };
 }
 
-impl_from_trait!(regex::Regex);
-impl_from_trait!(&regex::Regex);
 impl_from_trait!(String);
 impl_from_trait!(&str);
 
+impl From<regex::Regex> for Pattern {
+    fn from(item: regex::Regex) -> Self {
+        Self(item.as_str().to_string())
+    }
+}
+
+impl From<&regex::Regex> for Pattern {
+    fn from(item: &regex::Regex) -> Self {
+        Self(item.as_str().to_string())
+    }
+}
+
+#[cfg(feature = "fancy_regex")]
+impl From<fancy_regex::Regex> for Pattern {
+    fn from(item: fancy_regex::Regex) -> Self {
+        Self(item.as_str().to_string())
+    }
+}
+
+#[cfg(feature = "fancy_regex")]
+impl From<&fancy_regex::Regex> for Pattern {
+    fn from(item: &fancy_regex::Regex) -> Self {
+        Self(item.as_str().to_string())
+    }
+}
+
 impl std::fmt::Display for Pattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:}", self.0)